@@ -0,0 +1,160 @@
+//! Benchmarks for the crate's hot paths.
+//!
+//! Run with `cargo bench`. Criterion keeps the previous run as a baseline and reports regressions.
+//!
+//! ## Performance budget
+//!
+//! Measured on a typical laptop core. Changes that push a benchmark past its budget need a
+//! justification in the pull request.
+//!
+//! | Benchmark                           | Budget  |
+//! |-------------------------------------|---------|
+//! | `deserialize_market`                | < 10 µs |
+//! | `deserialize_markets_page_100`      | < 1 ms  |
+//! | `deserialize_orderbook_depth_100`   | < 20 µs |
+//! | `serialize_order_payload`           | < 2 µs  |
+//! | `serialize_order_payload_pooled`    | < 1 µs  |
+//! | `serialize_batch_payload_20`        | < 10 µs |
+//! | `serialize_batch_payload_20_copied` | < 10 µs |
+//!
+//! The crate only fetches orderbook snapshots over REST and keeps no local book, so there is no
+//! delta application to benchmark yet. Snapshot deserialization stands in for it until one exists.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use kalshi::{Action, Market, OrderCreationField, OrderType, Orderbook, Side};
+
+const MARKET_JSON: &str = r#"{
+    "ticker": "HIGHNY-23NOV13-T51",
+    "event_ticker": "HIGHNY-23NOV13",
+    "market_type": "binary",
+    "title": "Will the high temp in NYC be >51° on Nov 13, 2023?",
+    "subtitle": "52° or above",
+    "yes_sub_title": "52° or above",
+    "no_sub_title": "51° or below",
+    "open_time": "2023-11-12T15:00:00Z",
+    "close_time": "2023-11-14T04:59:00Z",
+    "expected_expiration_time": "2023-11-14T14:00:00Z",
+    "expiration_time": "2023-11-21T04:59:00Z",
+    "latest_expiration_time": "2023-11-21T04:59:00Z",
+    "settlement_timer_seconds": 1800,
+    "status": "active",
+    "response_price_units": "usd_cent",
+    "notional_value": 100,
+    "tick_size": 1,
+    "yes_bid": 42,
+    "yes_ask": 45,
+    "no_bid": 55,
+    "no_ask": 58,
+    "last_price": 44,
+    "previous_yes_bid": 40,
+    "previous_yes_ask": 46,
+    "previous_price": 43,
+    "volume": 12840,
+    "volume_24h": 3120,
+    "liquidity": 845012,
+    "open_interest": 9120,
+    "result": "",
+    "cap_strike": null,
+    "can_close_early": true,
+    "expiration_value": "",
+    "category": "Climate and Weather",
+    "risk_limit_cents": 0,
+    "strike_type": "greater",
+    "floor_strike": 51.0,
+    "rules_primary": "If the highest temperature recorded in Central Park is greater than 51, the market resolves to Yes.",
+    "rules_secondary": "",
+    "settlement_value": null,
+    "functional_strike": null
+}"#;
+
+fn markets_page(count: usize) -> String {
+    let markets = vec![MARKET_JSON; count].join(",");
+    format!("[{}]", markets)
+}
+
+fn orderbook_json(depth: usize) -> String {
+    let levels: Vec<String> = (1..=depth)
+        .map(|i| format!("[{},{}]", i % 99 + 1, i * 10))
+        .collect();
+    format!(
+        r#"{{"yes":[{}],"no":[{}]}}"#,
+        levels.join(","),
+        levels.join(",")
+    )
+}
+
+fn deserialization(c: &mut Criterion) {
+    c.bench_function("deserialize_market", |b| {
+        b.iter(|| serde_json::from_str::<Market>(black_box(MARKET_JSON)).unwrap())
+    });
+
+    let page = markets_page(100);
+    c.bench_function("deserialize_markets_page_100", |b| {
+        b.iter(|| serde_json::from_str::<Vec<Market>>(black_box(&page)).unwrap())
+    });
+
+    let orderbook = orderbook_json(100);
+    c.bench_function("deserialize_orderbook_depth_100", |b| {
+        b.iter(|| serde_json::from_str::<Orderbook>(black_box(&orderbook)).unwrap())
+    });
+}
+
+fn order() -> OrderCreationField {
+    OrderCreationField {
+        action: Action::Buy,
+        client_order_id: Some("2b2781e1-6ff8-4e35-87b4-fa8fd0a81fd4".to_string()),
+        count: black_box(10),
+        side: Side::Yes,
+        ticker: "HIGHNY-23NOV13-T51".to_string(),
+        input_type: OrderType::Limit,
+        buy_max_cost: None,
+        expiration_ts: None,
+        no_price: None,
+        sell_position_floor: None,
+        yes_price: Some(black_box(45)),
+        order_group_id: None,
+    }
+}
+
+fn order_payload(c: &mut Criterion) {
+    c.bench_function("serialize_order_payload", |b| {
+        b.iter(|| serde_json::to_vec(&order()).unwrap())
+    });
+
+    // Mirrors how the client serializes order bodies: into a buffer sized after the previous body,
+    // which is handed to the request as is.
+    let mut last_size = 0;
+    c.bench_function("serialize_order_payload_pooled", |b| {
+        b.iter(|| {
+            let mut buf = Vec::with_capacity(last_size);
+            serde_json::to_writer(&mut buf, &order()).unwrap();
+            last_size = buf.len();
+            buf
+        })
+    });
+
+    // A full batched order request, serialized the client's way and, for comparison, into a buffer
+    // reused across requests followed by an exactly sized copy for the request.
+    let batch: Vec<OrderCreationField> = (0..20).map(|_| order()).collect();
+    let mut last_size = 0;
+    c.bench_function("serialize_batch_payload_20", |b| {
+        b.iter(|| {
+            let mut buf = Vec::with_capacity(last_size);
+            serde_json::to_writer(&mut buf, black_box(&batch)).unwrap();
+            last_size = buf.len();
+            buf
+        })
+    });
+
+    let mut buf = Vec::new();
+    c.bench_function("serialize_batch_payload_20_copied", |b| {
+        b.iter(|| {
+            buf.clear();
+            serde_json::to_writer(&mut buf, black_box(&batch)).unwrap();
+            buf.to_vec()
+        })
+    });
+}
+
+criterion_group!(benches, deserialization, order_payload);
+criterion_main!(benches);
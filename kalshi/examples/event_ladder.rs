@@ -0,0 +1,121 @@
+//! Event-ladder arbitrage.
+//!
+//! In an event whose markets are mutually exclusive, exactly one market settles 'Yes' and pays
+//! one dollar. If buying one 'Yes' contract in every market, taker fees included, costs less than
+//! that dollar, holding the whole ladder gains the difference whichever market wins. This example
+//! scans an event for that condition and buys the ladder once, one leg per market.
+//!
+//! Legs that don't fill immediately are canceled and retried on a later scan, priced against what
+//! the filled legs actually cost. The example stops once every leg is held.
+//!
+//! Runs against the demo environment. Credentials are read from the `DEMO_USER_NAME`
+//! and `DEMO_PASSWORD` environment variables.
+//!
+//! ```text
+//! cargo run --example event_ladder --features strategy-examples -- HIGHNY-23NOV13
+//! ```
+
+use kalshi::{
+    estimate_taker_fee, Action, Kalshi, KalshiError, MarketStatus, OrderType, Side,
+    TradingEnvironment,
+};
+use std::collections::HashSet;
+use std::env;
+use std::time::Duration;
+
+/// Minimum profit per ladder, in cents, after fees, before trading.
+const MIN_EDGE: i64 = 2;
+/// Delay between scans.
+const POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+#[tokio::main]
+async fn main() -> Result<(), KalshiError> {
+    let event_ticker = env::args()
+        .nth(1)
+        .expect("usage: event_ladder <event ticker>");
+    let username = env::var("DEMO_USER_NAME").expect("DEMO_USER_NAME is not set");
+    let password = env::var("DEMO_PASSWORD").expect("DEMO_PASSWORD is not set");
+
+    let kalshi = Kalshi::new(TradingEnvironment::DemoMode)
+        .login(&username, &password)
+        .await?;
+    let _refresher = kalshi.spawn_token_refresh()?;
+
+    // Markets whose leg is already held, and what those legs cost including fees.
+    let mut held: HashSet<String> = HashSet::new();
+    let mut paid: i64 = 0;
+
+    loop {
+        let event = kalshi.get_single_event(&event_ticker, Some(true)).await?;
+
+        if !event.mutually_exclusive {
+            println!("{} is not mutually exclusive, nothing to do", event_ticker);
+            return Ok(());
+        }
+
+        let markets = event.markets.unwrap_or_default();
+        // The ladder only pays out if every market of the event can be bought.
+        if markets.is_empty()
+            || markets
+                .iter()
+                .any(|market| market.status != MarketStatus::Active)
+        {
+            println!("{} has markets that aren't trading, skipping", event_ticker);
+            tokio::time::sleep(POLL_INTERVAL).await;
+            continue;
+        }
+
+        let missing: Vec<_> = markets
+            .iter()
+            .filter(|market| !held.contains(&market.ticker))
+            .collect();
+        if missing.is_empty() {
+            println!("holding the full ladder for {} cents", paid);
+            return Ok(());
+        }
+
+        // A market without sellers quotes an ask of 100 and can't be part of a profitable ladder.
+        let cost: i64 = missing
+            .iter()
+            .map(|market| market.yes_ask + estimate_taker_fee(1, market.yes_ask))
+            .sum();
+        let edge = 100 - paid - cost;
+
+        if edge >= MIN_EDGE {
+            println!(
+                "{} legs cost {} after fees, buying for an edge of {}",
+                missing.len(),
+                cost,
+                edge
+            );
+
+            for market in missing {
+                let order = kalshi
+                    .create_order(
+                        Action::Buy,
+                        None,
+                        1,
+                        Side::Yes,
+                        market.ticker.clone(),
+                        OrderType::Limit,
+                        None,
+                        None,
+                        None,
+                        None,
+                        Some(market.yes_ask),
+                    )
+                    .await?;
+
+                if order.taker_fill_count.unwrap_or(0) > 0 {
+                    held.insert(market.ticker.clone());
+                    paid += order.taker_fill_cost.unwrap_or(0) + order.taker_fees.unwrap_or(0);
+                } else {
+                    // The ask moved; don't leave the leg resting at a stale price.
+                    kalshi.cancel_order(&order.order_id).await?;
+                }
+            }
+        }
+
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
@@ -0,0 +1,98 @@
+//! A simple market maker.
+//!
+//! Quotes one contract on both sides of a market, joining the best 'Yes' bid and the best
+//! 'No' bid (which together act as a bid and an ask on 'Yes'). Quotes are canceled and
+//! replaced every interval, and the side that would grow the inventory past its limit
+//! stops quoting.
+//!
+//! Runs against the demo environment. Credentials are read from the `DEMO_USER_NAME`
+//! and `DEMO_PASSWORD` environment variables.
+//!
+//! ```text
+//! cargo run --example market_maker --features strategy-examples -- HIGHNY-23NOV13-T51
+//! ```
+
+use kalshi::{Action, Kalshi, KalshiError, OrderType, Side, TradingEnvironment};
+use std::env;
+use std::time::Duration;
+
+/// Largest net position, in either direction, before the quote adding to it is pulled.
+const MAX_INVENTORY: i32 = 10;
+/// Minimum spread, in cents, worth quoting.
+const MIN_SPREAD: i64 = 2;
+/// Delay between requotes.
+const REQUOTE_INTERVAL: Duration = Duration::from_secs(5);
+
+#[tokio::main]
+async fn main() -> Result<(), KalshiError> {
+    let ticker = env::args()
+        .nth(1)
+        .expect("usage: market_maker <market ticker>");
+    let username = env::var("DEMO_USER_NAME").expect("DEMO_USER_NAME is not set");
+    let password = env::var("DEMO_PASSWORD").expect("DEMO_PASSWORD is not set");
+
+    let kalshi = Kalshi::new(TradingEnvironment::DemoMode)
+        .login(&username, &password)
+        .await?;
+    let _refresher = kalshi.spawn_token_refresh()?;
+
+    let mut quotes: Vec<String> = Vec::new();
+
+    loop {
+        if !quotes.is_empty() {
+            kalshi
+                .batch_cancel_order(std::mem::take(&mut quotes))
+                .await?;
+        }
+
+        let market = kalshi.get_single_market(&ticker).await?;
+        let (_, _, positions) = kalshi
+            .get_user_positions(None, None, None, Some(ticker.clone()), None)
+            .await?;
+        let inventory = positions.first().map(|p| p.position).unwrap_or(0);
+
+        let spread = market.yes_ask - market.yes_bid;
+        if spread >= MIN_SPREAD {
+            let mut sides = Vec::new();
+            if inventory < MAX_INVENTORY {
+                sides.push((Side::Yes, market.yes_bid));
+            }
+            if inventory > -MAX_INVENTORY {
+                sides.push((Side::No, market.no_bid));
+            }
+
+            for (side, price) in sides {
+                let (yes_price, no_price) = match side {
+                    Side::Yes => (Some(price), None),
+                    Side::No => (None, Some(price)),
+                };
+                let order = kalshi
+                    .create_order(
+                        Action::Buy,
+                        None,
+                        1,
+                        side,
+                        ticker.clone(),
+                        OrderType::Limit,
+                        None,
+                        None,
+                        no_price,
+                        None,
+                        yes_price,
+                    )
+                    .await?;
+                quotes.push(order.order_id);
+            }
+        }
+
+        println!(
+            "inventory {}, quoting {} orders around {}/{}",
+            inventory,
+            quotes.len(),
+            market.yes_bid,
+            market.yes_ask
+        );
+
+        tokio::time::sleep(REQUOTE_INTERVAL).await;
+    }
+}
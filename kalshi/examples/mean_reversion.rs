@@ -0,0 +1,100 @@
+//! Mean-reversion on a single market.
+//!
+//! Polls a market's prices, keeps a rolling mean of the last traded price and buys a 'Yes'
+//! contract when the ask drops well below the mean, selling it back once the bid recovers.
+//!
+//! Runs against the demo environment. Credentials are read from the `DEMO_USER_NAME`
+//! and `DEMO_PASSWORD` environment variables.
+//!
+//! ```text
+//! cargo run --example mean_reversion --features strategy-examples -- HIGHNY-23NOV13-T51
+//! ```
+
+use kalshi::{Action, Kalshi, KalshiError, OrderType, Side, TradingEnvironment};
+use std::collections::VecDeque;
+use std::env;
+use std::time::Duration;
+
+/// Number of price samples in the rolling mean.
+const WINDOW: usize = 20;
+/// How far below the mean, in cents, the ask must be before buying.
+const ENTRY_THRESHOLD: f64 = 5.0;
+/// Largest number of contracts to hold.
+const MAX_POSITION: i32 = 5;
+/// Delay between polls.
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+#[tokio::main]
+async fn main() -> Result<(), KalshiError> {
+    let ticker = env::args()
+        .nth(1)
+        .expect("usage: mean_reversion <market ticker>");
+    let username = env::var("DEMO_USER_NAME").expect("DEMO_USER_NAME is not set");
+    let password = env::var("DEMO_PASSWORD").expect("DEMO_PASSWORD is not set");
+
+    let kalshi = Kalshi::new(TradingEnvironment::DemoMode)
+        .login(&username, &password)
+        .await?;
+    let _refresher = kalshi.spawn_token_refresh()?;
+
+    let mut prices: VecDeque<i64> = VecDeque::with_capacity(WINDOW);
+
+    loop {
+        let market = kalshi.get_single_market(&ticker).await?;
+
+        if prices.len() == WINDOW {
+            prices.pop_front();
+        }
+        prices.push_back(market.last_price);
+
+        if prices.len() == WINDOW {
+            let mean = prices.iter().sum::<i64>() as f64 / WINDOW as f64;
+
+            let (_, _, positions) = kalshi
+                .get_user_positions(None, None, None, Some(ticker.clone()), None)
+                .await?;
+            let held = positions
+                .first()
+                .map(|position| position.net_yes_contracts())
+                .unwrap_or(0);
+
+            if (market.yes_ask as f64) < mean - ENTRY_THRESHOLD && held < MAX_POSITION {
+                println!("ask {} below mean {:.1}, buying", market.yes_ask, mean);
+                kalshi
+                    .create_order(
+                        Action::Buy,
+                        None,
+                        1,
+                        Side::Yes,
+                        ticker.clone(),
+                        OrderType::Limit,
+                        None,
+                        None,
+                        None,
+                        None,
+                        Some(market.yes_ask),
+                    )
+                    .await?;
+            } else if held > 0 && market.yes_bid as f64 >= mean {
+                println!("bid {} back at mean {:.1}, selling", market.yes_bid, mean);
+                kalshi
+                    .create_order(
+                        Action::Sell,
+                        None,
+                        held,
+                        Side::Yes,
+                        ticker.clone(),
+                        OrderType::Limit,
+                        None,
+                        None,
+                        None,
+                        None,
+                        Some(market.yes_bid),
+                    )
+                    .await?;
+            }
+        }
+
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
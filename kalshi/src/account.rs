@@ -0,0 +1,77 @@
+use super::{Authenticated, Environment, Kalshi};
+use crate::kalshi_error::*;
+use serde::{Deserialize, Serialize};
+
+impl<E: Environment> Kalshi<Authenticated, E> {
+    /// Retrieves details about the authenticated user's account from the Kalshi exchange.
+    ///
+    /// This method fetches the account's API access tier and request limits, combined with the member ID
+    /// returned at login, so bots can adapt their behavior (e.g. request pacing) to the account's tier.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Account)`: The account details on successful retrieval.
+    /// - `Err(KalshiError)`: An error if the user is not authenticated or if there is an issue with the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let account = kalshi_instance.get_account().await.unwrap();
+    /// println!("{} requests per second allowed", account.read_limit);
+    /// ```
+    ///
+    pub async fn get_account(&self) -> Result<Account, KalshiError> {
+        let member_id = match self.get_member_id() {
+            Some(member_id) => member_id,
+            None => {
+                return Err(KalshiError::UserInputError(
+                    "Not logged in, a valid token is required for requests that require authentication"
+                        .to_string(),
+                ));
+            }
+        };
+
+        let limits_url: &str = &format!("{}/account/limits", self.base_url());
+
+        let result: AccountLimitsResponse = self
+            .send_authenticated(self.client.get(limits_url))
+            .await?
+            .json()
+            .await?;
+
+        Ok(Account {
+            member_id,
+            usage_tier: result.usage_tier,
+            read_limit: result.read_limit,
+            write_limit: result.write_limit,
+        })
+    }
+}
+
+// PRIVATE STRUCTS
+// used in get_account
+#[derive(Debug, Deserialize, Serialize)]
+struct AccountLimitsResponse {
+    usage_tier: String,
+    read_limit: i64,
+    write_limit: i64,
+}
+
+// PUBLIC STRUCTS
+
+/// Details about the authenticated user's account on the Kalshi exchange.
+///
+/// Includes the member ID and the API access tier with its request limits.
+///
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Account {
+    /// The member ID of the account.
+    pub member_id: String,
+    /// The API access tier of the account (e.g. 'basic', 'advanced').
+    pub usage_tier: String,
+    /// Maximum number of read requests allowed per second.
+    pub read_limit: i64,
+    /// Maximum number of write requests (order creation, cancellation, ...) allowed per second.
+    pub write_limit: i64,
+}
@@ -1,27 +1,130 @@
-use super::Kalshi;
+use super::{Environment, Kalshi};
 use crate::kalshi_error::*;
+use reqwest::header::{HeaderValue, AUTHORIZATION};
 use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex, RwLock, Weak};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
-impl<'a> Kalshi {
+/// How long a token issued by the login endpoint stays valid.
+pub(crate) const TOKEN_LIFETIME: Duration = Duration::from_secs(30 * 60);
+
+/// How long before expiry the background refresher logs in again.
+const REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// How many unread session events a subscriber can fall behind before skipping the oldest.
+pub(crate) const SESSION_EVENT_CAPACITY: usize = 64;
+
+/// Delay between attempts when a background refresh fails.
+const REFRESH_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+impl<S, E: Environment> Kalshi<S, E> {
     /// Asynchronously logs a user into the Kalshi exchange.
     ///
     /// This method sends a POST request to the Kalshi exchange's login endpoint with the user's credentials.
-    /// On successful authentication, it updates the current session's token and member ID.
+    /// On successful authentication, it updates the current session's token and member ID and returns
+    /// an authenticated instance sharing the session. Only a shared reference is needed, so a `Kalshi` instance cloned across tasks can log in again
+    /// without any extra synchronization. The credentials are kept on the session so that [`spawn_token_refresh`](Kalshi::spawn_token_refresh)
+    /// can log in again before the token expires.
     ///
     /// # Arguments
     /// * `user` - A string slice representing the user's email.
     /// * `password` - A string slice representing the user's password.
     ///
     /// # Returns
-    /// - `Ok(())`: Empty result indicating successful login.
+    /// - `Ok(Kalshi<Authenticated>)`: An authenticated instance on successful login.
     /// - `Err(KalshiError)`: Error in case of a failure in the HTTP request or response parsing.
     ///
     /// # Example
     /// ```
-    /// kalshi_instance.login("johndoe@example.com", "example_password").await?;
+    /// let kalshi_instance = Kalshi::new(TradingEnvironment::DemoMode)
+    ///     .login("johndoe@example.com", "example_password")
+    ///     .await?;
+    /// ```
+    pub async fn login(
+        &self,
+        user: &str,
+        password: &str,
+    ) -> Result<Kalshi<Authenticated, E>, KalshiError> {
+        self.authenticate(user, password).await?;
+        Ok(self.with_state())
+    }
+
+    /// Retrieves the member ID returned by the exchange at login, if available.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `Option<String>` containing the member ID. If the user is not logged in, it returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// if let Some(member_id) = kalshi_instance.get_member_id() {
+    ///     println!("Logged in as member {}", member_id);
+    /// }
+    /// ```
+    ///
+    pub fn get_member_id(&self) -> Option<String> {
+        self.auth.read().unwrap().member_id.clone()
+    }
+
+    /// Subscribes to session-level events such as logins, token refreshes and authentication failures.
+    ///
+    /// Bots can log and alert on the health of their session separately from their trading logic.
+    /// Every clone of this `Kalshi` instance publishes to the same channel. Events published while no
+    /// receiver is subscribed are discarded, and a receiver that falls more than 64 events behind
+    /// skips the oldest ones.
+    ///
+    /// # Returns
+    ///
+    /// A `broadcast::Receiver` yielding every `SessionEvent` published after the call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut events = kalshi_instance.subscribe_session_events();
+    /// tokio::spawn(async move {
+    ///     while let Ok(event) = events.recv().await {
+    ///         println!("session event: {:?}", event);
+    ///     }
+    /// });
     /// ```
-    pub async fn login(&mut self, user: &str, password: &str) -> Result<(), KalshiError> {
-        let login_url: &str = &format!("{}/login", self.base_url.to_string());
+    ///
+    pub fn subscribe_session_events(&self) -> broadcast::Receiver<SessionEvent> {
+        self.events.subscribe()
+    }
+
+    /// Publishes a session event to every subscriber.
+    pub(crate) fn publish_event(&self, event: SessionEvent) {
+        // Sending only fails when nobody is subscribed, in which case the event is not needed.
+        let _ = self.events.send(event);
+    }
+
+    /// Logs in with the given credentials and stores the resulting token on the shared session.
+    pub(crate) async fn authenticate(&self, user: &str, password: &str) -> Result<(), KalshiError> {
+        match self.request_token(user, password, false).await {
+            Ok(()) => {
+                self.publish_event(SessionEvent::LoggedIn);
+                Ok(())
+            }
+            Err(e) => {
+                self.publish_event(SessionEvent::AuthFailed(e.to_string()));
+                Err(e)
+            }
+        }
+    }
+
+    // With `refresh` set, the token is only stored if the session still holds its credentials, so a
+    // logout that finished while the login request was in flight isn't undone.
+    async fn request_token(
+        &self,
+        user: &str,
+        password: &str,
+        refresh: bool,
+    ) -> Result<(), KalshiError> {
+        let login_url: &str = &format!("{}/login", self.base_url());
 
         let login_payload = LoginPayload {
             email: user.to_string(),
@@ -37,48 +140,649 @@ impl<'a> Kalshi {
             .json()
             .await?;
 
-        self.curr_token = Some(format!("Bearer {}", result.token));
-        self.member_id = Some(result.member_id);
+        let mut auth = self.auth.write().unwrap();
+        if refresh && auth.credentials.is_none() {
+            return Err(KalshiError::AuthenticationError(
+                "Session was logged out while its token was being refreshed".to_string(),
+            ));
+        }
+        auth.token = Some(format!("Bearer {}", result.token));
+        auth.member_id = Some(result.member_id.clone());
+        auth.token_expiry = Some(SystemTime::now() + TOKEN_LIFETIME);
+        auth.credentials = Some(login_payload);
+        drop(auth);
+
+        register_session(&self.base_url(), &self.auth);
 
         return Ok(());
     }
 
+    /// Sends a request for public data, which needs no token.
+    ///
+    /// When the session is logged in the authorization header is attached anyway, so the request
+    /// is attributed to the account like every other request of the session.
+    pub(crate) async fn send_public(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, KalshiError> {
+        if self.get_user_token().is_some() {
+            return self.send_authenticated(request).await;
+        }
+        self.dispatch(request).await
+    }
+
+    /// Sends a request with the session's authorization header attached.
+    ///
+    /// If the exchange answers with `401 Unauthorized` and credentials from a previous login are
+    /// stored, the session logs in again once and the request is replayed with the new token.
+    /// Requests rejected at the same time share a single re-login. A [`KalshiError::AuthenticationError`]
+    /// is returned if the re-login or the replay fails to authenticate, or if the session was logged out
+    /// in the meantime.
+    pub(crate) async fn send_authenticated(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, KalshiError> {
+        let replay = request.try_clone();
+        let token = self.get_user_token().ok_or_else(|| {
+            KalshiError::UserInputError(
+                "Not logged in, a valid token is required for requests that require authentication"
+                    .to_string(),
+            )
+        })?;
+
+        let response = self
+            .dispatch_authenticated(request.header(AUTHORIZATION, authorization_header(&token)?))
+            .await?;
+
+        if response.status() != reqwest::StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+
+        let has_credentials = self.auth.read().unwrap().credentials.is_some();
+        let replay = match replay {
+            Some(replay) if has_credentials => replay,
+            _ => {
+                self.publish_event(SessionEvent::AuthFailed(
+                    "Session token was rejected by the exchange".to_string(),
+                ));
+                return Err(KalshiError::AuthenticationError(
+                    "Session token was rejected by the exchange".to_string(),
+                ));
+            }
+        };
+
+        if let Err(e) = self.relogin_after_rejection(&token).await {
+            return Err(KalshiError::AuthenticationError(format!(
+                "Session token was rejected and re-login failed: {}",
+                e
+            )));
+        }
+
+        let token = self.get_user_token().ok_or_else(|| {
+            KalshiError::AuthenticationError(
+                "Session was logged out before the request could be replayed".to_string(),
+            )
+        })?;
+
+        let response = self
+            .dispatch_authenticated(replay.header(AUTHORIZATION, authorization_header(&token)?))
+            .await?;
+
+        if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+            self.publish_event(SessionEvent::AuthFailed(
+                "Session token was rejected again after re-login".to_string(),
+            ));
+            return Err(KalshiError::AuthenticationError(
+                "Session token was rejected again after re-login".to_string(),
+            ));
+        }
+
+        Ok(response)
+    }
+
+    /// Builds a request and sends it through the client the request was created with.
+    ///
+    /// Publishes [`SessionEvent::RateLimited`] when the exchange answers with `429 Too Many Requests`.
+    pub(crate) async fn dispatch(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, KalshiError> {
+        let (client, request) = request.build_split();
+        self.execute(&client, request?).await
+    }
+
+    // Like `dispatch`, and runs the auth hook on the built request first, if one is set.
+    async fn dispatch_authenticated(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, KalshiError> {
+        let (client, request) = request.build_split();
+        let mut request = request?;
+
+        if let Some(hook) = &self.auth_hook {
+            (hook.0)(&mut request);
+        }
+
+        self.execute(&client, request).await
+    }
+
+    // Sends a built request, reporting rate limits and unreachable endpoints.
+    async fn execute(
+        &self,
+        client: &reqwest::Client,
+        request: reqwest::Request,
+    ) -> Result<reqwest::Response, KalshiError> {
+        let url = request.url().to_string();
+
+        match client.execute(request).await {
+            Ok(response) => {
+                if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                    self.publish_event(SessionEvent::RateLimited);
+                }
+                Ok(response)
+            }
+            Err(e) => {
+                if e.is_connect() || e.is_timeout() {
+                    self.report_unreachable(&url);
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    /// Logs in again after the exchange rejected `rejected_token`, unless another request already did.
+    ///
+    /// Only one re-login runs at a time. A request that waited for another one to finish finds the
+    /// token changed and skips its own.
+    async fn relogin_after_rejection(&self, rejected_token: &str) -> Result<(), KalshiError> {
+        let _relogin = self.relogin.lock().await;
+
+        if self.auth.read().unwrap().token.as_deref() != Some(rejected_token) {
+            return Ok(());
+        }
+
+        self.reauthenticate().await
+    }
+
+    /// Logs in again using the credentials stored by the last successful login.
+    pub(crate) async fn reauthenticate(&self) -> Result<(), KalshiError> {
+        let credentials = self.auth.read().unwrap().credentials.clone();
+
+        match credentials {
+            Some(creds) => match self
+                .request_token(&creds.email, &creds.password, true)
+                .await
+            {
+                Ok(()) => {
+                    self.publish_event(SessionEvent::TokenRefreshed);
+                    Ok(())
+                }
+                Err(e) => {
+                    self.publish_event(SessionEvent::AuthFailed(e.to_string()));
+                    Err(e)
+                }
+            },
+            None => Err(KalshiError::UserInputError(
+                "Not logged in, credentials are required to refresh the session token".to_string(),
+            )),
+        }
+    }
+}
+
+impl<E: Environment> Kalshi<Authenticated, E> {
     /// Asynchronously logs a user out of the Kalshi exchange.
     ///
     /// Sends a POST request to the Kalshi exchange's logout endpoint. This method
     /// should be called to properly terminate the session initiated by `login`.
+    /// The token, member ID and stored credentials are cleared from every clone of this instance.
     ///
     /// # Returns
-    /// - `Ok(())`: Empty result indicating successful logout.
+    /// - `Ok(Kalshi<Anonymous>)`: An anonymous instance on successful logout, which can log in again.
     /// - `Err(KalshiError)`: Error in case of a failure in the HTTP request.
     ///
     /// # Examples
     /// ```
-    /// kalshi_instance.logout().await?;
+    /// let kalshi_instance = kalshi_instance.logout().await?;
     /// ```
-    pub async fn logout(&self) -> Result<(), KalshiError> {
-        let logout_url: &str = &format!("{}/logout", self.base_url.to_string());
+    pub async fn logout(self) -> Result<Kalshi<Anonymous, E>, KalshiError> {
+        end_session(&self.client, &self.base_url(), &self.auth).await?;
+        Ok(self.with_state())
+    }
 
-        self.client
-            .post(logout_url)
-            .header("Authorization", self.curr_token.clone().unwrap())
-            .header("content-type", "application/json".to_string())
-            .send()
-            .await?;
+    /// Returns a guard that logs this session out when it is dropped.
+    ///
+    /// Dropping the guard spawns the logout request on the current tokio runtime, so a bot that exits
+    /// or restarts doesn't leave its session open against the account. If no runtime is available when
+    /// the guard is dropped, the session is left to expire on its own.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let kalshi_instance = kalshi_instance.login("johndoe@example.com", "example_password").await?;
+    /// let _guard = kalshi_instance.logout_on_drop();
+    /// ```
+    ///
+    pub fn logout_on_drop(&self) -> LogoutGuard {
+        LogoutGuard {
+            kalshi: self.retype(),
+        }
+    }
 
-        return Ok(());
+    /// Spawns a background task that logs in again shortly before the current token expires.
+    ///
+    /// Tokens issued by Kalshi are only valid for thirty minutes. Long-running bots can call this
+    /// once after [`login`](Kalshi::login) instead of re-logging in by hand. The refreshed token is
+    /// shared with every clone of this `Kalshi` instance. If a refresh fails, it is retried every
+    /// thirty seconds until it succeeds or the handle is stopped. The refresher stops on its own once
+    /// the session logs out.
+    ///
+    /// # Returns
+    /// - `Ok(TokenRefreshHandle)`: A handle owning the background task. Dropping it stops the refresher.
+    /// - `Err(KalshiError)`: An error if the session has no stored credentials, e.g. when it was
+    ///   restored with [`from_session`](Kalshi::from_session).
+    ///
+    /// # Example
+    /// ```
+    /// let kalshi_instance = kalshi_instance.login("johndoe@example.com", "example_password").await?;
+    /// let _refresher = kalshi_instance.spawn_token_refresh()?;
+    /// ```
+    pub fn spawn_token_refresh(&self) -> Result<TokenRefreshHandle, KalshiError> {
+        if self.auth.read().unwrap().credentials.is_none() {
+            return Err(KalshiError::UserInputError(
+                "Not logged in, credentials are required to refresh the session token".to_string(),
+            ));
+        }
+
+        let kalshi = self.clone();
+        let handle = tokio::spawn(async move {
+            loop {
+                let refresh_at = kalshi
+                    .auth
+                    .read()
+                    .unwrap()
+                    .token_expiry
+                    .map(|expiry| expiry - REFRESH_MARGIN);
+
+                if let Some(refresh_at) = refresh_at {
+                    if let Ok(wait) = refresh_at.duration_since(SystemTime::now()) {
+                        tokio::time::sleep(wait).await;
+                    }
+                }
+
+                // Refreshes share the lock of the re-logins after a rejected token, so the two never
+                // log in at the same time.
+                let relogin = kalshi.relogin.lock().await;
+
+                // The session logged out, which wiped the credentials.
+                if kalshi.auth.read().unwrap().credentials.is_none() {
+                    return;
+                }
+
+                // A re-login finished while this task waited for the lock.
+                let refreshed = kalshi
+                    .auth
+                    .read()
+                    .unwrap()
+                    .token_expiry
+                    .is_some_and(|expiry| expiry - REFRESH_MARGIN > SystemTime::now());
+                if refreshed {
+                    continue;
+                }
+
+                let result = kalshi.reauthenticate().await;
+                drop(relogin);
+                if result.is_err() {
+                    tokio::time::sleep(REFRESH_RETRY_DELAY).await;
+                }
+            }
+        });
+
+        Ok(TokenRefreshHandle { handle })
     }
+
+    /// Exports the current authentication state so it can be persisted and restored later.
+    ///
+    /// A bot that restarts within the thirty minute token window can restore the exported
+    /// session with [`from_session`](Kalshi::from_session) instead of logging in again.
+    /// The user's password is never included in the exported session.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `Option<Session>`. If the session has been logged out, it returns `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let session = kalshi_instance.export_session().unwrap();
+    /// std::fs::write("session.json", serde_json::to_string(&session)?)?;
+    /// ```
+    ///
+    pub fn export_session(&self) -> Option<Session> {
+        let auth = self.auth.read().unwrap();
+
+        match (&auth.token, &auth.member_id, auth.token_expiry) {
+            (Some(token), Some(member_id), Some(expiry)) => Some(Session {
+                base_url: self.base_url(),
+                token: token.clone(),
+                member_id: member_id.clone(),
+                expires_at: expiry
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_secs())
+                    .unwrap_or(0),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl Kalshi<Authenticated> {
+    /// Logs out every session this process has logged in with, across all `Kalshi` instances.
+    ///
+    /// Useful on shutdown of a process running several bots, so orphaned sessions don't accumulate
+    /// against the account's limits. Sessions that were already logged out or whose instances have
+    /// been dropped are skipped.
+    ///
+    /// # Returns
+    /// - `Ok(usize)`: The number of sessions that were logged out.
+    /// - `Err(KalshiError)`: The first error encountered. Every session is still attempted.
+    ///
+    /// # Examples
+    /// ```
+    /// let logged_out = Kalshi::logout_all().await?;
+    /// ```
+    pub async fn logout_all() -> Result<usize, KalshiError> {
+        let sessions: Vec<(String, Arc<RwLock<AuthState>>)> = {
+            let mut registry = SESSION_REGISTRY.lock().unwrap();
+            registry.retain(|(_, auth)| auth.strong_count() > 0);
+            registry
+                .iter()
+                .filter_map(|(base_url, auth)| auth.upgrade().map(|auth| (base_url.clone(), auth)))
+                .filter(|(_, auth)| auth.read().unwrap().token.is_some())
+                .collect()
+        };
+
+        let client = reqwest::Client::new();
+        let mut logged_out = 0;
+        let mut first_error = None;
+
+        for (base_url, auth) in sessions {
+            match end_session(&client, &base_url, &auth).await {
+                Ok(()) => logged_out += 1,
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(logged_out),
+        }
+    }
+
+    /// Creates a new Kalshi instance from a previously exported session.
+    ///
+    /// The restored instance targets the same trading environment the session was created in.
+    /// Since the password is not part of the session, the restored instance cannot refresh its token;
+    /// call [`login`](Kalshi::login) once the session expires. Like a fresh login, the restored session
+    /// is logged out by [`logout_all`](Kalshi::logout_all).
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - A session obtained from [`export_session`](Kalshi::export_session).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Kalshi<Authenticated>)`: An authenticated instance using the session's token.
+    /// - `Err(KalshiError)`: An error if the session's token has already expired.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let session: Session = serde_json::from_str(&std::fs::read_to_string("session.json")?)?;
+    /// let kalshi_instance = Kalshi::from_session(session)?;
+    /// ```
+    ///
+    pub fn from_session(session: Session) -> Result<Kalshi<Authenticated>, KalshiError> {
+        let expiry = UNIX_EPOCH + Duration::from_secs(session.expires_at);
+
+        if expiry <= SystemTime::now() {
+            return Err(KalshiError::UserInputError(
+                "Session has expired, log in again to obtain a new token".to_string(),
+            ));
+        }
+
+        let kalshi = Kalshi::with_base_url(session.base_url.clone());
+        *kalshi.auth.write().unwrap() = AuthState {
+            token: Some(session.token.clone()),
+            member_id: Some(session.member_id.clone()),
+            token_expiry: Some(expiry),
+            credentials: None,
+        };
+        register_session(&kalshi.base_url(), &kalshi.auth);
+
+        Ok(kalshi.with_state())
+    }
+}
+
+/// A handle to the background task started by [`Kalshi::spawn_token_refresh`].
+///
+/// The refresher keeps running for as long as the handle is alive. Dropping the handle
+/// or calling [`stop`](TokenRefreshHandle::stop) aborts the task.
+#[derive(Debug)]
+pub struct TokenRefreshHandle {
+    handle: JoinHandle<()>,
 }
 
+impl TokenRefreshHandle {
+    /// Stops the background refresher. The current token stays valid until it expires.
+    pub fn stop(self) {}
+}
+
+impl Drop for TokenRefreshHandle {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// A serializable snapshot of an authenticated session.
+///
+/// Obtained from [`Kalshi::export_session`] and restored with [`Kalshi::from_session`].
+/// The token grants full access to the account, so store it as carefully as a password.
+/// The token is wiped from memory when the session is dropped and redacted from `Debug` output.
+#[derive(Clone, Serialize, Deserialize, Zeroize, ZeroizeOnDrop)]
+pub struct Session {
+    /// The base URL of the environment the session was created in.
+    pub base_url: String,
+    /// The authorization header value for the session.
+    pub token: String,
+    /// The member ID returned at login.
+    pub member_id: String,
+    /// The token's expiry, in seconds since the Unix epoch.
+    pub expires_at: u64,
+}
+
+impl std::fmt::Debug for Session {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Session")
+            .field("base_url", &self.base_url)
+            .field("token", &"<redacted>")
+            .field("member_id", &self.member_id)
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+/// A user-supplied function run on every authenticated request just before it is sent.
+/// Set with [`Kalshi::with_auth_hook`].
+#[derive(Clone)]
+pub(crate) struct AuthHook(pub(crate) Arc<dyn Fn(&mut reqwest::Request) + Send + Sync>);
+
+impl std::fmt::Debug for AuthHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AuthHook")
+    }
+}
+
+/// Logs its session out when dropped. Returned by [`Kalshi::logout_on_drop`].
+#[derive(Debug)]
+pub struct LogoutGuard {
+    kalshi: Kalshi<Authenticated>,
+}
+
+impl Drop for LogoutGuard {
+    fn drop(&mut self) {
+        if self.kalshi.get_user_token().is_none() {
+            return;
+        }
+
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            let kalshi = self.kalshi.clone();
+            runtime.spawn(async move {
+                let _ = kalshi.logout().await;
+            });
+        }
+    }
+}
+
+/// Sessions logged in by this process, used by [`Kalshi::logout_all`].
+static SESSION_REGISTRY: Mutex<Vec<(String, Weak<RwLock<AuthState>>)>> = Mutex::new(Vec::new());
+
+fn register_session(base_url: &str, auth: &Arc<RwLock<AuthState>>) {
+    let mut registry = SESSION_REGISTRY.lock().unwrap();
+    registry.retain(|(_, registered)| registered.strong_count() > 0);
+
+    if !registry
+        .iter()
+        .any(|(_, registered)| std::ptr::eq(registered.as_ptr(), Arc::as_ptr(auth)))
+    {
+        registry.push((base_url.to_string(), Arc::downgrade(auth)));
+    }
+}
+
+// Builds the authorization header for a session token. The header is marked sensitive, so it is
+// redacted from the request's debug output.
+fn authorization_header(token: &str) -> Result<HeaderValue, KalshiError> {
+    let mut header = HeaderValue::from_str(token).map_err(|_| {
+        KalshiError::InternalError("Session token is not a valid header value".to_string())
+    })?;
+    header.set_sensitive(true);
+    Ok(header)
+}
+
+/// Sends the logout request for a session and clears its authentication state.
+async fn end_session(
+    client: &reqwest::Client,
+    base_url: &str,
+    auth: &RwLock<AuthState>,
+) -> Result<(), KalshiError> {
+    let token = match auth.read().unwrap().token.as_deref() {
+        Some(token) => authorization_header(token)?,
+        None => return Ok(()),
+    };
+    let logout_url: &str = &format!("{}/logout", base_url);
+
+    client
+        .post(logout_url)
+        .header(AUTHORIZATION, token)
+        .header("content-type", "application/json".to_string())
+        .send()
+        .await?;
+
+    let mut auth = auth.write().unwrap();
+    auth.zeroize();
+    auth.token_expiry = None;
+
+    Ok(())
+}
+
+/// A session-level event, published to receivers obtained from [`Kalshi::subscribe_session_events`].
+///
+/// New kinds of events may be added in future releases, so matches on it need a wildcard arm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SessionEvent {
+    /// A login with user-supplied credentials succeeded.
+    LoggedIn,
+    /// The session logged in again with stored credentials, either from the background
+    /// refresher or after a request was rejected with `401 Unauthorized`.
+    TokenRefreshed,
+    /// A login, refresh or authenticated request failed to authenticate. Holds a description of the failure.
+    AuthFailed(String),
+    /// The exchange answered a request with `429 Too Many Requests`.
+    RateLimited,
+    /// Requests are now sent to another base URL, after a health check or a failed connection.
+    /// Holds the new base URL.
+    EndpointChanged(String),
+    /// A dead-man switch missed its heartbeat and is canceling all resting orders.
+    DeadmanTriggered,
+    /// A tripped dead-man switch failed to cancel every order or close every position.
+    /// Holds a description of the failure.
+    DeadmanFailed(String),
+}
+
+/// Authentication state shared between clones of a `Kalshi` instance.
+///
+/// The token and credentials are wiped from memory when the last clone is dropped or the session logs out.
+#[derive(Default, Zeroize, ZeroizeOnDrop)]
+pub(crate) struct AuthState {
+    pub(crate) token: Option<String>,
+    pub(crate) member_id: Option<String>,
+    #[zeroize(skip)]
+    pub(crate) token_expiry: Option<SystemTime>,
+    pub(crate) credentials: Option<LoginPayload>,
+}
+
+impl std::fmt::Debug for AuthState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthState")
+            .field("token", &self.token.as_ref().map(|_| "<redacted>"))
+            .field("member_id", &self.member_id)
+            .field("token_expiry", &self.token_expiry)
+            .field("credentials", &self.credentials)
+            .finish()
+    }
+}
+
+/// Marks a [`Kalshi`] instance that has not logged in.
+///
+/// Only public market and exchange data can be requested. Obtained from [`Kalshi::new`].
+#[derive(Debug, Clone, Copy)]
+pub struct Anonymous;
+
+/// Marks a [`Kalshi`] instance that has logged in.
+///
+/// Adds the endpoints that require a session. Obtained from [`Kalshi::login`] or [`Kalshi::from_session`].
+#[derive(Debug, Clone, Copy)]
+pub struct Authenticated;
+
 // used in login method
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Zeroize, ZeroizeOnDrop)]
 struct LoginResponse {
     member_id: String,
     token: String,
 }
+
+impl std::fmt::Debug for LoginResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoginResponse")
+            .field("member_id", &self.member_id)
+            .field("token", &"<redacted>")
+            .finish()
+    }
+}
 // used in login method
-#[derive(Debug, Serialize, Deserialize)]
-struct LoginPayload {
+#[derive(Clone, Serialize, Deserialize, Zeroize, ZeroizeOnDrop)]
+pub(crate) struct LoginPayload {
     email: String,
     password: String,
 }
+
+impl std::fmt::Debug for LoginPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoginPayload")
+            .field("email", &self.email)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
@@ -0,0 +1,469 @@
+use crate::market::Snapshot;
+#[cfg(feature = "chrono")]
+use crate::market::Trade;
+use serde::{Deserialize, Serialize};
+
+/// Open, high, low and close prices of a market over a time bucket, together with the volume traded in it.
+///
+/// Prices are in cents. Candles are identified by the Unix timestamp, in seconds, at which their bucket starts.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Candle {
+    /// Unix timestamp, in seconds, of the start of the bucket.
+    pub start_ts: i64,
+    /// First price of the bucket.
+    pub open: i32,
+    /// Highest price of the bucket.
+    pub high: i32,
+    /// Lowest price of the bucket.
+    pub low: i32,
+    /// Last price of the bucket.
+    pub close: i32,
+    /// Number of contracts traded during the bucket.
+    pub volume: i64,
+}
+
+/// The bucket sizes candles can be resampled to.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CandleInterval {
+    /// One minute.
+    Minute,
+    /// Five minutes.
+    FiveMinutes,
+    /// One hour.
+    Hour,
+    /// One day.
+    Day,
+}
+
+impl CandleInterval {
+    /// Returns the length of the interval in seconds.
+    pub fn seconds(&self) -> i64 {
+        match self {
+            CandleInterval::Minute => 60,
+            CandleInterval::FiveMinutes => 5 * 60,
+            CandleInterval::Hour => 60 * 60,
+            CandleInterval::Day => 24 * 60 * 60,
+        }
+    }
+
+    /// Returns the start of the bucket containing `ts`, a Unix timestamp in seconds.
+    ///
+    /// Buckets are aligned to the wall clock of a timezone `utc_offset_secs` seconds ahead of UTC,
+    /// so with an offset of `-5 * 3600` daily buckets start at midnight in New York standard time.
+    ///
+    /// # Arguments
+    ///
+    /// * `ts` - The Unix timestamp to find the bucket for.
+    /// * `utc_offset_secs` - The offset of the timezone buckets are aligned to, in seconds east of UTC.
+    ///
+    pub fn bucket_start(&self, ts: i64, utc_offset_secs: i32) -> i64 {
+        let offset = utc_offset_secs as i64;
+        let seconds = self.seconds();
+        (ts + offset).div_euclid(seconds) * seconds - offset
+    }
+}
+
+/// Resamples candles into larger buckets, e.g. one-minute candles into hourly or daily ones.
+///
+/// The exchange only serves candles for a few fixed intervals; this aggregates them into any
+/// [`CandleInterval`]. Each resampled candle opens at the first open of its bucket, closes at the
+/// last close, spans the highest high and lowest low, and sums the volume. Empty buckets are skipped.
+///
+/// The input doesn't have to be sorted. Source candles should be no larger than `interval` and aligned
+/// to its buckets, otherwise a candle is attributed entirely to the bucket its start falls into.
+///
+/// # Arguments
+///
+/// * `candles` - The candles to resample.
+/// * `interval` - The bucket size of the resampled candles.
+/// * `utc_offset_secs` - The offset, in seconds east of UTC, of the timezone whose wall clock buckets
+///   are aligned to. Use `0` for UTC. Fixed offsets don't follow daylight saving time changes.
+///
+/// # Returns
+///
+/// The resampled candles, sorted by start time.
+///
+/// # Example
+///
+/// ```
+/// use kalshi::{resample_candles, CandleInterval};
+/// // Daily candles for a market in New York, from one-minute candles
+/// let daily = resample_candles(&minute_candles, CandleInterval::Day, -5 * 3600);
+/// ```
+///
+pub fn resample_candles(
+    candles: &[Candle],
+    interval: CandleInterval,
+    utc_offset_secs: i32,
+) -> Vec<Candle> {
+    let mut sorted = candles.to_vec();
+    sorted.sort_by_key(|candle| candle.start_ts);
+
+    let mut resampled: Vec<Candle> = Vec::new();
+    for candle in sorted {
+        let start_ts = interval.bucket_start(candle.start_ts, utc_offset_secs);
+        match resampled.last_mut() {
+            Some(bucket) if bucket.start_ts == start_ts => {
+                bucket.high = bucket.high.max(candle.high);
+                bucket.low = bucket.low.min(candle.low);
+                bucket.close = candle.close;
+                bucket.volume += candle.volume;
+            }
+            _ => resampled.push(Candle { start_ts, ..candle }),
+        }
+    }
+    resampled
+}
+
+/// Builds candles incrementally from price observations, such as trades as they happen.
+///
+/// Observations are expected in time order. The candle of the current bucket is returned once
+/// an observation in a later bucket arrives; buckets without observations produce no candle.
+/// An observation older than the current bucket is folded into the current candle.
+///
+/// # Example
+///
+/// ```
+/// use kalshi::{CandleBuilder, CandleInterval};
+/// let mut builder = CandleBuilder::new(CandleInterval::Minute, 0);
+/// for (ts, price, count) in live_trades {
+///     if let Some(candle) = builder.push(ts, price, count) {
+///         println!("closed candle {:?}", candle);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CandleBuilder {
+    interval: CandleInterval,
+    utc_offset_secs: i32,
+    current: Option<Candle>,
+}
+
+impl CandleBuilder {
+    /// Creates a builder producing candles of `interval`, aligned to the wall clock of a timezone
+    /// `utc_offset_secs` seconds ahead of UTC.
+    pub fn new(interval: CandleInterval, utc_offset_secs: i32) -> CandleBuilder {
+        CandleBuilder {
+            interval,
+            utc_offset_secs,
+            current: None,
+        }
+    }
+
+    /// Adds an observation, returning the previous candle if the observation starts a new bucket.
+    ///
+    /// # Arguments
+    ///
+    /// * `ts` - The Unix timestamp of the observation, in seconds.
+    /// * `price` - The observed price, in cents.
+    /// * `volume` - The number of contracts traded in the observation.
+    ///
+    pub fn push(&mut self, ts: i64, price: i32, volume: i64) -> Option<Candle> {
+        let start_ts = self.interval.bucket_start(ts, self.utc_offset_secs);
+
+        if let Some(candle) = self.current.as_mut() {
+            if start_ts <= candle.start_ts {
+                candle.high = candle.high.max(price);
+                candle.low = candle.low.min(price);
+                candle.close = price;
+                candle.volume += volume;
+                return None;
+            }
+        }
+
+        self.current.replace(Candle {
+            start_ts,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume,
+        })
+    }
+
+    /// Returns the candle of the current bucket, which may still change.
+    pub fn current(&self) -> Option<&Candle> {
+        self.current.as_ref()
+    }
+
+    /// Consumes the builder, returning the candle of the current bucket.
+    pub fn finish(self) -> Option<Candle> {
+        self.current
+    }
+}
+
+/// Aggregates historical trades into candles of the 'Yes' price, e.g. to backfill data older than the
+/// exchange's candlestick window.
+///
+/// Only available with the `chrono` feature enabled, which parses the trades' timestamps.
+/// The trades don't have to be sorted; the exchange returns them newest first.
+///
+/// # Arguments
+///
+/// * `trades` - The trades to aggregate.
+/// * `interval` - The bucket size of the candles.
+/// * `utc_offset_secs` - The offset, in seconds east of UTC, of the timezone buckets are aligned to.
+///
+/// # Returns
+///
+/// The candles, sorted by start time. Buckets without trades produce no candle.
+///
+#[cfg(feature = "chrono")]
+pub fn candles_from_trades(
+    trades: &[Trade],
+    interval: CandleInterval,
+    utc_offset_secs: i32,
+) -> Vec<Candle> {
+    let mut points: Vec<(i64, i32, i64)> = trades
+        .iter()
+        .map(|trade| {
+            (
+                trade.created_time.timestamp(),
+                trade.yes_price,
+                trade.count as i64,
+            )
+        })
+        .collect();
+    points.sort_by_key(|(ts, _, _)| *ts);
+
+    build_candles(points, interval, utc_offset_secs)
+}
+
+/// Aggregates market history snapshots into candles of the last traded 'Yes' price.
+///
+/// A candle's volume is the growth of the market's total volume during its bucket.
+/// The snapshots don't have to be sorted.
+///
+/// # Arguments
+///
+/// * `snapshots` - The snapshots to aggregate, e.g. from [`Kalshi::download_market_history`](crate::Kalshi::download_market_history).
+/// * `interval` - The bucket size of the candles.
+/// * `utc_offset_secs` - The offset, in seconds east of UTC, of the timezone buckets are aligned to.
+///
+/// # Returns
+///
+/// The candles, sorted by start time. Buckets without snapshots produce no candle.
+///
+pub fn candles_from_snapshots(
+    snapshots: &[Snapshot],
+    interval: CandleInterval,
+    utc_offset_secs: i32,
+) -> Vec<Candle> {
+    let mut sorted: Vec<&Snapshot> = snapshots.iter().collect();
+    sorted.sort_by_key(|snapshot| snapshot.ts);
+
+    let mut previous_volume: Option<i64> = None;
+    let points = sorted.into_iter().map(|snapshot| {
+        let volume = snapshot.volume as i64;
+        let traded = previous_volume.map_or(0, |previous| (volume - previous).max(0));
+        previous_volume = Some(volume);
+        (snapshot.ts, snapshot.yes_price.value() as i32, traded)
+    });
+
+    build_candles(points, interval, utc_offset_secs)
+}
+
+/// Trading volume and open interest of a market over one time bucket.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct VolumePoint {
+    /// Unix timestamp, in seconds, of the start of the bucket.
+    pub start_ts: i64,
+    /// Number of contracts traded during the bucket.
+    pub volume: i64,
+    /// The market's total volume at the end of the bucket, or `None` before the first snapshot.
+    pub cumulative_volume: Option<i64>,
+    /// Open interest at the end of the bucket, or `None` before the first snapshot.
+    pub open_interest: Option<i64>,
+}
+
+/// Builds an aligned volume and open interest series from market history snapshots.
+///
+/// Unlike [`candles_from_snapshots`], every bucket between `min_ts` and `max_ts` gets a point, so
+/// series from several markets line up index by index. A bucket's volume is the growth of the market's
+/// total volume during it; the first snapshot only sets the baseline. Buckets without snapshots trade
+/// nothing and carry the previous totals and open interest forward. The snapshots don't have to be sorted.
+///
+/// # Arguments
+///
+/// * `snapshots` - The snapshots to aggregate, e.g. from [`Kalshi::download_market_history`](crate::Kalshi::download_market_history).
+/// * `min_ts` - The start of the series, in seconds since the Unix epoch. Its bucket is the first point.
+/// * `max_ts` - The end of the series, in seconds since the Unix epoch. Its bucket is the last point.
+/// * `interval` - The bucket size of the series.
+/// * `utc_offset_secs` - The offset, in seconds east of UTC, of the timezone buckets are aligned to.
+///
+/// # Returns
+///
+/// One point per bucket, sorted by start time. Empty if `max_ts` is before `min_ts`.
+///
+pub fn volume_series_from_snapshots(
+    snapshots: &[Snapshot],
+    min_ts: i64,
+    max_ts: i64,
+    interval: CandleInterval,
+    utc_offset_secs: i32,
+) -> Vec<VolumePoint> {
+    let mut sorted: Vec<&Snapshot> = snapshots
+        .iter()
+        .filter(|snapshot| snapshot.ts <= max_ts)
+        .collect();
+    sorted.sort_by_key(|snapshot| snapshot.ts);
+    let mut snapshots = sorted.into_iter().peekable();
+
+    let mut cumulative_volume: Option<i64> = None;
+    let mut open_interest: Option<i64> = None;
+    let mut series = Vec::new();
+    if max_ts < min_ts {
+        return series;
+    }
+
+    let mut start_ts = interval.bucket_start(min_ts, utc_offset_secs);
+    let last_start = interval.bucket_start(max_ts, utc_offset_secs);
+    while start_ts <= last_start {
+        let end_ts = start_ts + interval.seconds();
+        let mut volume = 0;
+        while let Some(snapshot) = snapshots.next_if(|snapshot| snapshot.ts < end_ts) {
+            let total = snapshot.volume as i64;
+            if snapshot.ts >= start_ts {
+                volume += cumulative_volume.map_or(0, |previous| (total - previous).max(0));
+            }
+            cumulative_volume = Some(total);
+            open_interest = Some(snapshot.open_interest as i64);
+        }
+
+        series.push(VolumePoint {
+            start_ts,
+            volume,
+            cumulative_volume,
+            open_interest,
+        });
+        start_ts = end_ts;
+    }
+    series
+}
+
+fn build_candles(
+    points: impl IntoIterator<Item = (i64, i32, i64)>,
+    interval: CandleInterval,
+    utc_offset_secs: i32,
+) -> Vec<Candle> {
+    let mut builder = CandleBuilder::new(interval, utc_offset_secs);
+    let mut candles: Vec<Candle> = points
+        .into_iter()
+        .filter_map(|(ts, price, volume)| builder.push(ts, price, volume))
+        .collect();
+    candles.extend(builder.finish());
+    candles
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        candles_from_snapshots, resample_candles, volume_series_from_snapshots, Candle,
+        CandleInterval, VolumePoint,
+    };
+    use crate::fixtures;
+    use crate::market::Snapshot;
+    use crate::price::Cents;
+
+    fn candle(start_ts: i64, open: i32, high: i32, low: i32, close: i32, volume: i64) -> Candle {
+        Candle {
+            start_ts,
+            open,
+            high,
+            low,
+            close,
+            volume,
+        }
+    }
+
+    #[test]
+    fn test_resample_candles_aggregates_ohlcv_in_local_buckets() {
+        // 2024-01-02 04:59 and 05:00 UTC straddle midnight in UTC-5.
+        let candles = [
+            candle(1_704_171_600, 40, 45, 38, 44, 10),
+            candle(1_704_171_540, 30, 42, 29, 40, 5),
+            candle(1_704_171_540 - 60, 20, 31, 20, 30, 7),
+        ];
+
+        let daily = resample_candles(&candles, CandleInterval::Day, -5 * 3600);
+
+        assert_eq!(
+            daily,
+            vec![
+                candle(1_704_171_600 - 86_400, 20, 42, 20, 40, 12),
+                candle(1_704_171_600, 40, 45, 38, 44, 10),
+            ]
+        );
+
+        let utc = resample_candles(&candles, CandleInterval::Day, 0);
+        assert_eq!(utc.len(), 1);
+        assert_eq!(utc[0].start_ts, 1_704_153_600);
+    }
+
+    #[test]
+    fn test_candles_from_snapshots_use_volume_growth() {
+        let snapshot = |ts: i64, yes_price: i64, volume: i32| Snapshot {
+            yes_price: Cents(yes_price),
+            volume,
+            ..fixtures::snapshot(ts)
+        };
+        let snapshots = [
+            snapshot(65, 42, 130),
+            snapshot(10, 40, 100),
+            snapshot(50, 45, 120),
+            snapshot(130, 41, 130),
+        ];
+
+        let candles = candles_from_snapshots(&snapshots, CandleInterval::Minute, 0);
+
+        assert_eq!(
+            candles,
+            vec![
+                candle(0, 40, 45, 40, 45, 20),
+                candle(60, 42, 42, 42, 42, 10),
+                candle(120, 41, 41, 41, 41, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_volume_series_fills_every_bucket() {
+        let snapshot = |ts: i64, volume: i32, open_interest: i32| Snapshot {
+            volume,
+            open_interest,
+            ..fixtures::snapshot(ts)
+        };
+        let snapshots = [
+            snapshot(200, 140, 60),
+            snapshot(70, 100, 40),
+            snapshot(30, 90, 35),
+            snapshot(110, 115, 50),
+        ];
+        let point = |start_ts: i64, volume: i64, cumulative: i64, open_interest: i64| VolumePoint {
+            start_ts,
+            volume,
+            cumulative_volume: Some(cumulative),
+            open_interest: Some(open_interest),
+        };
+
+        let series = volume_series_from_snapshots(&snapshots, 15, 240, CandleInterval::Minute, 0);
+
+        assert_eq!(
+            series,
+            vec![
+                point(0, 0, 90, 35),
+                point(60, 25, 115, 50),
+                point(120, 0, 115, 50),
+                point(180, 25, 140, 60),
+                point(240, 0, 140, 60),
+            ]
+        );
+
+        let before = volume_series_from_snapshots(&snapshots, -60, -1, CandleInterval::Minute, 0);
+        assert_eq!(before.len(), 1);
+        assert_eq!(before[0].open_interest, None);
+    }
+}
@@ -0,0 +1,410 @@
+use crate::kalshi_error::*;
+use crate::market::{Category, Event, SeriesTree};
+use crate::portfolio::{MarketPosition, Side};
+use std::collections::{HashMap, HashSet};
+
+/// The event, series and category a market belongs to, as registered with a [`ConcentrationLimits`].
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarketGroup {
+    /// Ticker of the market's event.
+    pub event_ticker: String,
+    /// Ticker of the series the market's event belongs to.
+    pub series_ticker: String,
+    /// Category of the market's event.
+    pub category: Category,
+}
+
+/// Caps the share of equity exposed to a single category or series of markets.
+///
+/// The exchange only limits the size of each market on its own, so a bot spread over many weather markets
+/// can end up with most of its equity riding on one forecast. Caps are fractions of equity, checked with
+/// [`check_order`](ConcentrationLimits::check_order) before sending an order. Markets are attributed to their
+/// series and category through the events registered with [`add_event`](ConcentrationLimits::add_event) or
+/// [`add_series_tree`](ConcentrationLimits::add_series_tree).
+///
+/// Exposure is measured as the cost of the positions held, i.e. [`MarketPosition::market_exposure`].
+/// 'Yes' and 'No' exposure in the same market always net, since buying one side of a market closes a position
+/// on the other. Markets declared as economically linked with [`link_markets`](ConcentrationLimits::link_markets)
+/// or [`link_event`](ConcentrationLimits::link_event) are netted too: within a link, 'Yes' exposure offsets 'No'
+/// exposure, so a hedged pair doesn't count twice against a cap.
+///
+/// # Example
+///
+/// ```
+/// use kalshi::{Category, ConcentrationLimits};
+/// let mut limits = ConcentrationLimits::new()
+///     .with_category_cap(Category::ClimateAndWeather, 0.2)
+///     .with_series_cap("KXHIGHNY", 0.05);
+/// limits.add_series_tree(&kalshi_instance.get_series_tree(&"KXHIGHNY".to_string()).await?);
+///
+/// let (_, _, positions) = kalshi_instance.get_user_positions(None, None, None, None, None).await?;
+/// limits.check_order(&ticker, Side::Yes, 10 * 45, &positions, equity)?;
+/// kalshi_instance.create_order(/* ... */).await?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConcentrationLimits {
+    category_caps: HashMap<Category, f64>,
+    series_caps: HashMap<String, f64>,
+    groups: HashMap<String, MarketGroup>,
+    // The link each linked market's exposure is netted in, by ticker.
+    links: HashMap<String, String>,
+}
+
+impl ConcentrationLimits {
+    /// Creates limits without any caps or registered markets.
+    pub fn new() -> ConcentrationLimits {
+        ConcentrationLimits::default()
+    }
+
+    /// Caps the exposure to markets of `category` at `max_fraction` of equity.
+    pub fn with_category_cap(
+        mut self,
+        category: Category,
+        max_fraction: f64,
+    ) -> ConcentrationLimits {
+        self.category_caps.insert(category, max_fraction);
+        self
+    }
+
+    /// Caps the exposure to markets of the series `series_ticker` at `max_fraction` of equity.
+    pub fn with_series_cap(
+        mut self,
+        series_ticker: &str,
+        max_fraction: f64,
+    ) -> ConcentrationLimits {
+        self.series_caps
+            .insert(series_ticker.to_string(), max_fraction);
+        self
+    }
+
+    /// Registers the event, series and category of a market.
+    pub fn add_market(&mut self, ticker: &str, group: MarketGroup) {
+        self.groups.insert(ticker.to_string(), group);
+    }
+
+    /// Registers the nested markets of an event, e.g. one fetched with `with_nested_markets`.
+    pub fn add_event(&mut self, event: &Event) {
+        for market in event.markets.iter().flatten() {
+            self.add_market(&market.ticker, group_of(event));
+        }
+    }
+
+    /// Registers every market of a series, as returned by [`Kalshi::get_series_tree`](crate::Kalshi::get_series_tree).
+    pub fn add_series_tree(&mut self, tree: &SeriesTree) {
+        for event in &tree.events {
+            for market in &event.markets {
+                self.add_market(&market.ticker, group_of(&event.event));
+            }
+        }
+    }
+
+    /// Declares `tickers` as economically linked under the name `link`, so their exposure is netted.
+    ///
+    /// A market belongs to at most one link; linking it again moves it to the new link.
+    pub fn link_markets(&mut self, link: &str, tickers: &[String]) {
+        for ticker in tickers {
+            self.links.insert(ticker.clone(), link.to_string());
+        }
+    }
+
+    /// Links the nested markets of an event under the event's ticker.
+    pub fn link_event(&mut self, event: &Event) {
+        let tickers: Vec<String> = event
+            .markets
+            .iter()
+            .flatten()
+            .map(|market| market.ticker.clone())
+            .collect();
+        self.link_markets(&event.event_ticker, &tickers);
+    }
+
+    /// Returns the event, series and category registered for a market, if any.
+    pub fn group(&self, ticker: &str) -> Option<&MarketGroup> {
+        self.groups.get(ticker)
+    }
+
+    /// Checks that adding `order_cost` of exposure in `ticker` keeps every capped category and series within its cap.
+    ///
+    /// Positions in markets that aren't registered can't be attributed and don't count toward any cap.
+    /// An order that lowers the exposure of a capped category or series, e.g. by buying the side opposite
+    /// to a held or linked position, is allowed even if the exposure stays above the cap.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticker` - The market the order is for.
+    /// * `side` - The side the order buys.
+    /// * `order_cost` - The most the order can add to the exposure, in cents, e.g. its count times its price.
+    /// * `positions` - The positions currently held.
+    /// * `equity` - The account's equity in cents, e.g. from [`marked_equity`](crate::marked_equity).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())`: The order stays within every cap that applies to its market.
+    /// - `Err(KalshiError)`: A `UserInputError` naming the breached cap, or if caps are set and `ticker` isn't registered.
+    ///
+    pub fn check_order(
+        &self,
+        ticker: &str,
+        side: Side,
+        order_cost: i64,
+        positions: &[MarketPosition],
+        equity: i64,
+    ) -> Result<(), KalshiError> {
+        let order = (ticker, side, order_cost.max(0));
+        self.check_caps(ticker, positions, positions, Some(order), equity)
+    }
+
+    /// Checks that moving from the `before` positions to the `after` positions keeps every capped category
+    /// and series of `ticker` within its cap, e.g. for the projected portfolio of an order that fills completely.
+    ///
+    /// Follows the same rules as [`check_order`](ConcentrationLimits::check_order), so lowering a capped
+    /// exposure is allowed even if it stays above the cap.
+    pub fn check_positions(
+        &self,
+        ticker: &str,
+        before: &[MarketPosition],
+        after: &[MarketPosition],
+        equity: i64,
+    ) -> Result<(), KalshiError> {
+        self.check_caps(ticker, before, after, None, equity)
+    }
+
+    fn check_caps(
+        &self,
+        ticker: &str,
+        before: &[MarketPosition],
+        after: &[MarketPosition],
+        order: Option<(&str, Side, i64)>,
+        equity: i64,
+    ) -> Result<(), KalshiError> {
+        if self.category_caps.is_empty() && self.series_caps.is_empty() {
+            return Ok(());
+        }
+
+        let group = self.group(ticker).ok_or_else(|| {
+            KalshiError::UserInputError(format!(
+                "Market {} has no registered series or category to check concentration limits against",
+                ticker
+            ))
+        })?;
+
+        if let Some(cap) = self.category_caps.get(&group.category) {
+            let in_category = |other: &MarketGroup| other.category == group.category;
+            check_cap(
+                "category",
+                &group.category.to_string(),
+                *cap,
+                self.exposure(before, None, in_category),
+                self.exposure(after, order, in_category),
+                equity,
+            )?;
+        }
+
+        if let Some(cap) = self.series_caps.get(&group.series_ticker) {
+            let in_series = |other: &MarketGroup| other.series_ticker == group.series_ticker;
+            check_cap(
+                "series",
+                &group.series_ticker,
+                *cap,
+                self.exposure(before, None, in_series),
+                self.exposure(after, order, in_series),
+                equity,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the exposure of the positions to each category, netting linked markets.
+    /// Positions in markets that aren't registered are left out.
+    pub fn exposure_by_category(&self, positions: &[MarketPosition]) -> HashMap<Category, i64> {
+        self.exposure_by(positions, |group| group.category.clone())
+    }
+
+    /// Returns the exposure of the positions to each event, by event ticker, netting linked markets.
+    /// Positions in markets that aren't registered are left out.
+    pub fn exposure_by_event(&self, positions: &[MarketPosition]) -> HashMap<String, i64> {
+        self.exposure_by(positions, |group| group.event_ticker.clone())
+    }
+
+    fn exposure_by<K: Eq + std::hash::Hash + Clone>(
+        &self,
+        positions: &[MarketPosition],
+        key_of: impl Fn(&MarketGroup) -> K,
+    ) -> HashMap<K, i64> {
+        let keys: HashSet<K> = positions
+            .iter()
+            .filter_map(|position| self.group(&position.ticker))
+            .map(&key_of)
+            .collect();
+
+        keys.into_iter()
+            .map(|key| {
+                let exposure = self.exposure(positions, None, |group| key_of(group) == key);
+                (key, exposure)
+            })
+            .collect()
+    }
+
+    // Sums the exposure of the positions, plus an optional order, in markets matching `in_group`.
+    // Each link, and each market outside of a link, counts the absolute value of its 'Yes' minus its 'No' exposure.
+    fn exposure(
+        &self,
+        positions: &[MarketPosition],
+        order: Option<(&str, Side, i64)>,
+        in_group: impl Fn(&MarketGroup) -> bool,
+    ) -> i64 {
+        let held = positions.iter().filter_map(|position| {
+            let side = position.direction()?;
+            Some((
+                position.ticker.as_str(),
+                side,
+                position.market_exposure.max(0),
+            ))
+        });
+
+        let mut linked: HashMap<&str, i64> = HashMap::new();
+        let mut unlinked: HashMap<&str, i64> = HashMap::new();
+        for (ticker, side, cost) in held.chain(order) {
+            if !self.group(ticker).is_some_and(&in_group) {
+                continue;
+            }
+            let net = match self.links.get(ticker) {
+                Some(link) => linked.entry(link.as_str()).or_default(),
+                None => unlinked.entry(ticker).or_default(),
+            };
+            *net = match side {
+                Side::Yes => net.saturating_add(cost),
+                Side::No => net.saturating_sub(cost),
+            };
+        }
+
+        linked
+            .values()
+            .chain(unlinked.values())
+            .fold(0, |total: i64, net| {
+                total.saturating_add(net.saturating_abs())
+            })
+    }
+}
+
+fn group_of(event: &Event) -> MarketGroup {
+    MarketGroup {
+        event_ticker: event.event_ticker.clone(),
+        series_ticker: event.series_ticker.clone(),
+        category: event.category.clone(),
+    }
+}
+
+fn check_cap(
+    kind: &str,
+    name: &str,
+    max_fraction: f64,
+    before: i64,
+    after: i64,
+    equity: i64,
+) -> Result<(), KalshiError> {
+    let limit = max_fraction * equity.max(0) as f64;
+    if after as f64 > limit && after > before {
+        return Err(KalshiError::UserInputError(format!(
+            "Order would raise exposure to {} {} to {} cents, above its cap of {} cents ({}% of equity)",
+            kind,
+            name,
+            after,
+            limit.floor() as i64,
+            max_fraction * 100.0
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::position;
+
+    fn limits() -> ConcentrationLimits {
+        let group = |series: &str, category: &str| MarketGroup {
+            event_ticker: format!("{}-EV", series),
+            series_ticker: series.to_string(),
+            category: Category::from(category),
+        };
+        let mut limits = ConcentrationLimits::new()
+            .with_category_cap(Category::ClimateAndWeather, 0.2)
+            .with_series_cap("KXHIGHNY", 0.1);
+        limits.add_market("NY-1", group("KXHIGHNY", "Climate and Weather"));
+        limits.add_market("NY-2", group("KXHIGHNY", "Climate and Weather"));
+        limits.add_market("CHI-1", group("KXHIGHCHI", "Climate and Weather"));
+        limits.add_market("FED-1", group("FED", "Economics"));
+        limits
+    }
+
+    #[test]
+    fn test_check_order_enforces_category_and_series_caps() {
+        let limits = limits();
+        let positions = [
+            position("NY-1", 10, 800),
+            position("CHI-1", 10, 900),
+            position("FED-1", 10, 5_000),
+        ];
+
+        // 10_000 of equity: weather holds 1_700 of a 2_000 cap, NY holds 800 of a 1_000 cap.
+        assert!(limits
+            .check_order("CHI-1", Side::Yes, 300, &positions, 10_000)
+            .is_ok());
+        assert!(limits
+            .check_order("CHI-1", Side::Yes, 301, &positions, 10_000)
+            .is_err());
+        assert!(limits
+            .check_order("NY-2", Side::Yes, 201, &positions, 10_000)
+            .is_err());
+
+        // Buying 'No' in NY-1 closes part of the 'Yes' position held there.
+        assert!(limits
+            .check_order("NY-1", Side::No, 201, &positions, 10_000)
+            .is_ok());
+        assert!(limits
+            .check_order("NY-1", Side::No, 1_800, &positions, 10_000)
+            .is_ok());
+        assert!(limits
+            .check_order("NY-1", Side::No, 1_801, &positions, 10_000)
+            .is_err());
+        assert!(limits
+            .check_order("FED-1", Side::Yes, 5_000, &positions, 10_000)
+            .is_ok());
+        assert!(limits
+            .check_order("UNKNOWN", Side::Yes, 1, &positions, 10_000)
+            .is_err());
+        assert!(ConcentrationLimits::new()
+            .check_order("UNKNOWN", Side::Yes, 1, &positions, 10_000)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_linked_markets_net_opposite_sides() {
+        let mut limits = limits();
+        let positions = [position("NY-1", 10, 800), position("NY-2", -5, 300)];
+
+        // Unlinked, the series holds 1_100 of a 1_000 cap and any new exposure is rejected.
+        assert!(limits
+            .check_order("NY-2", Side::No, 1, &positions, 10_000)
+            .is_err());
+
+        // Linked, the 'No' position offsets the 'Yes' one: 500 net, so 500 more fits on the 'Yes' side
+        // and up to 1_500 on the 'No' side before the net flips past the cap.
+        limits.link_markets("NY", &["NY-1".to_string(), "NY-2".to_string()]);
+        assert!(limits
+            .check_order("NY-1", Side::Yes, 500, &positions, 10_000)
+            .is_ok());
+        assert!(limits
+            .check_order("NY-1", Side::Yes, 501, &positions, 10_000)
+            .is_err());
+        assert!(limits
+            .check_order("NY-2", Side::No, 1_500, &positions, 10_000)
+            .is_ok());
+        assert!(limits
+            .check_order("NY-2", Side::No, 1_501, &positions, 10_000)
+            .is_err());
+    }
+}
@@ -0,0 +1,268 @@
+use crate::kalshi_error::*;
+use reqwest::header::{HeaderMap, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use std::collections::{HashMap, VecDeque};
+
+/// The result of a conditional request for market data.
+///
+/// Returned by the `*_if_modified` methods, which send the validators a [`ConditionalCache`] holds from
+/// the previous response for the same URL and let the exchange answer with `304 Not Modified` when nothing changed.
+/// A not modified response has no body and is cheaper for both the client and the rate limit.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Conditional<T> {
+    /// The resource changed since the last request, or this is the first request for it.
+    Modified(T),
+    /// The resource is unchanged since the last request; the previously returned value is still current.
+    NotModified,
+}
+
+impl<T> Conditional<T> {
+    /// Returns `true` if the exchange returned a new value.
+    pub fn is_modified(&self) -> bool {
+        matches!(self, Conditional::Modified(_))
+    }
+
+    /// Returns the new value, or `None` if the resource was not modified.
+    pub fn modified(self) -> Option<T> {
+        match self {
+            Conditional::Modified(value) => Some(value),
+            Conditional::NotModified => None,
+        }
+    }
+
+    /// Maps a modified value with `f`, leaving `NotModified` untouched.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Conditional<U> {
+        match self {
+            Conditional::Modified(value) => Conditional::Modified(f(value)),
+            Conditional::NotModified => Conditional::NotModified,
+        }
+    }
+}
+
+/// Number of URLs a [`ConditionalCache`] keeps validators for unless configured otherwise.
+const DEFAULT_CACHE_CAPACITY: usize = 256;
+
+/// The validators of earlier conditional requests, owned by the polling loop that sent them.
+///
+/// The `*_if_modified` methods send the validators stored for a URL and only return
+/// [`Conditional::NotModified`] to the cache that received the full value before. Keep one cache per
+/// polling loop, next to the values it holds, rather than sharing one between loops.
+///
+/// The cache holds validators for a bounded number of URLs. Once full, the URL requested least
+/// recently is forgotten, so its next request returns the full value again.
+///
+/// # Example
+///
+/// ```
+/// let mut cache = ConditionalCache::new();
+/// loop {
+///     if let Conditional::Modified(market) = kalshi_instance.get_single_market_if_modified(&ticker, &mut cache).await? {
+///         println!("New yes bid: {}", market.yes_bid);
+///     }
+///     tokio::time::sleep(Duration::from_secs(1)).await;
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConditionalCache {
+    validators: HashMap<String, Validator>,
+    recent: VecDeque<String>,
+    capacity: usize,
+}
+
+impl Default for ConditionalCache {
+    fn default() -> Self {
+        ConditionalCache::new()
+    }
+}
+
+impl ConditionalCache {
+    /// Creates an empty cache holding validators for up to 256 URLs.
+    pub fn new() -> ConditionalCache {
+        ConditionalCache::with_capacity(DEFAULT_CACHE_CAPACITY)
+    }
+
+    /// Creates an empty cache holding validators for up to `capacity` URLs.
+    ///
+    /// A capacity of zero keeps nothing, so every request returns the full value.
+    pub fn with_capacity(capacity: usize) -> ConditionalCache {
+        ConditionalCache {
+            validators: HashMap::new(),
+            recent: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    /// Returns the number of URLs validators are stored for.
+    pub fn len(&self) -> usize {
+        self.validators.len()
+    }
+
+    /// Returns `true` if no validators are stored.
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+
+    /// Forgets every stored validator, so the next request for each resource returns its full value.
+    ///
+    /// Useful after dropping the values the polling loop kept from earlier responses.
+    pub fn clear(&mut self) {
+        self.validators.clear();
+        self.recent.clear();
+    }
+
+    /// Adds the validators stored for `url` to a request, making it conditional.
+    pub(crate) fn apply(
+        &self,
+        request: reqwest::RequestBuilder,
+        url: &str,
+    ) -> reqwest::RequestBuilder {
+        match self.validators.get(url) {
+            Some(validator) => {
+                let mut request = request;
+                if let Some(etag) = &validator.etag {
+                    request = request.header(IF_NONE_MATCH, etag);
+                }
+                if let Some(last_modified) = &validator.last_modified {
+                    request = request.header(IF_MODIFIED_SINCE, last_modified);
+                }
+                request
+            }
+            None => request,
+        }
+    }
+
+    /// Reads the response to a conditional request, storing its validators for the next request to `url`.
+    pub(crate) async fn read<T: DeserializeOwned>(
+        &mut self,
+        url: &str,
+        response: reqwest::Response,
+    ) -> Result<Conditional<T>, KalshiError> {
+        if response.status() == StatusCode::NOT_MODIFIED {
+            self.touch(url);
+            return Ok(Conditional::NotModified);
+        }
+
+        let validator = Validator::from_headers(response.headers());
+        let value: T = response.error_for_status()?.json().await?;
+
+        match validator {
+            Some(validator) => self.insert(url, validator),
+            None => self.remove(url),
+        }
+
+        Ok(Conditional::Modified(value))
+    }
+
+    fn insert(&mut self, url: &str, validator: Validator) {
+        self.remove(url);
+        if self.capacity == 0 {
+            return;
+        }
+
+        while self.recent.len() >= self.capacity {
+            if let Some(oldest) = self.recent.pop_front() {
+                self.validators.remove(&oldest);
+            }
+        }
+
+        self.recent.push_back(url.to_string());
+        self.validators.insert(url.to_string(), validator);
+    }
+
+    // Marks `url` as the most recently requested URL, if validators are stored for it.
+    fn touch(&mut self, url: &str) {
+        if let Some(index) = self.recent.iter().position(|recent| recent == url) {
+            if let Some(url) = self.recent.remove(index) {
+                self.recent.push_back(url);
+            }
+        }
+    }
+
+    fn remove(&mut self, url: &str) {
+        if self.validators.remove(url).is_some() {
+            self.recent.retain(|recent| recent != url);
+        }
+    }
+}
+
+/// The `ETag` and `Last-Modified` headers of a response.
+#[derive(Debug, Clone)]
+struct Validator {
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl Validator {
+    fn from_headers(headers: &HeaderMap) -> Option<Validator> {
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+
+        let validator = Validator {
+            etag: header(ETAG),
+            last_modified: header(LAST_MODIFIED),
+        };
+
+        if validator.etag.is_none() && validator.last_modified.is_none() {
+            return None;
+        }
+        Some(validator)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn validator(etag: &str) -> Validator {
+        Validator {
+            etag: Some(etag.to_string()),
+            last_modified: None,
+        }
+    }
+
+    #[test]
+    fn test_conditional_cache_forgets_least_recent_url() {
+        let mut cache = ConditionalCache::with_capacity(2);
+        cache.insert("a", validator("1"));
+        cache.insert("b", validator("2"));
+        cache.insert("a", validator("3"));
+        cache.insert("c", validator("4"));
+
+        assert_eq!(cache.len(), 2);
+        assert!(!cache.validators.contains_key("b"));
+        assert_eq!(cache.validators["a"].etag.as_deref(), Some("3"));
+        assert_eq!(cache.validators["c"].etag.as_deref(), Some("4"));
+
+        cache.remove("a");
+        assert_eq!(cache.recent, VecDeque::from(vec!["c".to_string()]));
+
+        let mut disabled = ConditionalCache::with_capacity(0);
+        disabled.insert("a", validator("1"));
+        assert!(disabled.is_empty());
+    }
+
+    #[test]
+    fn test_conditional_cache_keeps_urls_answered_not_modified() {
+        let mut cache = ConditionalCache::with_capacity(2);
+        cache.insert("a", validator("1"));
+        cache.insert("b", validator("2"));
+
+        // A 304 for "a" makes "b" the least recently requested URL.
+        cache.touch("a");
+        cache.touch("unknown");
+        cache.insert("c", validator("3"));
+
+        assert!(cache.validators.contains_key("a"));
+        assert!(!cache.validators.contains_key("b"));
+        assert_eq!(
+            cache.recent,
+            VecDeque::from(vec!["a".to_string(), "c".to_string()])
+        );
+    }
+}
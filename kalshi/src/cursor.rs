@@ -0,0 +1,101 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+
+/// A pagination cursor returned by the listing endpoints, such as markets, events, orders, fills and settlements.
+///
+/// Pass it back to the same method to request the next page. The exchange marks the last page with an
+/// empty or missing cursor, which deserializes to a cursor for which [`is_end`](Cursor::is_end) is `true`.
+///
+/// # Example
+///
+/// ```
+/// let mut cursor = None;
+/// loop {
+///     let (next, markets) = kalshi_instance
+///         .get_multiple_markets(Some(100), cursor, None, None, None, None, None, None)
+///         .await?;
+///     println!("{} markets", markets.len());
+///     if next.is_end() {
+///         break;
+///     }
+///     cursor = Some(next);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize)]
+#[serde(transparent)]
+pub struct Cursor(String);
+
+impl Cursor {
+    /// Creates a cursor from its raw value, e.g. one persisted to resume paging later.
+    pub fn new(cursor: impl Into<String>) -> Cursor {
+        Cursor(cursor.into())
+    }
+
+    /// Returns `true` if there are no more pages after the one this cursor came with.
+    pub fn is_end(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the cursor as `None` if it marks the end, so pages can be chained with `?` or `match`.
+    pub fn into_next(self) -> Option<Cursor> {
+        if self.is_end() {
+            return None;
+        }
+        Some(self)
+    }
+
+    /// Returns the raw value of the cursor.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for Cursor {
+    fn from(cursor: String) -> Self {
+        Cursor(cursor)
+    }
+}
+
+impl From<&str> for Cursor {
+    fn from(cursor: &str) -> Self {
+        Cursor(cursor.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Cursor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let cursor = Option::<String>::deserialize(deserializer)?;
+        Ok(Cursor(cursor.unwrap_or_default()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Cursor;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct Page {
+        #[serde(default)]
+        cursor: Cursor,
+    }
+
+    #[test]
+    fn test_cursor_end_from_empty_null_or_missing() {
+        for json in [r#"{"cursor":""}"#, r#"{"cursor":null}"#, "{}"] {
+            let page: Page = serde_json::from_str(json).unwrap();
+            assert!(page.cursor.is_end(), "{}", json);
+            assert_eq!(page.cursor.into_next(), None);
+        }
+
+        let page: Page = serde_json::from_str(r#"{"cursor":"abc"}"#).unwrap();
+        assert!(!page.cursor.is_end());
+        assert_eq!(page.cursor.into_next(), Some(Cursor::new("abc")));
+    }
+}
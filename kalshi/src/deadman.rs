@@ -0,0 +1,200 @@
+use super::{Authenticated, Environment, Kalshi};
+use crate::auth::SessionEvent;
+use crate::kalshi_error::*;
+use crate::utils;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::runtime::Runtime;
+
+impl<E: Environment> Kalshi<Authenticated, E> {
+    /// Spawns a dead-man switch that cancels every resting order if the strategy stops sending heartbeats.
+    ///
+    /// The strategy calls [`Deadman::heartbeat`] from its main loop. If no heartbeat arrives within
+    /// `timeout`, e.g. because the loop hung or deadlocked, the switch trips once: it publishes
+    /// [`SessionEvent::DeadmanTriggered`], cancels all resting orders and, if `flatten` is set,
+    /// closes every open position with market orders. If any of that fails, it publishes
+    /// [`SessionEvent::DeadmanFailed`].
+    ///
+    /// The timer runs on a thread of its own and the orders are canceled from a runtime and HTTP
+    /// clients of its own, so the switch still trips when the strategy blocks every thread of its runtime.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The longest time allowed between two heartbeats. Spawning counts as the first heartbeat.
+    /// * `flatten` - Whether to also close open positions when the switch trips.
+    ///
+    /// # Returns
+    ///
+    /// A handle to send heartbeats through. Dropping it disarms the switch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let deadman = kalshi_instance.spawn_deadman(Duration::from_secs(10), false);
+    /// loop {
+    ///     deadman.heartbeat();
+    ///     run_strategy_iteration().await?;
+    /// }
+    /// ```
+    ///
+    pub fn spawn_deadman(&self, timeout: Duration, flatten: bool) -> Deadman {
+        let last_heartbeat = Arc::new(Mutex::new(Instant::now()));
+        let tripped = Arc::new(AtomicBool::new(false));
+        let (disarm, disarmed) = mpsc::channel::<()>();
+
+        let kalshi = self.clone();
+        let task_heartbeat = last_heartbeat.clone();
+        let task_tripped = tripped.clone();
+        std::thread::spawn(move || {
+            loop {
+                let deadline = *task_heartbeat.lock().unwrap() + timeout;
+                let now = Instant::now();
+                if now >= deadline {
+                    break;
+                }
+                // Nothing is ever sent, so this only returns early once the handle is dropped.
+                if let Err(RecvTimeoutError::Disconnected) = disarmed.recv_timeout(deadline - now) {
+                    return;
+                }
+            }
+
+            task_tripped.store(true, Ordering::SeqCst);
+            kalshi.publish_event(SessionEvent::DeadmanTriggered);
+            if let Err(e) = trip(&kalshi, flatten) {
+                kalshi.publish_event(SessionEvent::DeadmanFailed(e.to_string()));
+            }
+        });
+
+        Deadman {
+            last_heartbeat,
+            tripped,
+            _disarm: disarm,
+        }
+    }
+
+    /// Installs a panic hook that cancels every resting order before a panic unwinds or aborts the process.
+    ///
+    /// Keeps a crashing strategy from leaving live quotes resting in the market. The hook is process-wide:
+    /// a panic on any thread triggers it, once. It cancels the orders on a separate thread with its own
+    /// runtime and HTTP clients, waits at most `timeout` for that to finish, and then runs the panic hook
+    /// that was installed before, so panic messages are still printed.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The longest time the panicking thread waits for the orders to be canceled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let kalshi_instance = kalshi_instance.login("johndoe@example.com", "example_password").await?;
+    /// kalshi_instance.install_panic_kill_switch(Duration::from_secs(5));
+    /// ```
+    ///
+    pub fn install_panic_kill_switch(&self, timeout: Duration) {
+        let kalshi = self.clone();
+        let triggered = AtomicBool::new(false);
+        let previous = std::panic::take_hook();
+
+        std::panic::set_hook(Box::new(move |info| {
+            if !triggered.swap(true, Ordering::SeqCst) {
+                let kalshi = kalshi.clone();
+                let (sender, receiver) = mpsc::channel();
+                std::thread::spawn(move || {
+                    let result = (|| {
+                        let (kalshi, runtime) = detach(&kalshi)?;
+                        let outcome = runtime
+                            .block_on(tokio::time::timeout(timeout, kalshi.cancel_all_orders()))
+                            .map_err(|_| {
+                                KalshiError::InternalError("Canceling orders timed out".to_string())
+                            })??;
+                        Ok::<_, KalshiError>(outcome.successes().count())
+                    })();
+                    // The panicking thread may have stopped waiting already.
+                    let _ = sender.send(result);
+                });
+
+                // The canceler may itself hang, e.g. building its runtime, so the wait is bounded too.
+                match receiver.recv_timeout(timeout) {
+                    Ok(Ok(canceled)) => eprintln!("Panic kill switch canceled {} orders", canceled),
+                    Ok(Err(e)) => eprintln!("Panic kill switch failed to cancel orders: {}", e),
+                    Err(RecvTimeoutError::Timeout) => {
+                        eprintln!("Panic kill switch timed out canceling orders")
+                    }
+                    Err(RecvTimeoutError::Disconnected) => {
+                        eprintln!("Panic kill switch failed to cancel orders")
+                    }
+                }
+            }
+
+            previous(info);
+        }));
+    }
+}
+
+// Returns a copy of `kalshi` with HTTP clients of its own, and a runtime for the calling thread to
+// drive them on. Connections pooled by another runtime may be unusable from a blocked or panicking one.
+fn detach<E: Environment>(
+    kalshi: &Kalshi<Authenticated, E>,
+) -> Result<(Kalshi<Authenticated, E>, Runtime), KalshiError> {
+    let mut kalshi = kalshi.clone();
+    kalshi.client = reqwest::Client::new();
+    kalshi.order_client = utils::build_order_client();
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| KalshiError::InternalError(e.to_string()))?;
+    Ok((kalshi, runtime))
+}
+
+// Cancels every resting order and, if `flatten` is set, closes every open position.
+fn trip<E: Environment>(
+    kalshi: &Kalshi<Authenticated, E>,
+    flatten: bool,
+) -> Result<(), KalshiError> {
+    let (kalshi, runtime) = detach(kalshi)?;
+
+    let canceled = runtime.block_on(kalshi.cancel_all_orders())?;
+    let mut failed = canceled.failures().count();
+    if flatten {
+        let flattened = runtime.block_on(kalshi.flatten_positions())?;
+        failed += flattened.failures().count();
+    }
+
+    if failed > 0 {
+        return Err(KalshiError::InternalError(format!(
+            "{} orders could not be canceled or placed",
+            failed
+        )));
+    }
+    Ok(())
+}
+
+/// A handle to the dead-man switch started by [`Kalshi::spawn_deadman`].
+///
+/// The switch stays armed for as long as the handle is alive. Dropping the handle
+/// or calling [`stop`](Deadman::stop) disarms it.
+#[derive(Debug)]
+pub struct Deadman {
+    last_heartbeat: Arc<Mutex<Instant>>,
+    tripped: Arc<AtomicBool>,
+    // Dropping the sender disarms the switch.
+    _disarm: mpsc::Sender<()>,
+}
+
+impl Deadman {
+    /// Signals that the strategy is alive, restarting the timeout.
+    pub fn heartbeat(&self) {
+        *self.last_heartbeat.lock().unwrap() = Instant::now();
+    }
+
+    /// Returns `true` once the switch has tripped. Heartbeats have no effect after that.
+    pub fn is_tripped(&self) -> bool {
+        self.tripped.load(Ordering::SeqCst)
+    }
+
+    /// Disarms the switch without touching any orders.
+    pub fn stop(self) {}
+}
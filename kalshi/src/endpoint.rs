@@ -0,0 +1,335 @@
+use super::{Environment, Kalshi};
+use crate::auth::{Anonymous, SessionEvent};
+use crate::utils;
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+
+/// How long a health check waits for a host to answer before counting it as down.
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Weight of the newest sample in a host's smoothed latency.
+const LATENCY_SMOOTHING: f64 = 0.3;
+
+/// A host with a smoothed latency this much higher than the active host's doesn't trigger a switch,
+/// so requests don't flap between hosts with similar latencies.
+const SWITCH_THRESHOLD: f64 = 0.8;
+
+impl<S, E: Environment> Kalshi<S, E> {
+    /// Adds backup base URLs that requests fail over to when the primary host is unavailable.
+    ///
+    /// The URL chosen by the trading environment stays the primary and is used until a health check
+    /// or a failed connection marks it as down. Backups must serve the same API version and environment,
+    /// e.g. another region of the same exchange or a gateway in front of it, since the session token
+    /// is sent to whichever host is active.
+    ///
+    /// # Arguments
+    ///
+    /// * `urls` - Backup base URLs in order of preference, e.g. `https://backup.example.com/trade-api/v2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalshi::{Kalshi, TradingEnvironment};
+    /// let kalshi = Kalshi::new(TradingEnvironment::LiveMarketMode)
+    ///     .with_fallback_urls(vec!["https://backup.example.com/trade-api/v2".to_string()]);
+    /// ```
+    ///
+    pub fn with_fallback_urls(self, urls: Vec<String>) -> Kalshi<S, E> {
+        let mut endpoints = self.endpoints.write().unwrap();
+        for url in urls {
+            endpoints.add(url.trim_end_matches('/').to_string());
+        }
+        drop(endpoints);
+        self
+    }
+
+    /// Returns the base URL requests are currently sent to.
+    pub fn active_base_url(&self) -> String {
+        self.base_url()
+    }
+
+    /// Returns the health and smoothed latency of every configured base URL, primary first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// for endpoint in kalshi_instance.endpoint_stats() {
+    ///     println!("{} healthy={} latency={:?}", endpoint.url, endpoint.healthy, endpoint.latency);
+    /// }
+    /// ```
+    ///
+    pub fn endpoint_stats(&self) -> Vec<EndpointStats> {
+        self.endpoints.read().unwrap().hosts.clone()
+    }
+
+    /// Checks every configured base URL once and switches to the fastest healthy one.
+    ///
+    /// Each host is asked for the exchange status concurrently. Hosts that don't answer successfully
+    /// within five seconds are marked as down. If the active host is down or another healthy host is
+    /// markedly faster, requests are sent to that host from now on and a
+    /// [`SessionEvent::EndpointChanged`] is published.
+    ///
+    /// # Returns
+    ///
+    /// The health and smoothed latency of every configured base URL after the check.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let stats = kalshi_instance.check_endpoints().await;
+    /// ```
+    ///
+    pub async fn check_endpoints(&self) -> Vec<EndpointStats> {
+        let urls: Vec<String> = self
+            .endpoints
+            .read()
+            .unwrap()
+            .hosts
+            .iter()
+            .map(|host| host.url.clone())
+            .collect();
+
+        let tasks = urls
+            .into_iter()
+            .map(|url| {
+                let client = self.client.clone();
+                let status_url = format!("{}/exchange/status", url);
+                let probe = async move {
+                    let start = Instant::now();
+                    client
+                        .get(status_url)
+                        .timeout(HEALTH_CHECK_TIMEOUT)
+                        .send()
+                        .await?
+                        .error_for_status()?;
+                    Ok(start.elapsed())
+                };
+                (url, probe)
+            })
+            .collect();
+
+        let results = utils::join_bounded(tasks, utils::DEFAULT_MAX_CONCURRENCY).await;
+
+        let mut endpoints = self.endpoints.write().unwrap();
+        for (index, result) in results.into_iter().enumerate() {
+            endpoints.record(index, result.ok());
+        }
+        let switched = endpoints.select();
+        let stats = endpoints.hosts.clone();
+        drop(endpoints);
+
+        if let Some(url) = switched {
+            self.publish_event(SessionEvent::EndpointChanged(url));
+        }
+
+        stats
+    }
+
+    /// Spawns a background task that runs [`check_endpoints`](Kalshi::check_endpoints) every `interval`.
+    ///
+    /// Only useful after configuring backups with [`with_fallback_urls`](Kalshi::with_fallback_urls).
+    /// The selected host is shared with every clone of this `Kalshi` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The time between two health checks.
+    ///
+    /// # Returns
+    ///
+    /// A handle owning the background task. Dropping it stops the health checks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let _monitor = kalshi_instance.spawn_endpoint_monitor(Duration::from_secs(10));
+    /// ```
+    ///
+    pub fn spawn_endpoint_monitor(&self, interval: Duration) -> EndpointMonitorHandle {
+        let kalshi: Kalshi<Anonymous, E> = self.with_state();
+        let handle = tokio::spawn(async move {
+            loop {
+                kalshi.check_endpoints().await;
+                tokio::time::sleep(interval).await;
+            }
+        });
+
+        EndpointMonitorHandle { handle }
+    }
+
+    /// Returns the base URL requests are currently sent to.
+    pub(crate) fn base_url(&self) -> String {
+        self.endpoints.read().unwrap().active_url().to_string()
+    }
+
+    /// Marks the host serving `request_url` as down after a failed connection, switching to another host if possible.
+    pub(crate) fn report_unreachable(&self, request_url: &str) {
+        let mut endpoints = self.endpoints.write().unwrap();
+        let index = match endpoints
+            .hosts
+            .iter()
+            .position(|host| request_url.starts_with(&host.url))
+        {
+            Some(index) => index,
+            None => return,
+        };
+        endpoints.record(index, None);
+        let switched = endpoints.select();
+        drop(endpoints);
+
+        if let Some(url) = switched {
+            self.publish_event(SessionEvent::EndpointChanged(url));
+        }
+    }
+}
+
+/// A handle to the background task started by [`Kalshi::spawn_endpoint_monitor`].
+///
+/// The health checks keep running for as long as the handle is alive. Dropping the handle
+/// or calling [`stop`](EndpointMonitorHandle::stop) aborts the task.
+#[derive(Debug)]
+pub struct EndpointMonitorHandle {
+    handle: JoinHandle<()>,
+}
+
+impl EndpointMonitorHandle {
+    /// Stops the background health checks. The currently selected host stays active.
+    pub fn stop(self) {}
+}
+
+impl Drop for EndpointMonitorHandle {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// The health and latency of one of the base URLs a `Kalshi` instance can send requests to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointStats {
+    /// The base URL of the host.
+    pub url: String,
+    /// Whether the last health check or request to the host succeeded.
+    pub healthy: bool,
+    /// Smoothed latency of the host's health checks, if one has succeeded yet.
+    pub latency: Option<Duration>,
+    /// Number of consecutive failed health checks or connections.
+    pub consecutive_failures: u32,
+}
+
+/// The base URLs of a `Kalshi` instance and which one is active, shared between clones.
+#[derive(Debug)]
+pub(crate) struct Endpoints {
+    hosts: Vec<EndpointStats>,
+    active: usize,
+}
+
+impl Endpoints {
+    pub(crate) fn new(base_url: String) -> Endpoints {
+        let mut endpoints = Endpoints {
+            hosts: Vec::new(),
+            active: 0,
+        };
+        endpoints.add(base_url);
+        endpoints
+    }
+
+    fn add(&mut self, url: String) {
+        if self.hosts.iter().any(|host| host.url == url) {
+            return;
+        }
+
+        self.hosts.push(EndpointStats {
+            url,
+            healthy: true,
+            latency: None,
+            consecutive_failures: 0,
+        });
+    }
+
+    /// Returns the URL the instance was created with, before any fallbacks.
+    pub(crate) fn primary_url(&self) -> &str {
+        &self.hosts[0].url
+    }
+
+    pub(crate) fn active_url(&self) -> &str {
+        &self.hosts[self.active].url
+    }
+
+    /// Records the outcome of a health check or request: a latency on success, `None` on failure.
+    fn record(&mut self, index: usize, latency: Option<Duration>) {
+        let host = &mut self.hosts[index];
+
+        match latency {
+            Some(sample) => {
+                host.healthy = true;
+                host.consecutive_failures = 0;
+                host.latency = Some(match host.latency {
+                    Some(previous) => {
+                        previous.mul_f64(1.0 - LATENCY_SMOOTHING)
+                            + sample.mul_f64(LATENCY_SMOOTHING)
+                    }
+                    None => sample,
+                });
+            }
+            None => {
+                host.healthy = false;
+                host.consecutive_failures += 1;
+            }
+        }
+    }
+
+    /// Selects the host requests are sent to, returning the new URL if the active host changed.
+    ///
+    /// A down active host is replaced by the fastest healthy host, preferring earlier hosts when
+    /// latencies are unknown. A healthy active host is only replaced by a markedly faster one.
+    /// If every host is down, the active host is kept.
+    fn select(&mut self) -> Option<String> {
+        let fastest = self
+            .hosts
+            .iter()
+            .enumerate()
+            .filter(|(_, host)| host.healthy)
+            .min_by_key(|(index, host)| (host.latency.unwrap_or(Duration::MAX), *index))
+            .map(|(index, _)| index)?;
+
+        let current = &self.hosts[self.active];
+        let switch = if !current.healthy {
+            true
+        } else {
+            match (current.latency, self.hosts[fastest].latency) {
+                (Some(current), Some(fastest)) => fastest < current.mul_f64(SWITCH_THRESHOLD),
+                _ => false,
+            }
+        };
+
+        if switch && fastest != self.active {
+            self.active = fastest;
+            Some(self.hosts[fastest].url.clone())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Endpoints;
+    use std::time::Duration;
+
+    #[test]
+    fn test_endpoints_fail_over_and_prefer_faster_hosts() {
+        let mut endpoints = Endpoints::new("https://primary".to_string());
+        endpoints.add("https://backup".to_string());
+
+        endpoints.record(0, None);
+        assert_eq!(endpoints.select(), Some("https://backup".to_string()));
+
+        endpoints.record(0, Some(Duration::from_millis(20)));
+        endpoints.record(1, Some(Duration::from_millis(22)));
+        assert_eq!(endpoints.select(), None);
+
+        endpoints.record(1, None);
+        endpoints.record(1, None);
+        assert_eq!(endpoints.select(), Some("https://primary".to_string()));
+        assert_eq!(endpoints.hosts[1].consecutive_failures, 2);
+    }
+}
@@ -1,8 +1,8 @@
-use super::Kalshi;
+use super::{Environment, Kalshi};
 use crate::kalshi_error::*;
 use serde::{Deserialize, Serialize};
 
-impl Kalshi {
+impl<S, E: Environment> Kalshi<S, E> {
     /// Asynchronously retrieves the current status of the exchange.
     ///
     /// This function makes an HTTP GET request to the Kalshi exchange status endpoint
@@ -16,12 +16,10 @@ impl Kalshi {
     /// kalshi_instance.get_exchange_status().await.unwrap();
     /// ```
     pub async fn get_exchange_status(&self) -> Result<ExchangeStatus, KalshiError> {
-        let exchange_status_url: &str = &format!("{}/exchange/status", self.base_url.to_string());
+        let exchange_status_url: &str = &format!("{}/exchange/status", self.base_url());
 
         let result: ExchangeStatus = self
-            .client
-            .get(exchange_status_url)
-            .send()
+            .send_public(self.client.get(exchange_status_url))
             .await?
             .json()
             .await?;
@@ -42,13 +40,10 @@ impl Kalshi {
     /// kalshi_instance.get_exchange_schedule().await.unwrap();
     /// ```
     pub async fn get_exchange_schedule(&self) -> Result<ExchangeScheduleStandard, KalshiError> {
-        let exchange_schedule_url: &str =
-            &format!("{}/exchange/schedule", self.base_url.to_string());
+        let exchange_schedule_url: &str = &format!("{}/exchange/schedule", self.base_url());
 
         let result: ExchangeScheduleResponse = self
-            .client
-            .get(exchange_schedule_url)
-            .send()
+            .send_public(self.client.get(exchange_schedule_url))
             .await?
             .json()
             .await?;
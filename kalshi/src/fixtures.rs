@@ -0,0 +1,59 @@
+//! Market and position fixtures shared by the unit tests.
+
+use crate::market::{Market, Snapshot};
+use crate::portfolio::MarketPosition;
+use crate::price::Cents;
+
+/// An active binary market quoted 40/42 on 'Yes' and 58/60 on 'No', last traded at 41.
+pub(crate) fn market(ticker: &str) -> Market {
+    serde_json::from_str(&format!(
+        r#"{{"ticker":"{}","event_ticker":"EV","market_type":"binary","title":"","subtitle":"",
+        "yes_sub_title":"","no_sub_title":"","open_time":"2024-01-01T00:00:00Z","close_time":"2024-02-01T00:00:00Z",
+        "latest_expiration_time":"2024-02-01T00:00:00Z","settlement_timer_seconds":60,"status":"active",
+        "response_price_units":"usd_cent","notional_value":100,"tick_size":1,"yes_bid":40,"yes_ask":42,
+        "no_bid":58,"no_ask":60,"last_price":41,"previous_yes_bid":40,"previous_yes_ask":42,"previous_price":41,
+        "volume":10,"volume_24h":5,"liquidity":1000,"open_interest":7,"result":"","can_close_early":true,
+        "expiration_value":"","rules_primary":"","rules_secondary":""}}"#,
+        ticker
+    ))
+    .unwrap()
+}
+
+/// A position of `contracts` in `ticker` (positive for 'Yes', negative for 'No') that cost `exposure` cents, without fees.
+pub(crate) fn position(ticker: &str, contracts: i32, exposure: i64) -> MarketPosition {
+    MarketPosition {
+        fees_paid: 0,
+        market_exposure: exposure,
+        position: contracts,
+        realized_pnl: 0,
+        resting_orders_count: 0,
+        ticker: ticker.to_string(),
+        total_traded: exposure,
+    }
+}
+
+/// A history snapshot at `ts` of a market quoted 49/51 on both sides, last traded at 50, without volume.
+pub(crate) fn snapshot(ts: i64) -> Snapshot {
+    Snapshot {
+        yes_price: Cents(50),
+        yes_bid: Cents(49),
+        yes_ask: Cents(51),
+        no_bid: Cents(49),
+        no_ask: Cents(51),
+        volume: 0,
+        open_interest: 0,
+        ts,
+    }
+}
+
+/// A history snapshot at `ts` of a market quoted `yes_bid`/`yes_ask`, with the matching 'No' quotes.
+pub(crate) fn quote(ts: i64, yes_bid: i64, yes_ask: i64) -> Snapshot {
+    Snapshot {
+        yes_price: Cents(yes_bid),
+        yes_bid: Cents(yes_bid),
+        yes_ask: Cents(yes_ask),
+        no_bid: Cents(100 - yes_ask),
+        no_ask: Cents(100 - yes_bid),
+        ..snapshot(ts)
+    }
+}
@@ -0,0 +1,484 @@
+use super::{Environment, Kalshi};
+use crate::candle::{volume_series_from_snapshots, CandleInterval, VolumePoint};
+use crate::cursor::Cursor;
+use crate::kalshi_error::*;
+use crate::market::Snapshot;
+use std::collections::HashSet;
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+
+/// The largest number of snapshots the market history endpoint returns per request.
+const HISTORY_PAGE_LIMIT: i32 = 100;
+
+/// The time between two market history records, used to plan volume history downloads.
+const HISTORY_RECORD_INTERVAL: Duration = Duration::from_secs(60);
+
+impl<S, E: Environment> Kalshi<S, E> {
+    /// Downloads a market's history over a long time range, one planned window at a time.
+    ///
+    /// Each window of the plan is requested with its own `min_ts` / `max_ts`, following cursors if a window
+    /// holds more than one page. Requests are paced to stay within `requests_per_second`, e.g. the
+    /// `read_limit` returned by [`get_account`](Kalshi::get_account), so a large pull doesn't starve the
+    /// rest of the bot of its rate budget. Snapshots are returned in chronological order.
+    ///
+    /// The returned timestamps are checked against the plan: snapshots outside the window they were requested
+    /// for, and windows whose last page came back full without a cursor, are reported as
+    /// [`ContinuityIssue`]s instead of failing the download.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticker` - A reference to a string representing the market's ticker.
+    /// * `plan` - The windows to request, usually built with [`HistoryPlan::for_record_interval`].
+    /// * `requests_per_second` - The request rate the download may use. Values below one are treated as one.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(HistoryDownload)`: The downloaded snapshots, the number of requests made and any continuity issues.
+    /// - `Err(KalshiError)`: An error if any request fails. Snapshots downloaded before the error are discarded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let plan = HistoryPlan::for_record_interval(1_690_000_000, 1_700_000_000, Duration::from_secs(60));
+    /// let download = kalshi_instance
+    ///     .download_market_history(&"HIGHNY-23NOV13-T51".to_string(), &plan, 10)
+    ///     .await
+    ///     .unwrap();
+    /// assert!(download.issues.is_empty());
+    /// ```
+    ///
+    pub async fn download_market_history(
+        &self,
+        ticker: &String,
+        plan: &HistoryPlan,
+        requests_per_second: u32,
+    ) -> Result<HistoryDownload, KalshiError> {
+        let mut pacer = tokio::time::interval(Duration::from_secs_f64(
+            1.0 / requests_per_second.max(1) as f64,
+        ));
+        pacer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let mut download = HistoryDownload::default();
+
+        for window in plan.windows() {
+            let mut snapshots = Vec::new();
+            let mut cursor = None;
+            let mut seen_cursors = HashSet::new();
+
+            loop {
+                pacer.tick().await;
+
+                let (next, page) = self
+                    .get_market_history(
+                        ticker,
+                        Some(HISTORY_PAGE_LIMIT),
+                        cursor,
+                        Some(window.min_ts),
+                        Some(window.max_ts),
+                    )
+                    .await?;
+                download.requests += 1;
+
+                let full_page = page.len() >= HISTORY_PAGE_LIMIT as usize;
+                snapshots.extend(page);
+
+                // A short page still ends the window only if the exchange returned no cursor.
+                match next.into_next() {
+                    Some(next) if !seen_cursors.insert(next.clone()) => {
+                        download.issues.push(ContinuityIssue::DuplicateCursor {
+                            window: *window,
+                            cursor: next,
+                        });
+                        break;
+                    }
+                    Some(next) => cursor = Some(next),
+                    None if full_page => {
+                        download
+                            .issues
+                            .push(ContinuityIssue::Truncated { window: *window });
+                        break;
+                    }
+                    None => break,
+                }
+            }
+
+            for snapshot in &snapshots {
+                if !window.contains(snapshot.ts) {
+                    download.issues.push(ContinuityIssue::OutOfWindow {
+                        window: *window,
+                        ts: snapshot.ts,
+                    });
+                }
+            }
+
+            snapshots.sort_by_key(|snapshot| snapshot.ts);
+            download.snapshots.extend(snapshots);
+        }
+
+        Ok(download)
+    }
+
+    /// Downloads a market's volume and open interest over a time range, as one point per `interval` bucket.
+    ///
+    /// The history is fetched with [`download_market_history`](Kalshi::download_market_history) and
+    /// bucketed with [`volume_series_from_snapshots`], so every bucket in the range gets a point even when
+    /// the market didn't trade. Buckets are aligned to UTC.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticker` - A reference to a string representing the market's ticker.
+    /// * `min_ts` - The start of the range, in seconds since the Unix epoch.
+    /// * `max_ts` - The end of the range, in seconds since the Unix epoch, inclusive.
+    /// * `interval` - The bucket size of the series.
+    /// * `requests_per_second` - The request rate the download may use. Values below one are treated as one.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<VolumePoint>)`: One point per bucket, sorted by start time.
+    /// - `Err(KalshiError)`: An error if any history request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let hourly = kalshi_instance
+    ///     .get_market_volume_history(&"HIGHNY-23NOV13-T51".to_string(), 1_700_000_000, 1_700_086_400, CandleInterval::Hour, 10)
+    ///     .await
+    ///     .unwrap();
+    /// for point in hourly {
+    ///     println!("{}: traded {}, open interest {:?}", point.start_ts, point.volume, point.open_interest);
+    /// }
+    /// ```
+    ///
+    pub async fn get_market_volume_history(
+        &self,
+        ticker: &String,
+        min_ts: i64,
+        max_ts: i64,
+        interval: CandleInterval,
+        requests_per_second: u32,
+    ) -> Result<Vec<VolumePoint>, KalshiError> {
+        let plan = HistoryPlan::for_record_interval(min_ts, max_ts, HISTORY_RECORD_INTERVAL);
+        let download = self
+            .download_market_history(ticker, &plan, requests_per_second)
+            .await?;
+
+        Ok(volume_series_from_snapshots(
+            &download.snapshots,
+            min_ts,
+            max_ts,
+            interval,
+            0,
+        ))
+    }
+
+    /// Checks a finished download for gaps and duplicates, and re-fetches the windows they fall in.
+    ///
+    /// Anomalies are found with [`HistoryDownload::find_anomalies`]. Every planned window that overlaps one is
+    /// downloaded again, and its snapshots replace the ones from the first download. Some gaps are genuine,
+    /// e.g. a market with no activity overnight, so anomalies that persist after the re-fetch are returned
+    /// rather than treated as errors.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticker` - A reference to a string representing the market's ticker.
+    /// * `plan` - The plan the download was made with.
+    /// * `download` - The download to check and repair in place.
+    /// * `max_gap` - The longest expected time between two consecutive snapshots.
+    /// * `requests_per_second` - The request rate the re-fetch may use. Values below one are treated as one.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<ContinuityIssue>)`: The anomalies still present after re-fetching. Empty if the download is clean.
+    /// - `Err(KalshiError)`: An error if a re-fetch request fails. The download is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut download = kalshi_instance.download_market_history(&ticker, &plan, 10).await?;
+    /// let remaining = kalshi_instance
+    ///     .repair_market_history(&ticker, &plan, &mut download, Duration::from_secs(300), 10)
+    ///     .await?;
+    /// ```
+    ///
+    pub async fn repair_market_history(
+        &self,
+        ticker: &String,
+        plan: &HistoryPlan,
+        download: &mut HistoryDownload,
+        max_gap: Duration,
+        requests_per_second: u32,
+    ) -> Result<Vec<ContinuityIssue>, KalshiError> {
+        let anomalies = download.find_anomalies(max_gap);
+        let retry = plan.windows_affected_by(&anomalies);
+
+        if retry.is_empty() {
+            return Ok(anomalies);
+        }
+
+        let refetched = self
+            .download_market_history(ticker, &retry, requests_per_second)
+            .await?;
+
+        download.requests += refetched.requests;
+        download.issues.extend(refetched.issues);
+        download.snapshots.retain(|snapshot| {
+            !retry
+                .windows()
+                .iter()
+                .any(|window| window.contains(snapshot.ts))
+        });
+        download.snapshots.extend(refetched.snapshots);
+        download.snapshots.sort_by_key(|snapshot| snapshot.ts);
+
+        Ok(download.find_anomalies(max_gap))
+    }
+}
+
+/// A time range split into consecutive, non-overlapping windows, each fetched with its own request.
+///
+/// Sizing windows so each one fits in a single page keeps large historical pulls cheap to retry and
+/// easy to verify.
+///
+/// # Example
+///
+/// ```
+/// // One day of minute-level history, in windows of at most 100 minutes.
+/// let plan = HistoryPlan::for_record_interval(1_700_000_000, 1_700_086_400, Duration::from_secs(60));
+/// assert_eq!(plan.len(), 15);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryPlan {
+    windows: Vec<HistoryWindow>,
+}
+
+impl HistoryPlan {
+    /// Splits `[min_ts, max_ts]` into windows of at most `window` each.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_ts` - The start of the range, in seconds since the Unix epoch.
+    /// * `max_ts` - The end of the range, in seconds since the Unix epoch, inclusive.
+    /// * `window` - The length of each window. Windows are at least one second long.
+    ///
+    pub fn new(min_ts: i64, max_ts: i64, window: Duration) -> HistoryPlan {
+        let step = (window.as_secs() as i64).max(1);
+        let mut windows = Vec::new();
+        let mut start = min_ts;
+
+        while start <= max_ts {
+            let end = start.saturating_add(step - 1).min(max_ts);
+            windows.push(HistoryWindow {
+                min_ts: start,
+                max_ts: end,
+            });
+            start = match end.checked_add(1) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+
+        HistoryPlan { windows }
+    }
+
+    /// Splits `[min_ts, max_ts]` into windows that each hold about one page of records,
+    /// given the expected time between two records (e.g. one minute for minute-level snapshots).
+    pub fn for_record_interval(min_ts: i64, max_ts: i64, record_interval: Duration) -> HistoryPlan {
+        HistoryPlan::new(min_ts, max_ts, record_interval * HISTORY_PAGE_LIMIT as u32)
+    }
+
+    /// Returns the planned windows, in chronological order.
+    pub fn windows(&self) -> &[HistoryWindow] {
+        &self.windows
+    }
+
+    /// Returns the number of planned windows, which is the minimum number of requests the download takes.
+    pub fn len(&self) -> usize {
+        self.windows.len()
+    }
+
+    /// Returns whether the plan has no windows, i.e. `min_ts` was after `max_ts`.
+    pub fn is_empty(&self) -> bool {
+        self.windows.is_empty()
+    }
+
+    /// Returns a plan holding only the windows that overlap the given issues, e.g. to re-fetch them.
+    pub fn windows_affected_by(&self, issues: &[ContinuityIssue]) -> HistoryPlan {
+        let windows = self
+            .windows
+            .iter()
+            .filter(|window| issues.iter().any(|issue| issue.overlaps(window)))
+            .copied()
+            .collect();
+
+        HistoryPlan { windows }
+    }
+}
+
+/// A window of a [`HistoryPlan`], requested with `min_ts` and `max_ts` (both inclusive, in seconds since the Unix epoch).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistoryWindow {
+    /// The start of the window.
+    pub min_ts: i64,
+    /// The end of the window, inclusive.
+    pub max_ts: i64,
+}
+
+impl HistoryWindow {
+    /// Returns whether `ts` falls within the window.
+    pub fn contains(&self, ts: i64) -> bool {
+        self.min_ts <= ts && ts <= self.max_ts
+    }
+}
+
+/// The result of [`Kalshi::download_market_history`].
+#[derive(Debug, Default)]
+pub struct HistoryDownload {
+    /// The downloaded snapshots, in chronological order.
+    pub snapshots: Vec<Snapshot>,
+    /// The number of requests made.
+    pub requests: usize,
+    /// Problems found while checking the returned timestamps against the plan.
+    pub issues: Vec<ContinuityIssue>,
+}
+
+impl HistoryDownload {
+    /// Finds time gaps longer than `max_gap` and duplicated timestamps between consecutive snapshots.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_gap` - The longest expected time between two consecutive snapshots.
+    ///
+    pub fn find_anomalies(&self, max_gap: Duration) -> Vec<ContinuityIssue> {
+        let max_gap = max_gap.as_secs() as i64;
+
+        self.snapshots
+            .windows(2)
+            .filter_map(|pair| {
+                let (previous, next) = (pair[0].ts, pair[1].ts);
+                if previous == next {
+                    Some(ContinuityIssue::DuplicateTimestamp { ts: next })
+                } else if next - previous > max_gap {
+                    Some(ContinuityIssue::Gap {
+                        from_ts: previous,
+                        to_ts: next,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// A problem with the timestamps returned for a window of a [`HistoryPlan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContinuityIssue {
+    /// A snapshot was returned for a timestamp outside the window it was requested for.
+    OutOfWindow {
+        /// The window that was requested.
+        window: HistoryWindow,
+        /// The timestamp of the returned snapshot.
+        ts: i64,
+    },
+    /// The window's last page was full but came without a cursor, so records may be missing.
+    /// Use a smaller window for this range.
+    Truncated {
+        /// The window that was requested.
+        window: HistoryWindow,
+    },
+    /// The exchange returned a cursor that was already followed for the window, so paging was stopped.
+    DuplicateCursor {
+        /// The window that was requested.
+        window: HistoryWindow,
+        /// The repeated cursor.
+        cursor: Cursor,
+    },
+    /// Two consecutive snapshots are further apart than expected.
+    Gap {
+        /// The timestamp of the snapshot before the gap.
+        from_ts: i64,
+        /// The timestamp of the snapshot after the gap.
+        to_ts: i64,
+    },
+    /// Two consecutive snapshots share a timestamp.
+    DuplicateTimestamp {
+        /// The duplicated timestamp.
+        ts: i64,
+    },
+}
+
+impl ContinuityIssue {
+    /// Returns whether the issue concerns records inside `window`.
+    fn overlaps(&self, window: &HistoryWindow) -> bool {
+        match self {
+            ContinuityIssue::OutOfWindow { window: w, .. }
+            | ContinuityIssue::Truncated { window: w }
+            | ContinuityIssue::DuplicateCursor { window: w, .. } => w == window,
+            ContinuityIssue::Gap { from_ts, to_ts } => {
+                window.min_ts <= *to_ts && *from_ts <= window.max_ts
+            }
+            ContinuityIssue::DuplicateTimestamp { ts } => window.contains(*ts),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ContinuityIssue, HistoryDownload, HistoryPlan, HistoryWindow};
+    use crate::fixtures::snapshot;
+    use std::time::Duration;
+
+    #[test]
+    fn test_history_plan_covers_range_without_overlap() {
+        let plan = HistoryPlan::new(0, 249, Duration::from_secs(100));
+
+        assert_eq!(
+            plan.windows(),
+            &[
+                HistoryWindow {
+                    min_ts: 0,
+                    max_ts: 99
+                },
+                HistoryWindow {
+                    min_ts: 100,
+                    max_ts: 199
+                },
+                HistoryWindow {
+                    min_ts: 200,
+                    max_ts: 249
+                },
+            ]
+        );
+        assert!(HistoryPlan::new(10, 0, Duration::from_secs(100)).is_empty());
+    }
+
+    #[test]
+    fn test_find_anomalies_and_affected_windows() {
+        let download = HistoryDownload {
+            snapshots: vec![snapshot(0), snapshot(60), snapshot(60), snapshot(400)],
+            requests: 1,
+            issues: Vec::new(),
+        };
+
+        let anomalies = download.find_anomalies(Duration::from_secs(120));
+        assert_eq!(
+            anomalies,
+            vec![
+                ContinuityIssue::DuplicateTimestamp { ts: 60 },
+                ContinuityIssue::Gap {
+                    from_ts: 60,
+                    to_ts: 400
+                },
+            ]
+        );
+
+        let plan = HistoryPlan::new(0, 599, Duration::from_secs(100));
+        let retry = plan.windows_affected_by(&anomalies);
+        assert_eq!(
+            retry.windows().iter().map(|w| w.min_ts).collect::<Vec<_>>(),
+            vec![0, 100, 200, 300, 400]
+        );
+    }
+}
@@ -0,0 +1,295 @@
+use crate::candle::Candle;
+use futures::stream::{Stream, StreamExt};
+use std::collections::VecDeque;
+
+/// A technical indicator computed incrementally from candles.
+///
+/// Indicators only see one candle at a time, so the same implementation gives the same values whether
+/// it is driven over downloaded history with [`run_indicator`] or over live candles with [`indicator_stream`].
+///
+/// # Example
+///
+/// ```
+/// use kalshi::{Indicator, SimpleMovingAverage};
+/// let mut sma = SimpleMovingAverage::new(20);
+/// for candle in &candles {
+///     if let Some(value) = sma.update(candle) {
+///         println!("{} sma={}", candle.start_ts, value);
+///     }
+/// }
+/// ```
+pub trait Indicator {
+    /// The value the indicator produces for each candle.
+    type Output;
+
+    /// Feeds the next candle to the indicator, returning its value once enough candles have been seen.
+    fn update(&mut self, candle: &Candle) -> Option<Self::Output>;
+
+    /// Forgets every candle seen so far, as if the indicator was newly created.
+    fn reset(&mut self);
+}
+
+/// Drives an indicator over historical candles, returning its value for each of them.
+///
+/// # Arguments
+///
+/// * `indicator` - The indicator to update. It keeps its state, so live candles can be fed to it afterwards.
+/// * `candles` - The candles to feed to the indicator, oldest first.
+///
+/// # Returns
+///
+/// The value of the indicator after each candle, `None` while it is still warming up.
+///
+pub fn run_indicator<I: Indicator>(
+    indicator: &mut I,
+    candles: &[Candle],
+) -> Vec<Option<I::Output>> {
+    candles
+        .iter()
+        .map(|candle| indicator.update(candle))
+        .collect()
+}
+
+/// Drives an indicator over a stream of live candles, pairing each candle with the indicator's value.
+///
+/// # Arguments
+///
+/// * `indicator` - The indicator to update, e.g. one already warmed up with [`run_indicator`].
+/// * `candles` - A stream of completed candles, oldest first.
+///
+/// # Example
+///
+/// ```
+/// use futures::StreamExt;
+/// let mut rsi = RelativeStrengthIndex::new(14);
+/// run_indicator(&mut rsi, &history);
+/// let mut values = Box::pin(indicator_stream(rsi, live_candles));
+/// while let Some((candle, rsi)) = values.next().await {
+///     println!("{} rsi={:?}", candle.start_ts, rsi);
+/// }
+/// ```
+pub fn indicator_stream<I, S>(
+    mut indicator: I,
+    candles: S,
+) -> impl Stream<Item = (Candle, Option<I::Output>)>
+where
+    I: Indicator,
+    S: Stream<Item = Candle>,
+{
+    candles.map(move |candle| {
+        let value = indicator.update(&candle);
+        (candle, value)
+    })
+}
+
+/// The average closing price of the last `period` candles.
+#[derive(Debug, Clone)]
+pub struct SimpleMovingAverage {
+    period: usize,
+    window: VecDeque<i32>,
+    sum: i64,
+}
+
+impl SimpleMovingAverage {
+    /// Creates a simple moving average over `period` candles.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn new(period: usize) -> SimpleMovingAverage {
+        assert!(period > 0, "indicator period must be positive");
+        SimpleMovingAverage {
+            period,
+            window: VecDeque::with_capacity(period),
+            sum: 0,
+        }
+    }
+}
+
+impl Indicator for SimpleMovingAverage {
+    type Output = f64;
+
+    fn update(&mut self, candle: &Candle) -> Option<f64> {
+        self.window.push_back(candle.close);
+        self.sum += candle.close as i64;
+        if self.window.len() > self.period {
+            self.sum -= self.window.pop_front().unwrap_or_default() as i64;
+        }
+
+        if self.window.len() < self.period {
+            return None;
+        }
+        Some(self.sum as f64 / self.period as f64)
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sum = 0;
+    }
+}
+
+/// An exponentially weighted average of closing prices, seeded with the simple average of the first `period` candles.
+#[derive(Debug, Clone)]
+pub struct ExponentialMovingAverage {
+    seed: SimpleMovingAverage,
+    alpha: f64,
+    value: Option<f64>,
+}
+
+impl ExponentialMovingAverage {
+    /// Creates an exponential moving average over `period` candles, with a smoothing factor of `2 / (period + 1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn new(period: usize) -> ExponentialMovingAverage {
+        ExponentialMovingAverage {
+            seed: SimpleMovingAverage::new(period),
+            alpha: 2.0 / (period as f64 + 1.0),
+            value: None,
+        }
+    }
+}
+
+impl Indicator for ExponentialMovingAverage {
+    type Output = f64;
+
+    fn update(&mut self, candle: &Candle) -> Option<f64> {
+        let value = match self.value {
+            Some(previous) => previous + self.alpha * (candle.close as f64 - previous),
+            None => self.seed.update(candle)?,
+        };
+        self.value = Some(value);
+        Some(value)
+    }
+
+    fn reset(&mut self) {
+        self.seed.reset();
+        self.value = None;
+    }
+}
+
+/// Wilder's relative strength index of closing prices over `period` candles, between 0 and 100.
+///
+/// A series that didn't move over the period is neutral, at 50.
+#[derive(Debug, Clone)]
+pub struct RelativeStrengthIndex {
+    period: usize,
+    previous_close: Option<i32>,
+    changes: usize,
+    average_gain: f64,
+    average_loss: f64,
+}
+
+impl RelativeStrengthIndex {
+    /// Creates a relative strength index over `period` candles. The first value is produced after `period + 1` candles.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn new(period: usize) -> RelativeStrengthIndex {
+        assert!(period > 0, "indicator period must be positive");
+        RelativeStrengthIndex {
+            period,
+            previous_close: None,
+            changes: 0,
+            average_gain: 0.0,
+            average_loss: 0.0,
+        }
+    }
+}
+
+impl Indicator for RelativeStrengthIndex {
+    type Output = f64;
+
+    fn update(&mut self, candle: &Candle) -> Option<f64> {
+        let previous = self.previous_close.replace(candle.close)?;
+        let change = (candle.close - previous) as f64;
+        let (gain, loss) = (change.max(0.0), (-change).max(0.0));
+        let period = self.period as f64;
+
+        self.changes += 1;
+        if self.changes <= self.period {
+            self.average_gain += gain / period;
+            self.average_loss += loss / period;
+            if self.changes < self.period {
+                return None;
+            }
+        } else {
+            self.average_gain = (self.average_gain * (period - 1.0) + gain) / period;
+            self.average_loss = (self.average_loss * (period - 1.0) + loss) / period;
+        }
+
+        if self.average_loss == 0.0 {
+            return Some(if self.average_gain == 0.0 {
+                50.0
+            } else {
+                100.0
+            });
+        }
+        Some(100.0 - 100.0 / (1.0 + self.average_gain / self.average_loss))
+    }
+
+    fn reset(&mut self) {
+        self.previous_close = None;
+        self.changes = 0;
+        self.average_gain = 0.0;
+        self.average_loss = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn closes(prices: &[i32]) -> Vec<Candle> {
+        prices
+            .iter()
+            .enumerate()
+            .map(|(index, &close)| Candle {
+                start_ts: index as i64 * 60,
+                open: close,
+                high: close,
+                low: close,
+                close,
+                volume: 1,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_indicators_match_between_history_and_stream() {
+        let candles = closes(&[10, 12, 11, 14, 13]);
+
+        let sma = run_indicator(&mut SimpleMovingAverage::new(3), &candles);
+        assert_eq!(
+            sma,
+            vec![None, None, Some(11.0), Some(37.0 / 3.0), Some(38.0 / 3.0)]
+        );
+
+        let rsi = run_indicator(&mut RelativeStrengthIndex::new(2), &candles);
+        assert!((rsi[2].unwrap() - 100.0 * 2.0 / 3.0).abs() < 1e-9);
+
+        let history = run_indicator(&mut ExponentialMovingAverage::new(2), &candles);
+        let live: Vec<Option<f64>> = indicator_stream(
+            ExponentialMovingAverage::new(2),
+            futures::stream::iter(candles),
+        )
+        .map(|(_, value)| value)
+        .collect()
+        .await;
+        assert_eq!(history, live);
+    }
+
+    #[test]
+    fn test_rsi_is_neutral_on_flat_series() {
+        let flat = run_indicator(
+            &mut RelativeStrengthIndex::new(2),
+            &closes(&[40, 40, 40, 40]),
+        );
+        assert_eq!(flat, vec![None, None, Some(50.0), Some(50.0)]);
+
+        let rising = run_indicator(&mut RelativeStrengthIndex::new(2), &closes(&[40, 41, 42]));
+        assert_eq!(rising[2], Some(100.0));
+    }
+}
@@ -16,9 +16,21 @@ pub enum KalshiError {
     RequestError(RequestError),
     /// Errors caused by incorrect or invalid user input.
     UserInputError(String),
+    /// Errors caused by the exchange rejecting the session's credentials, even after re-authenticating.
+    AuthenticationError(String),
+    /// Errors caused by calling an endpoint that modifies orders on a read-only client.
+    ReadOnlyError(String),
+    /// Errors caused by the exchange answering `429 Too Many Requests` even after backing off and retrying.
+    RateLimitError(String),
     /// Errors representing unexpected internal issues or situations that are not supposed to happen.
     InternalError(String),
-    // TODO: add error type specifically for joining threads together.
+    /// Errors caused by a concurrent request panicking before it finished.
+    /// Holds the identifier of the item (e.g. the order ID) the request was working on and the panic message.
+    TaskJoinError(String, String),
+    /// Errors caused by an order request failing in a way that leaves open whether the exchange accepted
+    /// the order, e.g. a timeout or a server error. Holds the order's `client_order_id`, to look the order
+    /// up before retrying, and a description of the failure.
+    OrderOutcomeUnknown(String, String),
 }
 
 impl fmt::Display for KalshiError {
@@ -26,6 +38,13 @@ impl fmt::Display for KalshiError {
         match self {
             KalshiError::RequestError(e) => write!(f, "HTTP Error: {}", e),
             KalshiError::UserInputError(e) => write!(f, "User Input Error: {}", e),
+            KalshiError::AuthenticationError(e) => write!(f, "Authentication Error: {}", e),
+            KalshiError::ReadOnlyError(e) => write!(f, "Read Only Error: {}", e),
+            KalshiError::RateLimitError(e) => write!(f, "Rate Limit Error: {}", e),
+            KalshiError::TaskJoinError(id, e) => write!(f, "Task Join Error for {}: {}", id, e),
+            KalshiError::OrderOutcomeUnknown(id, e) => {
+                write!(f, "Order Outcome Unknown for {}: {}", id, e)
+            }
             KalshiError::InternalError(e) => write!(f, "INTERNAL ERROR, PLEASE EMAIL DEVELOPER OR MAKE A NEW ISSUE ON THE CRATE'S REPOSITORY: https://github.com/dpeachpeach/kalshi-rust. Specific Error: {}", e)
         }
     }
@@ -36,7 +55,12 @@ impl Error for KalshiError {
         match self {
             KalshiError::RequestError(e) => Some(e),
             KalshiError::UserInputError(_) => None,
+            KalshiError::AuthenticationError(_) => None,
+            KalshiError::ReadOnlyError(_) => None,
+            KalshiError::RateLimitError(_) => None,
             KalshiError::InternalError(_) => None,
+            KalshiError::TaskJoinError(..) => None,
+            KalshiError::OrderOutcomeUnknown(..) => None,
         }
     }
 }
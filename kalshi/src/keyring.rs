@@ -0,0 +1,115 @@
+use super::{Authenticated, Environment, Kalshi};
+use crate::kalshi_error::*;
+use zeroize::Zeroizing;
+
+/// The service name credentials are stored under when none is given.
+pub const DEFAULT_KEYRING_SERVICE: &str = "kalshi-rust";
+
+/// Stores and retrieves Kalshi passwords in the operating system's secret store
+/// (Keychain on macOS, Credential Manager on Windows, Secret Service on Linux).
+///
+/// Only available with the `keyring` feature enabled. Useful for desktop operators
+/// who would rather not keep passwords in `.env` files.
+///
+/// # Example
+///
+/// ```
+/// use kalshi::CredentialStore;
+///
+/// let store = CredentialStore::default();
+/// store.store_password("johndoe@example.com", "example_password")?;
+/// let password = store.get_password("johndoe@example.com")?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct CredentialStore {
+    service: String,
+}
+
+impl CredentialStore {
+    /// Creates a credential store that keeps entries under the given service name.
+    ///
+    /// # Arguments
+    ///
+    /// * `service` - The service name entries are stored under, e.g. one per trading environment.
+    pub fn new(service: &str) -> CredentialStore {
+        CredentialStore {
+            service: service.to_string(),
+        }
+    }
+
+    /// Saves the password for `user`, replacing any password already stored.
+    ///
+    /// # Returns
+    /// - `Ok(())`: The password was saved.
+    /// - `Err(KalshiError)`: The secret store could not be accessed.
+    pub fn store_password(&self, user: &str, password: &str) -> Result<(), KalshiError> {
+        self.entry(user)?
+            .set_password(password)
+            .map_err(keyring_error)
+    }
+
+    /// Retrieves the password stored for `user`.
+    ///
+    /// # Returns
+    /// - `Ok(String)`: The stored password.
+    /// - `Err(KalshiError)`: No password is stored for the user, or the secret store could not be accessed.
+    pub fn get_password(&self, user: &str) -> Result<String, KalshiError> {
+        self.entry(user)?.get_password().map_err(keyring_error)
+    }
+
+    /// Removes the password stored for `user`.
+    ///
+    /// # Returns
+    /// - `Ok(())`: The password was removed.
+    /// - `Err(KalshiError)`: No password is stored for the user, or the secret store could not be accessed.
+    pub fn delete_password(&self, user: &str) -> Result<(), KalshiError> {
+        self.entry(user)?.delete_password().map_err(keyring_error)
+    }
+
+    fn entry(&self, user: &str) -> Result<keyring::Entry, KalshiError> {
+        keyring::Entry::new(&self.service, user).map_err(keyring_error)
+    }
+}
+
+impl Default for CredentialStore {
+    fn default() -> Self {
+        CredentialStore::new(DEFAULT_KEYRING_SERVICE)
+    }
+}
+
+impl<S, E: Environment> Kalshi<S, E> {
+    /// Logs a user in with a password retrieved from the operating system's secret store.
+    ///
+    /// Only available with the `keyring` feature enabled.
+    ///
+    /// # Arguments
+    /// * `user` - A string slice representing the user's email.
+    /// * `store` - The credential store holding the user's password.
+    ///
+    /// # Returns
+    /// - `Ok(Kalshi<Authenticated>)`: An authenticated instance on successful login.
+    /// - `Err(KalshiError)`: Error if the password could not be retrieved or the login failed.
+    ///
+    /// # Example
+    /// ```
+    /// let store = CredentialStore::default();
+    /// let kalshi_instance = kalshi_instance.login_from_keyring("johndoe@example.com", &store).await?;
+    /// ```
+    pub async fn login_from_keyring(
+        &self,
+        user: &str,
+        store: &CredentialStore,
+    ) -> Result<Kalshi<Authenticated, E>, KalshiError> {
+        let password = Zeroizing::new(store.get_password(user)?);
+        self.login(user, &password).await
+    }
+}
+
+fn keyring_error(err: keyring::Error) -> KalshiError {
+    match err {
+        keyring::Error::NoEntry => KalshiError::UserInputError(
+            "No credentials found in the OS secret store for this user".to_string(),
+        ),
+        e => KalshiError::UserInputError(format!("OS secret store error: {}", e)),
+    }
+}
@@ -35,8 +35,10 @@
 //!
 //! Initialize the Kalshi Struct and login using your authentication details:
 //! - **IMPORTANT**:  A user's authentication token expires every thirty minutes, this means
-//! that you'll need to call the login function every thirty minutes in order to
-//! ensure that you remain authenticated with a valid token.
+//!   that you'll need to call the login function every thirty minutes in order to
+//!   ensure that you remain authenticated with a valid token. Alternatively, call
+//!   [spawn_token_refresh](Kalshi::spawn_token_refresh) once after logging in and the crate
+//!   will re-login in the background before the token expires.
 //! - Storing user / password information in plaintext is not recommended,
 //! an implementation of extracting user details from local environmental variables
 //! is available [here](https://github.com/dpeachpeach/kalshi-rust/blob/main/sample_bot/src/main.rs#L12)
@@ -47,12 +49,15 @@
 //! let username = "johndoe@example.com";
 //! let password = "example_password";
 //!
-//! let mut kalshi_instance = Kalshi::new(TradingEnvironment::DemoMode);
-//!
-//! kalshi_instance.login(username, password).await?;
+//! let kalshi_instance = Kalshi::new(TradingEnvironment::DemoMode)
+//!     .login(username, password)
+//!     .await?;
 //! ```
 //!
-//! After logging in, you can call any method present in the crate without issue.
+//! Logging in returns a `Kalshi<Authenticated>` instance, on which you can call any method present in the crate.
+//! Methods that need a session, such as [get_balance](Kalshi::get_balance) or [create_order](Kalshi::create_order),
+//! don't exist on the anonymous instance returned by [new](Kalshi::new), so calling them before
+//! logging in is a compile error.
 //! Here is a script that buys a 'yes' contract on November 13th's New York temperature
 //! market.
 //!
@@ -109,33 +114,106 @@
 //! ```
 //! use kalshi::Kalshi;
 //! use kalshi::TradingEnvironment;
-//! let kalshi_instance = Kalshi::new(TradingEnvironment::DemoMode);
+//! let kalshi_instance = Kalshi::new(TradingEnvironment::DemoMode)
+//!     .login(username, password)
+//!     .await?;
 //!
-//! kalshi_instance.get_balance();
+//! kalshi_instance.get_balance().await.unwrap();
 //! ```
 //!
 
-#[macro_use]
-mod utils;
+mod account;
 mod auth;
+mod candle;
+mod concentration;
+mod conditional;
+mod cursor;
+mod deadman;
+mod endpoint;
 mod exchange;
+#[cfg(test)]
+mod fixtures;
+mod history;
+mod indicator;
 mod kalshi_error;
+#[cfg(feature = "keyring")]
+mod keyring;
+mod maker_taker;
 mod market;
+mod order_group;
+mod payoff;
+mod performance;
 mod portfolio;
+mod preview;
+mod price;
+mod rebalance;
+#[cfg(feature = "chrono")]
+mod replay;
+mod seed;
+mod sweep;
+#[cfg(feature = "chrono")]
+mod tca;
+mod utils;
 
+pub use account::*;
 pub use auth::*;
+pub use candle::*;
+pub use concentration::*;
+pub use conditional::*;
+pub use cursor::*;
+pub use deadman::*;
+pub use endpoint::*;
 pub use exchange::*;
+pub use history::*;
+pub use indicator::*;
 pub use kalshi_error::*;
+#[cfg(feature = "keyring")]
+pub use keyring::*;
+pub use maker_taker::*;
 pub use market::*;
+pub use order_group::*;
+pub use payoff::*;
+pub use performance::*;
 pub use portfolio::*;
+pub use preview::*;
+pub use price::*;
+pub use rebalance::*;
+#[cfg(feature = "chrono")]
+pub use replay::*;
+pub use seed::*;
+pub use sweep::*;
+#[cfg(feature = "chrono")]
+pub use tca::*;
 
 // imports
 use reqwest;
+use serde::Serialize;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::atomic::AtomicUsize;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::SystemTime;
+use tokio::sync::broadcast;
+use zeroize::Zeroizing;
 
 /// The Kalshi struct is the core of the kalshi-crate. It acts as the interface
 /// between the user and the market, abstracting away the meat of requests
 /// by encapsulating authentication information and the client itself.
 ///
+/// Cloning a `Kalshi` instance is cheap, and every clone shares the same session:
+/// logging in, refreshing the token or logging out through one clone is visible to all
+/// of them. Clones can be moved into spawned tasks without any extra synchronization.
+///
+/// The type parameter tracks whether the instance is logged in. [`Kalshi::new`] returns a
+/// `Kalshi<Anonymous>`, which can only reach public market and exchange data.
+/// [`login`](Kalshi::login) returns a `Kalshi<Authenticated>` sharing the same session, which adds
+/// the portfolio, account and order endpoints.
+///
+/// The second type parameter optionally pins the trading environment. [`Kalshi::demo`] and
+/// [`Kalshi::live`] return a `Kalshi<Anonymous, Demo>` and a `Kalshi<Anonymous, Live>`, so a library
+/// can require a demo-only client in its signatures. Instances created with [`Kalshi::new`] use
+/// [`AnyEnvironment`] and can be narrowed with [`into_environment`](Kalshi::into_environment).
+///
 /// ## Creating a new `Kalshi` instance for demo mode:
 ///
 /// ```
@@ -147,18 +225,37 @@ use reqwest;
 ///
 ///
 #[derive(Debug, Clone)]
-pub struct Kalshi {
-    /// - `base_url`: The base URL for the API, determined by the trading environment.
-    base_url: String,
-    /// - `curr_token`: A field for storing the current authentication token.
-    curr_token: Option<String>,
-    /// - `member_id`: A field for storing the member ID.
-    member_id: Option<String>,
+pub struct Kalshi<S = Anonymous, E = AnyEnvironment> {
+    /// - `endpoints`: The base URLs for the API and which one is active, shared between clones.
+    ///   The primary URL is determined by the trading environment.
+    endpoints: Arc<RwLock<Endpoints>>,
+    /// - `auth`: Authentication state (token, member ID, credentials), shared between clones
+    ///   so that a background refresh is visible to every handle on the session.
+    auth: Arc<RwLock<AuthState>>,
+    /// - `relogin`: Held while the session logs in again after a rejected token, shared between clones
+    ///   so that concurrent rejections trigger a single re-login.
+    relogin: Arc<tokio::sync::Mutex<()>>,
+    /// - `events`: The channel session events are published on, shared between clones.
+    events: broadcast::Sender<SessionEvent>,
+    /// - `read_only`: Whether endpoints that modify orders are disabled.
+    read_only: bool,
+    /// - `auth_hook`: An optional function that inspects or rewrites authenticated requests before they are sent.
+    auth_hook: Option<AuthHook>,
     /// - `client`: The HTTP client used for making requests to the marketplace.
     client: reqwest::Client,
+    /// - `order_client`: A separate HTTP client reserved for creating, decreasing and canceling orders.
+    order_client: reqwest::Client,
+    /// - `url_buffer`: A buffer reused to build request URLs, shared between clones.
+    url_buffer: Arc<Mutex<String>>,
+    /// - `body_size`: The length of the last serialized request body, shared between clones.
+    body_size: Arc<AtomicUsize>,
+    /// - `state`: Marker for whether the instance is logged in.
+    state: PhantomData<S>,
+    /// - `environment`: Marker for the trading environment the instance is restricted to, if any.
+    environment: PhantomData<E>,
 }
 
-impl Kalshi {
+impl Kalshi<Anonymous> {
     /// Creates a new instance of Kalshi with the specified trading environment.
     /// This environment determines the base URL used for API requests.
     ///
@@ -180,20 +277,205 @@ impl Kalshi {
     /// let kalshi = Kalshi::new(TradingEnvironment::LiveMarketMode);
     /// ```
     ///
-    pub fn new(trading_env: TradingEnvironment) -> Kalshi {
-        return Kalshi {
-            base_url: utils::build_base_url(trading_env).to_string(),
-            curr_token: None,
-            member_id: None,
+    pub fn new(trading_env: TradingEnvironment) -> Kalshi<Anonymous> {
+        Kalshi::with_base_url(utils::build_base_url(trading_env).to_string())
+    }
+
+    /// Creates an anonymous instance sending its requests to `base_url`.
+    pub(crate) fn with_base_url(base_url: String) -> Kalshi<Anonymous> {
+        Kalshi {
+            endpoints: Arc::new(RwLock::new(Endpoints::new(base_url))),
+            auth: Arc::new(RwLock::new(AuthState::default())),
+            relogin: Arc::new(tokio::sync::Mutex::new(())),
+            events: broadcast::channel(SESSION_EVENT_CAPACITY).0,
+            read_only: false,
+            auth_hook: None,
             client: reqwest::Client::new(),
-        };
+            order_client: utils::build_order_client(),
+            url_buffer: Arc::new(Mutex::new(String::new())),
+            body_size: Arc::new(AtomicUsize::new(0)),
+            state: PhantomData,
+            environment: PhantomData,
+        }
+    }
+}
+
+impl Kalshi<Anonymous, Demo> {
+    /// Creates a new instance of Kalshi for the demo environment, restricted to it at the type level.
+    ///
+    /// Functions that take a `Kalshi<S, Demo>` can only be handed a paper trading client.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalshi::{Demo, Kalshi};
+    /// fn run_strategy_tests(kalshi: &Kalshi<kalshi::Authenticated, Demo>) { /* ... */ }
+    ///
+    /// let kalshi = Kalshi::demo();
+    /// ```
+    ///
+    pub fn demo() -> Kalshi<Anonymous, Demo> {
+        Kalshi::new(TradingEnvironment::DemoMode).retype()
+    }
+}
+
+impl Kalshi<Anonymous, Live> {
+    /// Creates a new instance of Kalshi for the live environment, restricted to it at the type level.
+    /// (Warning, you're using real money!)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalshi::Kalshi;
+    /// let kalshi = Kalshi::live();
+    /// ```
+    ///
+    pub fn live() -> Kalshi<Anonymous, Live> {
+        Kalshi::new(TradingEnvironment::LiveMarketMode).retype()
+    }
+}
+
+impl<S, E: Environment> Kalshi<S, E> {
+    /// Puts the instance in read-only mode.
+    ///
+    /// Every endpoint that creates, cancels or modifies orders returns a
+    /// [`KalshiError::ReadOnlyError`] without touching the network. Useful when pointing
+    /// analytics jobs at a live account.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalshi::{Kalshi, TradingEnvironment};
+    /// let kalshi = Kalshi::new(TradingEnvironment::LiveMarketMode).read_only();
+    /// ```
+    ///
+    pub fn read_only(mut self) -> Kalshi<S, E> {
+        self.read_only = true;
+        self
+    }
+
+    /// Sets a hook that is run on every authenticated request just before it is sent.
+    ///
+    /// The hook receives the fully built request, with the session's `Authorization` header already
+    /// attached, and may add, replace or remove headers. This enables custom signing schemes, header
+    /// rotation or corporate gateway tokens without forking the crate's request paths.
+    /// The hook also runs when a request is replayed after re-authenticating. Requests for public data
+    /// sent while logged out carry no session header and don't run the hook.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook` - A function receiving a mutable reference to each outgoing authenticated request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalshi::{Kalshi, TradingEnvironment};
+    /// let kalshi = Kalshi::new(TradingEnvironment::DemoMode).with_auth_hook(|request| {
+    ///     request
+    ///         .headers_mut()
+    ///         .insert("X-Gateway-Token", "gateway-secret".parse().unwrap());
+    /// });
+    /// ```
+    ///
+    pub fn with_auth_hook<F>(mut self, hook: F) -> Kalshi<S, E>
+    where
+        F: Fn(&mut reqwest::Request) + Send + Sync + 'static,
+    {
+        self.auth_hook = Some(AuthHook(Arc::new(hook)));
+        self
+    }
+
+    /// Returns whether the instance is in read-only mode. See [`read_only`](Kalshi::read_only).
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns a `ReadOnlyError` if the instance is in read-only mode.
+    pub(crate) fn ensure_writable(&self, operation: &str) -> Result<(), KalshiError> {
+        if self.read_only {
+            return Err(KalshiError::ReadOnlyError(format!(
+                "{} is not allowed on a read-only client",
+                operation
+            )));
+        }
+        Ok(())
+    }
+
+    /// Restricts the instance to the trading environment `T` at the type level.
+    ///
+    /// Converting to [`AnyEnvironment`] always succeeds. Converting to [`Demo`] or [`Live`] checks
+    /// the instance's primary URL, which is set by the trading environment it was created with.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Kalshi<S, T>)`: A handle on the same session, restricted to `T`.
+    /// - `Err(KalshiError)`: A `UserInputError` if the instance targets another environment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalshi::{Demo, Kalshi, TradingEnvironment};
+    /// let kalshi = Kalshi::new(TradingEnvironment::DemoMode).into_environment::<Demo>()?;
+    /// ```
+    ///
+    pub fn into_environment<T: Environment>(self) -> Result<Kalshi<S, T>, KalshiError> {
+        if let Some(trading_env) = T::trading_environment() {
+            let expected = utils::build_base_url(trading_env);
+            let primary = self.endpoints.read().unwrap().primary_url().to_string();
+            if primary != expected {
+                return Err(KalshiError::UserInputError(format!(
+                    "Instance targets {}, not the {:?} environment",
+                    primary,
+                    T::default()
+                )));
+            }
+        }
+        Ok(self.retype())
+    }
+
+    /// Returns a handle on the same session in another state.
+    pub(crate) fn with_state<T>(&self) -> Kalshi<T, E> {
+        self.retype()
+    }
+
+    /// Returns a handle on the same session in another state and environment.
+    pub(crate) fn retype<T, F>(&self) -> Kalshi<T, F> {
+        Kalshi {
+            endpoints: self.endpoints.clone(),
+            auth: self.auth.clone(),
+            relogin: self.relogin.clone(),
+            events: self.events.clone(),
+            read_only: self.read_only,
+            auth_hook: self.auth_hook.clone(),
+            client: self.client.clone(),
+            order_client: self.order_client.clone(),
+            url_buffer: self.url_buffer.clone(),
+            body_size: self.body_size.clone(),
+            state: PhantomData,
+            environment: PhantomData,
+        }
+    }
+
+    /// Starts building a request URL for `path`, relative to the base URL, in the client's reusable buffer.
+    pub(crate) fn url_builder(&self, path: fmt::Arguments) -> utils::QueryBuilder<'_> {
+        utils::QueryBuilder::new(&self.url_buffer, &self.base_url(), path)
+    }
+
+    /// Serializes a request body as JSON into a buffer sized after the previous body.
+    ///
+    /// The buffer itself becomes the body, so it is never copied, and it is allocated once at the size
+    /// the client's bodies usually have instead of growing from scratch on every order.
+    pub(crate) fn json_body<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, KalshiError> {
+        utils::json_body(&self.body_size, value)
     }
 
     /// Retrieves the current user authentication token, if available.
     ///
+    /// The copy is wiped from memory when it is dropped, like the session's own token.
+    ///
     /// # Returns
     ///
-    /// Returns an `Option<String>` containing the authentication token. If no token
+    /// Returns an `Option<Zeroizing<String>>` containing the authentication token. If no token
     /// is currently stored, it returns `None`.
     ///
     /// # Examples
@@ -203,20 +485,76 @@ impl Kalshi {
     /// let kalshi = Kalshi::new(TradingEnvironment::DemoMode);
     /// let token = kalshi.get_user_token();
     /// if let Some(t) = token {
-    ///     println!("Current token: {}", t);
+    ///     println!("Current token: {}", *t);
     /// } else {
     ///     println!("No token found");
     /// }
     /// ```
     ///
-    pub fn get_user_token(&self) -> Option<String> {
-        match &self.curr_token {
-            Some(val) => return Some(val.clone()),
-            _ => return None,
+    pub fn get_user_token(&self) -> Option<Zeroizing<String>> {
+        self.auth.read().unwrap().token.clone().map(Zeroizing::new)
+    }
+
+    /// Retrieves the time at which the current authentication token expires, if logged in.
+    ///
+    /// Tokens are valid for thirty minutes after login. The expiry is moved forward every
+    /// time the session logs in again, including background refreshes.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `Option<SystemTime>` with the token's expiry. If no token is stored, it returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// if let Some(expiry) = kalshi_instance.token_expires_at() {
+    ///     println!("Token expires at {:?}", expiry);
+    /// }
+    /// ```
+    ///
+    pub fn token_expires_at(&self) -> Option<SystemTime> {
+        self.auth.read().unwrap().token_expiry
+    }
+
+    /// Returns whether the instance holds a token that has not expired yet.
+    ///
+    /// Useful as a guard at the top of a trading loop, so that a bot re-logs in
+    /// instead of sending requests that will be rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// if !kalshi_instance.is_authenticated() {
+    ///     kalshi_instance.login(username, password).await?;
+    /// }
+    /// ```
+    ///
+    pub fn is_authenticated(&self) -> bool {
+        let auth = self.auth.read().unwrap();
+        match (&auth.token, auth.token_expiry) {
+            (Some(_), Some(expiry)) => expiry > SystemTime::now(),
+            _ => false,
         }
     }
 }
 
+// GENERAL TYPES
+// -----------------------------------------------
+
+/// A point in time returned by the exchange, such as a market's `close_time` or an order's `created_time`.
+///
+/// With the `chrono` feature enabled, timestamps are parsed into a `DateTime<Utc>` while deserializing.
+/// Without it, they are kept as the RFC 3339 strings sent by the exchange.
+#[cfg(feature = "chrono")]
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+
+/// A point in time returned by the exchange, such as a market's `close_time` or an order's `created_time`.
+///
+/// With the `chrono` feature enabled, timestamps are parsed into a `DateTime<Utc>` while deserializing.
+/// Without it, they are kept as the RFC 3339 strings sent by the exchange.
+#[cfg(not(feature = "chrono"))]
+pub type Timestamp = String;
+
 // GENERAL ENUMS
 // -----------------------------------------------
 
@@ -234,3 +572,58 @@ pub enum TradingEnvironment {
     /// Use this mode for actual trading activities with real money.
     LiveMarketMode,
 }
+
+/// A trading environment a [`Kalshi`] instance can be restricted to at the type level.
+///
+/// Implemented by [`Demo`], [`Live`] and [`AnyEnvironment`].
+///
+pub trait Environment: fmt::Debug + Default + Clone + Copy + Send + Sync + 'static {
+    /// Returns the trading environment instances are restricted to, or `None` if they may target either.
+    fn trading_environment() -> Option<TradingEnvironment>;
+}
+
+/// Marker for instances that may target either trading environment, chosen at runtime.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AnyEnvironment;
+
+/// Marker for instances restricted to the demo environment.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Demo;
+
+/// Marker for instances restricted to the live environment.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Live;
+
+impl Environment for AnyEnvironment {
+    fn trading_environment() -> Option<TradingEnvironment> {
+        None
+    }
+}
+
+impl Environment for Demo {
+    fn trading_environment() -> Option<TradingEnvironment> {
+        Some(TradingEnvironment::DemoMode)
+    }
+}
+
+impl Environment for Live {
+    fn trading_environment() -> Option<TradingEnvironment> {
+        Some(TradingEnvironment::LiveMarketMode)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Demo, Kalshi, Live, TradingEnvironment};
+
+    #[test]
+    fn test_into_environment_checks_primary_url() {
+        let kalshi = Kalshi::new(TradingEnvironment::DemoMode);
+        assert!(kalshi.clone().into_environment::<Live>().is_err());
+
+        let demo = kalshi
+            .with_fallback_urls(vec!["https://backup".to_string()])
+            .into_environment::<Demo>();
+        assert!(demo.is_ok());
+    }
+}
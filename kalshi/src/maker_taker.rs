@@ -0,0 +1,211 @@
+use crate::portfolio::{Fill, Side};
+use crate::preview::estimate_taker_fee;
+use std::collections::{HashMap, VecDeque};
+
+/// Maker and taker fill counts of one strategy in one market, as tracked by [`MakerTakerTracker`].
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MakerTakerStats {
+    /// Number of fills that rested on the book before trading.
+    pub maker_fills: usize,
+    /// Number of fills that crossed the spread.
+    pub taker_fills: usize,
+    /// Number of contracts filled as a maker.
+    pub maker_contracts: i64,
+    /// Number of contracts filled as a taker.
+    pub taker_contracts: i64,
+    /// Taker fees of the taker fills at the exchange's standard rate, in cents, estimated per fill with
+    /// [`estimate_taker_fee`]. Fills don't report the fee actually charged.
+    pub estimated_taker_fees: i64,
+    // Whether each of the most recent fills was a taker fill, oldest first.
+    recent: VecDeque<bool>,
+    // Whether an alert was raised and the recent taker ratio hasn't recovered since.
+    alerting: bool,
+}
+
+impl MakerTakerStats {
+    /// Returns the share of contracts filled as a taker since tracking started, or `None` if nothing filled.
+    pub fn taker_ratio(&self) -> Option<f64> {
+        let total = self.maker_contracts + self.taker_contracts;
+        if total == 0 {
+            return None;
+        }
+        Some(self.taker_contracts as f64 / total as f64)
+    }
+
+    /// Returns the share of taker fills among the most recent fills, or `None` if nothing filled.
+    pub fn recent_taker_ratio(&self) -> Option<f64> {
+        if self.recent.is_empty() {
+            return None;
+        }
+        let takers = self.recent.iter().filter(|is_taker| **is_taker).count();
+        Some(takers as f64 / self.recent.len() as f64)
+    }
+
+    fn record(&mut self, fill: &Fill, window: usize) {
+        if fill.is_taker {
+            self.taker_fills += 1;
+            self.taker_contracts += fill.count as i64;
+            let price = match fill.side {
+                Side::Yes => fill.yes_price,
+                Side::No => fill.no_price,
+            };
+            self.estimated_taker_fees += estimate_taker_fee(fill.count as i64, price);
+        } else {
+            self.maker_fills += 1;
+            self.maker_contracts += fill.count as i64;
+        }
+
+        self.recent.push_back(fill.is_taker);
+        if self.recent.len() > window {
+            self.recent.pop_front();
+        }
+    }
+}
+
+/// Raised by [`MakerTakerTracker::record`] when a passive strategy crosses the spread too often.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PassivityAlert {
+    /// The strategy that crossed the spread.
+    pub strategy: String,
+    /// The market it crossed the spread in.
+    pub ticker: String,
+    /// The share of taker fills among its most recent fills in the market.
+    pub recent_taker_ratio: f64,
+    /// The largest share the strategy was configured to tolerate.
+    pub max_taker_ratio: f64,
+}
+
+/// Tracks maker and taker fills per strategy and market.
+///
+/// Kalshi charges makers and takers differently, so a strategy meant to provide liquidity that starts
+/// crossing the spread pays more than planned. Strategies registered with [`passive`](MakerTakerTracker::passive)
+/// raise an alert when too many of their recent fills in a market were taker fills.
+///
+/// Taker fees are estimated from each fill's price. Fills report neither the fees charged nor any maker
+/// fees or rebates, which vary by series, so those aren't tracked.
+///
+/// # Example
+///
+/// ```
+/// use kalshi::MakerTakerTracker;
+/// let mut tracker = MakerTakerTracker::new(50).passive("mm", 0.2);
+/// for fill in &fills {
+///     if let Some(alert) = tracker.record("mm", fill) {
+///         eprintln!("{} is crossing the spread in {}: {:.0}% taker", alert.strategy, alert.ticker, alert.recent_taker_ratio * 100.0);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MakerTakerTracker {
+    window: usize,
+    max_taker_ratios: HashMap<String, f64>,
+    stats: HashMap<(String, String), MakerTakerStats>,
+}
+
+impl MakerTakerTracker {
+    /// Creates a tracker whose recent ratios and alerts consider the last `window` fills of each strategy in each market.
+    pub fn new(window: usize) -> MakerTakerTracker {
+        MakerTakerTracker {
+            window: window.max(1),
+            max_taker_ratios: HashMap::new(),
+            stats: HashMap::new(),
+        }
+    }
+
+    /// Marks `strategy` as passive, alerting once more than `max_taker_ratio` of its last `window` fills
+    /// in a market were taker fills.
+    pub fn passive(mut self, strategy: &str, max_taker_ratio: f64) -> MakerTakerTracker {
+        self.max_taker_ratios
+            .insert(strategy.to_string(), max_taker_ratio);
+        self
+    }
+
+    /// Records a fill of `strategy`.
+    ///
+    /// # Returns
+    ///
+    /// A `PassivityAlert` if the strategy is passive and this fill pushed its recent taker ratio in the market
+    /// above the tolerated share. The alert is raised once, and again only after the ratio has recovered.
+    pub fn record(&mut self, strategy: &str, fill: &Fill) -> Option<PassivityAlert> {
+        let window = self.window;
+        let stats = self
+            .stats
+            .entry((strategy.to_string(), fill.ticker.clone()))
+            .or_default();
+        stats.record(fill, window);
+
+        let max_taker_ratio = *self.max_taker_ratios.get(strategy)?;
+        let recent_taker_ratio = stats.recent_taker_ratio()?;
+        let exceeded = stats.recent.len() == window && recent_taker_ratio > max_taker_ratio;
+
+        let raise = exceeded && !stats.alerting;
+        stats.alerting = exceeded;
+        if !raise {
+            return None;
+        }
+
+        Some(PassivityAlert {
+            strategy: strategy.to_string(),
+            ticker: fill.ticker.clone(),
+            recent_taker_ratio,
+            max_taker_ratio,
+        })
+    }
+
+    /// Returns the statistics of `strategy` in the market `ticker`, if it has filled there.
+    pub fn stats(&self, strategy: &str, ticker: &str) -> Option<&MakerTakerStats> {
+        self.stats.get(&(strategy.to_string(), ticker.to_string()))
+    }
+
+    /// Returns the statistics of `strategy` summed over every market. Recent fills are not combined,
+    /// so [`recent_taker_ratio`](MakerTakerStats::recent_taker_ratio) of the result is `None`.
+    pub fn strategy_stats(&self, strategy: &str) -> MakerTakerStats {
+        let mut total = MakerTakerStats::default();
+        for (_, stats) in self.stats.iter().filter(|((name, _), _)| name == strategy) {
+            total.maker_fills += stats.maker_fills;
+            total.taker_fills += stats.taker_fills;
+            total.maker_contracts += stats.maker_contracts;
+            total.taker_contracts += stats.taker_contracts;
+            total.estimated_taker_fees += stats.estimated_taker_fees;
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MakerTakerTracker;
+    use crate::portfolio::Fill;
+
+    fn fill(is_taker: bool) -> Fill {
+        serde_json::from_str(&format!(
+            r#"{{"action":"buy","count":2,"created_time":"2023-11-14T22:13:30Z","is_taker":{},"no_price":58,
+                "order_id":"o","side":"yes","ticker":"EV-A","trade_id":"t","yes_price":42}}"#,
+            is_taker
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_passive_strategy_alerts_once_when_crossing() {
+        let mut tracker = MakerTakerTracker::new(4).passive("mm", 0.25);
+
+        let alerts: Vec<bool> = [false, true, false, true, true, true, false, false, false]
+            .into_iter()
+            .map(|is_taker| tracker.record("mm", &fill(is_taker)).is_some())
+            .collect();
+        // The window first exceeds 25% taker at the fourth fill, then only recovers at the last one.
+        assert_eq!(
+            alerts,
+            vec![false, false, false, true, false, false, false, false, false]
+        );
+
+        let stats = tracker.stats("mm", "EV-A").unwrap();
+        assert_eq!((stats.maker_fills, stats.taker_fills), (5, 4));
+        assert_eq!(tracker.strategy_stats("mm").taker_contracts, 8);
+        // 2 contracts at 42 cents cost 4 cents of fees per taker fill.
+        assert_eq!(stats.estimated_taker_fees, 16);
+        assert!(tracker.record("momo", &fill(true)).is_none());
+    }
+}
@@ -1,10 +1,46 @@
-use super::Kalshi;
+use super::{Anonymous, Environment, Kalshi};
+use crate::conditional::{Conditional, ConditionalCache};
+use crate::cursor::Cursor;
 use crate::kalshi_error::*;
-use serde::{Deserialize, Serialize};
+use crate::payoff::CONTRACT_PAYOUT_CENTS;
+use crate::portfolio::{Action, Side};
+use crate::price::Cents;
+use crate::utils;
+use crate::Timestamp;
+use futures::stream::{self, Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::time::Duration;
 
-impl Kalshi {
+/// Number of markets requested per page when searching the whole universe.
+const SEARCH_PAGE_SIZE: i64 = 200;
+
+/// Number of events requested per page when paging through events internally.
+const EVENTS_PAGE_SIZE: i64 = 200;
+
+/// Number of tickers sent in the `tickers` filter of one batched market request.
+const BATCH_TICKERS_PER_REQUEST: usize = 100;
+
+/// How many batched market requests are in flight at once.
+const BATCH_CONCURRENCY: usize = 4;
+
+/// Number of recent trades included in a [`MarketBundle`].
+const BUNDLE_TRADES_LIMIT: i32 = 100;
+
+/// How many times a rate-limited snapshot request is retried before giving up.
+const RATE_LIMIT_RETRIES: u32 = 3;
+
+/// The backoff before the first retry of a rate-limited request, growing linearly with each attempt.
+const RATE_LIMIT_BACKOFF: Duration = Duration::from_millis(500);
+
+impl<S, E: Environment> Kalshi<S, E> {
     /// Retrieves detailed information about a specific event from the Kalshi exchange.
     ///
+    /// The event's markets are returned in `Event.markets`, whether the exchange nested them in the
+    /// event or sent them alongside it.
+    ///
     /// # Arguments
     /// * `event_ticker` - A string reference representing the ticker of the event.
     /// * `with_nested_markets` - An optional boolean to include nested market data.
@@ -23,28 +59,18 @@ impl Kalshi {
         event_ticker: &String,
         with_nested_markets: Option<bool>,
     ) -> Result<Event, KalshiError> {
-        let single_event_url: &str =
-            &format!("{}/events/{}", self.base_url.to_string(), event_ticker);
+        let mut url = self.url_builder(format_args!("/events/{}", event_ticker));
+        url.param("with_nested_markets", with_nested_markets);
 
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(2);
-
-        add_param!(params, "with_nested_markets", with_nested_markets);
-
-        let single_event_url = reqwest::Url::parse_with_params(single_event_url, &params)
-            .unwrap_or_else(|err| {
-                eprintln!("{:?}", err);
-                panic!("Internal Parse Error, please contact developer!");
-            });
+        let single_event_url = url.finish()?;
 
         let result: SingleEventResponse = self
-            .client
-            .get(single_event_url)
-            .send()
+            .send_public(self.client.get(single_event_url))
             .await?
             .json()
             .await?;
 
-        return Ok(result.event);
+        return Ok(result.into_event());
     }
 
     /// Retrieves detailed information about a specific market from the Kalshi exchange.
@@ -62,93 +88,81 @@ impl Kalshi {
     /// let market = kalshi_instance.get_single_event(market_ticker).await.unwrap();
     /// ```
     pub async fn get_single_market(&self, ticker: &String) -> Result<Market, KalshiError> {
-        let single_market_url: &str = &format!("{}/markets/{}", self.base_url.to_string(), ticker);
+        let single_market_url: &str = &format!("{}/markets/{}", self.base_url(), ticker);
 
         let result: SingleMarketResponse = self
-            .client
-            .get(single_market_url)
-            .send()
+            .send_public(self.client.get(single_market_url))
             .await?
             .json()
             .await?;
 
         return Ok(result.market);
     }
-    /// Asynchronously retrieves information about multiple markets from the Kalshi exchange.
+
+    /// Waits until a market closes, e.g. to run flattening logic at expiration.
     ///
-    /// This method fetches data for a collection of markets, filtered by various optional parameters.
-    /// It supports pagination, time-based filtering, and selection by specific tickers or statuses.
+    /// The market is fetched again once its close time is reached, and the wait continues if the close
+    /// time was pushed back in the meantime. Only available with the `chrono` feature enabled.
     ///
     /// # Arguments
-    /// * `limit` - An optional integer to limit the number of markets returned.
-    /// * `cursor` - An optional string for pagination cursor.
-    /// * `event_ticker` - An optional string to filter markets by event ticker.
-    /// * `series_ticker` - An optional string to filter markets by series ticker.
-    /// * `max_close_ts` - An optional timestamp for the maximum close time.
-    /// * `min_close_ts` - An optional timestamp for the minimum close time.
-    /// * `status` - An optional string to filter markets by their status.
-    /// * `tickers` - An optional string to filter markets by specific tickers.
+    /// * `ticker` - A string reference representing the ticker of the market.
     ///
     /// # Returns
-    /// - `Ok((Option<String>, Vec<Market>))`: A tuple containing an optional pagination cursor and a vector of `Market` objects on success.
+    /// - `Ok(Market)`: The market as fetched after its close time passed.
     /// - `Err(KalshiError)`: Error in case of a failure in the HTTP request or response parsing.
-    ///
     /// # Example
+    /// ```
+    /// let market = kalshi_instance.sleep_until_close(&ticker).await?;
+    /// println!("{} closed with status {}", market.ticker, market.status);
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub async fn sleep_until_close(&self, ticker: &String) -> Result<Market, KalshiError> {
+        loop {
+            let market = self.get_single_market(ticker).await?;
+            let remaining = market.time_until_close();
+            if remaining.is_zero() {
+                return Ok(market);
+            }
+            tokio::time::sleep(remaining).await;
+        }
+    }
+
+    /// Retrieves a market like [`get_single_market`](Kalshi::get_single_market), unless it is unchanged since the last call.
+    ///
+    /// The validators `cache` holds from the previous response for this market are sent along, so the exchange can
+    /// answer with `304 Not Modified` instead of the full market. Useful for polling loops.
+    ///
+    /// # Arguments
+    /// * `ticker` - A string reference representing the ticker of the market.
+    /// * `cache` - The polling loop's validators, updated with the response's.
     ///
+    /// # Returns
+    /// - `Ok(Conditional<Market>)`: The market if it changed, or `Conditional::NotModified`.
+    /// - `Err(KalshiError)`: Error in case of a failure in the HTTP request or response parsing.
+    /// # Example
     /// ```
-    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
-    /// let markets_result = kalshi_instance.get_multiple_markets(
-    ///     Some(10),
-    ///     None,
-    ///     Some("event_ticker"),
-    ///     None,
-    ///     None,
-    ///     None,
-    ///     None,
-    ///     None
-    /// ).await.unwrap();
+    /// let mut cache = ConditionalCache::new();
+    /// if let Conditional::Modified(market) = kalshi_instance.get_single_market_if_modified(&ticker, &mut cache).await? {
+    ///     println!("New yes bid: {}", market.yes_bid);
+    /// }
     /// ```
-    pub async fn get_multiple_markets(
+    pub async fn get_single_market_if_modified(
         &self,
-        limit: Option<i64>,
-        cursor: Option<String>,
-        event_ticker: Option<String>,
-        series_ticker: Option<String>,
-        max_close_ts: Option<i64>,
-        min_close_ts: Option<i64>,
-        status: Option<String>,
-        tickers: Option<String>,
-    ) -> Result<(Option<String>, Vec<Market>), KalshiError> {
-        let markets_url: &str = &format!("{}/markets", self.base_url.to_string());
-
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(10);
-
-        add_param!(params, "limit", limit);
-        add_param!(params, "event_ticker", event_ticker);
-        add_param!(params, "series_ticker", series_ticker);
-        add_param!(params, "status", status);
-        add_param!(params, "cursor", cursor);
-        add_param!(params, "min_close_ts", min_close_ts);
-        add_param!(params, "max_close_ts", max_close_ts);
-        add_param!(params, "tickers", tickers);
-
-        let markets_url =
-            reqwest::Url::parse_with_params(markets_url, &params).unwrap_or_else(|err| {
-                eprintln!("{:?}", err);
-                panic!("Internal Parse Error, please contact developer!");
-            });
+        ticker: &String,
+        cache: &mut ConditionalCache,
+    ) -> Result<Conditional<Market>, KalshiError> {
+        let single_market_url: &str = &format!("{}/markets/{}", self.base_url(), ticker);
 
-        let result: PublicMarketsResponse = self
-            .client
-            .get(markets_url)
-            .header("Authorization", self.curr_token.clone().unwrap())
-            .send()
-            .await?
-            .json()
+        let response = self
+            .send_public(cache.apply(self.client.get(single_market_url), single_market_url))
             .await?;
 
-        Ok((result.cursor, result.markets))
+        let result: Conditional<SingleMarketResponse> =
+            cache.read(single_market_url, response).await?;
+
+        Ok(result.map(|result| result.market))
     }
+
     /// Asynchronously retrieves information about multiple events from the Kalshi exchange.
     ///
     /// This method fetches data for multiple events, with optional filtering based on status,
@@ -157,13 +171,13 @@ impl Kalshi {
     ///
     /// # Arguments
     /// * `limit` - An optional integer to limit the number of events returned.
-    /// * `cursor` - An optional string for pagination cursor.
-    /// * `status` - An optional string to filter events by their status.
+    /// * `cursor` - An optional cursor returned with the previous page.
+    /// * `status` - An optional status to filter events by.
     /// * `series_ticker` - An optional string to filter events by series ticker.
     /// * `with_nested_markets` - An optional boolean to include nested market data.
     ///
     /// # Returns
-    /// - `Ok((Option<String>, Vec<Event>))`: A tuple containing an optional pagination cursor and a vector of `Event` objects on success.
+    /// - `Ok((Cursor, Vec<Event>))`: A tuple containing the pagination cursor of the next page and a vector of `Event` objects on success.
     /// - `Err(KalshiError)`: Error in case of a failure in the HTTP request or response parsing.
     ///
     /// # Example
@@ -173,7 +187,7 @@ impl Kalshi {
     /// let events_result = kalshi_instance.get_multiple_events(
     ///     Some(10),
     ///     None,
-    ///     Some("active"),
+    ///     Some(EventStatusFilter::Open),
     ///     None,
     ///     Some(true)
     /// ).await.unwrap();
@@ -183,31 +197,98 @@ impl Kalshi {
     pub async fn get_multiple_events(
         &self,
         limit: Option<i64>,
-        cursor: Option<String>,
-        status: Option<String>,
+        cursor: Option<Cursor>,
+        status: Option<EventStatusFilter>,
+        series_ticker: Option<String>,
+        with_nested_markets: Option<bool>,
+    ) -> Result<(Cursor, Vec<Event>), KalshiError> {
+        let mut url = self.url_builder(format_args!("/events"));
+        url.param("limit", limit);
+        url.param("status", status);
+        url.param("cursor", cursor);
+        url.param("series_ticker", series_ticker);
+        url.param("with_nested_markets", with_nested_markets);
+
+        let events_url = url.finish()?;
+
+        let result: PublicEventsResponse = self
+            .send_public(self.client.get(events_url))
+            .await?
+            .json()
+            .await?;
+
+        return Ok((result.cursor, result.events));
+    }
+
+    /// Streams every event matching the given filters, following pagination cursors transparently.
+    ///
+    /// The events counterpart of [`get_markets_stream`](Kalshi::get_markets_stream): pages of
+    /// [`get_multiple_events`](Kalshi::get_multiple_events) are requested lazily, one at a time, as the
+    /// stream is consumed. The stream owns a handle on this session and can be moved into a spawned task.
+    /// If a page fails to load, the error is yielded and the stream ends.
+    ///
+    /// # Arguments
+    /// * `page_size` - An optional integer for the number of events requested per page.
+    /// * `status` - An optional status to filter events by.
+    /// * `series_ticker` - An optional string to filter events by series ticker.
+    /// * `with_nested_markets` - An optional boolean to include each event's markets.
+    ///
+    /// # Returns
+    /// A stream yielding `Ok(Event)` for every matching event, or `Err(KalshiError)` if a page failed to load.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::StreamExt;
+    ///
+    /// let mut events = Box::pin(kalshi_instance.get_events_stream(
+    ///     Some(200),
+    ///     Some(EventStatusFilter::Open),
+    ///     None,
+    ///     Some(true),
+    /// ));
+    /// while let Some(event) = events.next().await {
+    ///     println!("{}", event?.event_ticker);
+    /// }
+    /// ```
+    pub fn get_events_stream(
+        &self,
+        page_size: Option<i64>,
+        status: Option<EventStatusFilter>,
         series_ticker: Option<String>,
         with_nested_markets: Option<bool>,
-    ) -> Result<(Option<String>, Vec<Event>), KalshiError> {
-        let events_url: &str = &format!("{}/events", self.base_url.to_string());
+    ) -> impl Stream<Item = Result<Event, KalshiError>> + Send + 'static {
+        let kalshi: Kalshi<Anonymous, E> = self.with_state();
 
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(6);
+        // The state is the cursor of the next page to fetch, or `None` once the last page was returned.
+        let pages = stream::try_unfold(Some(None), move |next: Option<Option<Cursor>>| {
+            let kalshi = kalshi.clone();
+            let series_ticker = series_ticker.clone();
 
-        add_param!(params, "limit", limit);
-        add_param!(params, "status", status);
-        add_param!(params, "cursor", cursor);
-        add_param!(params, "series_ticker", series_ticker);
-        add_param!(params, "with_nested_markets", with_nested_markets);
+            async move {
+                let cursor = match next {
+                    Some(cursor) => cursor,
+                    None => return Ok(None),
+                };
 
-        let events_url =
-            reqwest::Url::parse_with_params(events_url, &params).unwrap_or_else(|err| {
-                eprintln!("{:?}", err);
-                panic!("Internal Parse Error, please contact developer!");
-            });
+                let (cursor, events) = kalshi
+                    .get_multiple_events(
+                        page_size,
+                        cursor,
+                        status,
+                        series_ticker,
+                        with_nested_markets,
+                    )
+                    .await?;
 
-        let result: PublicEventsResponse = self.client.get(events_url).send().await?.json().await?;
+                let next = cursor.into_next().map(Some);
+                Ok::<_, KalshiError>(Some((stream::iter(events.into_iter().map(Ok)), next)))
+            }
+        });
 
-        return Ok((result.cursor, result.events));
+        pages.try_flatten()
     }
+
     /// Asynchronously retrieves detailed information about a specific series from the Kalshi exchange.
     ///
     /// This method fetches data for a series identified by its ticker. The series data includes
@@ -226,124 +307,131 @@ impl Kalshi {
     /// let series = kalshi_instance.get_series(series_ticker).await.unwrap();
     /// ```
     pub async fn get_series(&self, ticker: &String) -> Result<Series, KalshiError> {
-        let series_url: &str = &format!("{}/series/{}", self.base_url.to_string(), ticker);
+        let series_url: &str = &format!("{}/series/{}", self.base_url(), ticker);
 
-        let result: SeriesResponse = self.client.get(series_url).send().await?.json().await?;
+        let result: SeriesResponse = self
+            .send_public(self.client.get(series_url))
+            .await?
+            .json()
+            .await?;
 
         return Ok(result.series);
     }
-    /// Asynchronously retrieves the order book for a specific market in the Kalshi exchange.
+
+    /// Asynchronously retrieves all series on the Kalshi exchange, optionally filtered by category and tags.
     ///
-    /// This method fetches the order book for a market, which includes the bid and ask prices
-    /// for both 'Yes' and 'No' options. It allows specifying the depth of the order book to be retrieved.
+    /// This method lets users enumerate the series in a category without knowing their tickers in advance,
+    /// e.g. to discover the universe of markets a scanner should watch.
     ///
     /// # Arguments
-    /// * `ticker` - A reference to a string representing the market's ticker.
-    /// * `depth` - An optional integer specifying the depth of the order book.
+    /// * `category` - An optional category to filter series by.
+    /// * `tags` - An optional string of comma-separated tags; only series with at least one of them are returned.
     ///
     /// # Returns
-    /// - `Ok(Orderbook)`: `Orderbook` object on successful retrieval.
+    /// - `Ok(Vec<Series>)`: A vector of `Series` objects on successful retrieval.
     /// - `Err(KalshiError)`: Error in case of a failure in the HTTP request or response parsing.
-    ///
     /// # Example
-    /// Returns an orderbook with a depth of 10 entries for some market.
     /// ```
-    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
-    /// let market_ticker = "some_market_ticker";
-    /// let orderbook = kalshi_instance.get_market_orderbook(market_ticker, Some(10)).await.unwrap();
+    /// let series = kalshi_instance
+    ///     .get_multiple_series(Some(Category::Economics), None)
+    ///     .await
+    ///     .unwrap();
     /// ```
-    pub async fn get_market_orderbook(
+    pub async fn get_multiple_series(
         &self,
-        ticker: &String,
-        depth: Option<i32>,
-    ) -> Result<Orderbook, KalshiError> {
-        let orderbook_url: &str =
-            &format!("{}/markets/{}/orderbook", self.base_url.to_string(), ticker);
-
-        let mut params: Vec<(&str, String)> = Vec::new();
-
-        add_param!(params, "depth", depth);
+        category: Option<Category>,
+        tags: Option<String>,
+    ) -> Result<Vec<Series>, KalshiError> {
+        let mut url = self.url_builder(format_args!("/series"));
+        url.param("category", category);
+        url.param("tags", tags);
 
-        let orderbook_url =
-            reqwest::Url::parse_with_params(orderbook_url, &params).unwrap_or_else(|err| {
-                eprintln!("{:?}", err);
-                panic!("Internal Parse Error, please contact developer!");
-            });
+        let series_url = url.finish()?;
 
-        let result: OrderBookResponse = self
-            .client
-            .get(orderbook_url)
-            .header("Authorization", self.curr_token.clone().unwrap())
-            .send()
+        let result: MultipleSeriesResponse = self
+            .send_public(self.client.get(series_url))
             .await?
             .json()
             .await?;
 
-        return Ok(result.orderbook);
+        Ok(result.series)
     }
 
-    /// Asynchronously retrieves the market history for a given market on the Kalshi exchange.
+    /// Retrieves a series together with all of its events and their markets, as a tree.
     ///
-    /// This method fetches historical data for a specific market, which can include
-    /// details like prices, bids, asks, volume, and open interest over time. It allows
-    /// filtering the history based on time and pagination parameters.
+    /// The series and the pages of its events, which include their nested markets, are requested
+    /// concurrently. Events are returned in the order the exchange lists them.
     ///
     /// # Arguments
-    /// * `ticker` - A reference to a string representing the market's ticker.
-    /// * `limit` - An optional integer to limit the number of history records returned.
-    /// * `cursor` - An optional string for pagination cursor.
-    /// * `min_ts` - An optional timestamp to specify the minimum time for history records.
-    /// * `max_ts` - An optional timestamp to specify the maximum time for history records.
+    /// * `series_ticker` - A reference to a string representing the series's ticker.
     ///
     /// # Returns
-    /// - `Ok((Option<String>, Vec<Snapshot>))`: A tuple containing an optional pagination cursor and a vector of `Snapshot` objects on success.
-    /// - `Err(KalshiError)`: Error in case of a failure in the HTTP request or response parsing.
+    /// - `Ok(SeriesTree)`: The series, its events and each event's markets on successful retrieval.
+    /// - `Err(KalshiError)`: Error in case of a failure in any of the HTTP requests or response parsing.
     /// # Example
-    ///
     /// ```
-    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
-    /// let market_history = kalshi_instance.get_market_history(
-    ///     "ticker_name",
-    ///     Some(10),
-    ///     None,
-    ///     None,
-    ///     None
-    /// ).await.unwrap();
+    /// let tree = kalshi_instance.get_series_tree(&"INXD".to_string()).await?;
+    /// for event in &tree.events {
+    ///     println!("{}: {} markets", event.event.event_ticker, event.markets.len());
+    /// }
     /// ```
-    pub async fn get_market_history(
-        &self,
-        ticker: &String,
-        limit: Option<i32>,
-        cursor: Option<String>,
-        min_ts: Option<i64>,
-        max_ts: Option<i64>,
-    ) -> Result<(Option<String>, Vec<Snapshot>), KalshiError> {
-        let market_history_url: &str =
-            &format! {"{}/markets/{}/history", self.base_url.to_string(), ticker};
+    pub async fn get_series_tree(&self, series_ticker: &String) -> Result<SeriesTree, KalshiError> {
+        let events = self
+            .get_events_stream(
+                Some(EVENTS_PAGE_SIZE),
+                None,
+                Some(series_ticker.clone()),
+                Some(true),
+            )
+            .try_collect::<Vec<Event>>();
 
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(5);
+        let (series, events) = tokio::try_join!(self.get_series(series_ticker), events)?;
 
-        add_param!(params, "limit", limit);
-        add_param!(params, "cursor", cursor);
-        add_param!(params, "min_ts", min_ts);
-        add_param!(params, "max_ts", max_ts);
+        let events = events
+            .into_iter()
+            .map(|mut event| EventTree {
+                markets: event.markets.take().unwrap_or_default(),
+                event,
+            })
+            .collect();
 
-        let market_history_url = reqwest::Url::parse_with_params(market_history_url, &params)
-            .unwrap_or_else(|err| {
-                eprintln!("{:?}", err);
-                panic!("Internal Parse Error, please contact developer!");
-            });
+        Ok(SeriesTree { series, events })
+    }
 
-        let result: MarketHistoryResponse = self
-            .client
-            .get(market_history_url)
-            .header("Authorization", self.curr_token.clone().unwrap())
-            .send()
-            .await?
-            .json()
-            .await?;
+    /// Retrieves a market together with its order book and most recent trades.
+    ///
+    /// The three requests are sent concurrently, which makes this the usual call for a dashboard
+    /// or an entry check that needs a current picture of a single market.
+    ///
+    /// # Arguments
+    /// * `ticker` - A reference to a string representing the market's ticker.
+    ///
+    /// # Returns
+    /// - `Ok(MarketBundle)`: The market, its full order book and up to 100 of its latest trades.
+    /// - `Err(KalshiError)`: Error in case of a failure in any of the HTTP requests or response parsing.
+    /// # Example
+    /// ```
+    /// let bundle = kalshi_instance.get_market_bundle(&"HIGHNY-23NOV13-T51".to_string()).await?;
+    /// println!("last {} with {} recent trades", bundle.market.last_price, bundle.trades.len());
+    /// ```
+    pub async fn get_market_bundle(&self, ticker: &String) -> Result<MarketBundle, KalshiError> {
+        let (market, orderbook, (_, trades)) = tokio::try_join!(
+            self.get_single_market(ticker),
+            self.get_market_orderbook(ticker, None),
+            self.get_trades(
+                None,
+                Some(BUNDLE_TRADES_LIMIT),
+                Some(ticker.clone()),
+                None,
+                None
+            ),
+        )?;
 
-        Ok((result.cursor, result.history))
+        Ok(MarketBundle {
+            market,
+            orderbook,
+            trades,
+        })
     }
 
     /// Asynchronously retrieves trade data from the Kalshi exchange.
@@ -353,14 +441,14 @@ impl Kalshi {
     /// such as time, ticker, and pagination options.
     ///
     /// # Arguments
-    /// * `cursor` - An optional string for pagination cursor.
+    /// * `cursor` - An optional cursor returned with the previous page.
     /// * `limit` - An optional integer to limit the number of trades returned.
     /// * `ticker` - An optional string representing the market's ticker for which trades are to be fetched.
     /// * `min_ts` - An optional timestamp to specify the minimum time for trade records.
     /// * `max_ts` - An optional timestamp to specify the maximum time for trade records.
     ///
     /// # Returns
-    /// - `Ok((Option<String>, Vec<Trade>))`: A tuple containing an optional pagination cursor and a vector of `Trade` objects on success.
+    /// - `Ok((Cursor, Vec<Trade>))`: A tuple containing the pagination cursor of the next page and a vector of `Trade` objects on success.
     /// - `Err(KalshiError)`: Error in case of a failure in the HTTP request or response parsing.
     /// ```
     /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
@@ -374,94 +462,811 @@ impl Kalshi {
     /// ```
     pub async fn get_trades(
         &self,
-        cursor: Option<String>,
+        cursor: Option<Cursor>,
         limit: Option<i32>,
         ticker: Option<String>,
         min_ts: Option<i64>,
         max_ts: Option<i64>,
-    ) -> Result<(Option<String>, Vec<Trade>), KalshiError> {
-        let trades_url: &str = &format!("{}/markets/trades", self.base_url.to_string());
-
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(7);
+    ) -> Result<(Cursor, Vec<Trade>), KalshiError> {
+        let mut url = self.url_builder(format_args!("/markets/trades"));
+        url.param("limit", limit);
+        url.param("cursor", cursor);
+        url.param("min_ts", min_ts);
+        url.param("max_ts", max_ts);
+        url.param("ticker", ticker);
 
-        add_param!(params, "limit", limit);
-        add_param!(params, "cursor", cursor);
-        add_param!(params, "min_ts", min_ts);
-        add_param!(params, "max_ts", max_ts);
-        add_param!(params, "ticker", ticker);
+        let trades_url = url.finish()?;
 
-        let trades_url =
-            reqwest::Url::parse_with_params(trades_url, &params).unwrap_or_else(|err| {
-                eprintln!("{:?}", err);
-                panic!("Internal Parse Error, please contact developer!");
-            });
-
-        let result: PublicTradesResponse = self.client.get(trades_url).send().await?.json().await?;
+        let result: PublicTradesResponse = self
+            .send_public(self.client.get(trades_url))
+            .await?
+            .json()
+            .await?;
 
         Ok((result.cursor, result.trades))
     }
-}
 
-// PRIVATE STRUCTS
-// used in get_single_event
-#[derive(Debug, Deserialize, Serialize)]
-struct SingleEventResponse {
-    event: Event,
-    markets: Option<Vec<Market>>,
-}
+    /// Streams the public trades matching the given filters, following pagination cursors transparently.
+    ///
+    /// The exchange returns trades newest first, so with `min_ts` set the stream ends once it has reached
+    /// that time, which makes downloading a market's complete trade history over a range a single call.
+    /// Pages are requested lazily as the stream is consumed. If a page fails to load, the error is
+    /// yielded and the stream ends.
+    ///
+    /// # Arguments
+    /// * `page_size` - An optional integer for the number of trades requested per page.
+    /// * `ticker` - An optional string representing the market's ticker for which trades are to be fetched.
+    /// * `min_ts` - An optional timestamp at which to stop, in seconds since the Unix epoch.
+    /// * `max_ts` - An optional timestamp of the newest trades to include.
+    ///
+    /// # Returns
+    /// A stream yielding `Ok(Trade)` for every matching trade, or `Err(KalshiError)` if a page failed to load.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::TryStreamExt;
+    ///
+    /// let trades: Vec<Trade> = kalshi_instance
+    ///     .get_trades_stream(Some(1000), Some("ticker_name".to_string()), Some(1_700_000_000), None)
+    ///     .try_collect()
+    ///     .await?;
+    /// ```
+    pub fn get_trades_stream(
+        &self,
+        page_size: Option<i32>,
+        ticker: Option<String>,
+        min_ts: Option<i64>,
+        max_ts: Option<i64>,
+    ) -> impl Stream<Item = Result<Trade, KalshiError>> + Send + 'static {
+        let kalshi: Kalshi<Anonymous, E> = self.with_state();
 
-// used in get_single_market
-#[derive(Debug, Deserialize, Serialize)]
-struct SingleMarketResponse {
-    market: Market,
-}
+        // The state is the cursor of the next page to fetch, or `None` once the last page was returned.
+        let pages = stream::try_unfold(Some(None), move |next: Option<Option<Cursor>>| {
+            let kalshi = kalshi.clone();
+            let ticker = ticker.clone();
 
-#[derive(Debug, Deserialize, Serialize)]
-struct PublicMarketsResponse {
-    cursor: Option<String>,
-    markets: Vec<Market>,
-}
+            async move {
+                let cursor = match next {
+                    Some(cursor) => cursor,
+                    None => return Ok(None),
+                };
 
-#[derive(Debug, Deserialize, Serialize)]
-struct PublicEventsResponse {
-    cursor: Option<String>,
-    events: Vec<Event>,
-}
+                let (cursor, trades) = kalshi
+                    .get_trades(cursor, page_size, ticker, min_ts, max_ts)
+                    .await?;
 
-#[derive(Debug, Deserialize, Serialize)]
-struct SeriesResponse {
-    series: Series,
-}
+                let next = cursor.into_next().map(Some);
+                Ok::<_, KalshiError>(Some((stream::iter(trades.into_iter().map(Ok)), next)))
+            }
+        });
 
-#[derive(Debug, Deserialize, Serialize)]
-struct OrderBookResponse {
-    orderbook: Orderbook,
-}
+        pages.try_flatten()
+    }
 
-#[derive(Debug, Deserialize, Serialize)]
-struct MarketHistoryResponse {
-    cursor: Option<String>,
-    ticker: String,
-    history: Vec<Snapshot>,
-}
+    /// Asynchronously retrieves information about multiple markets from the Kalshi exchange.
+    ///
+    /// This method fetches data for a collection of markets, filtered by various optional parameters.
+    /// It supports pagination, time-based filtering, and selection by specific tickers or statuses.
+    ///
+    /// # Arguments
+    /// * `limit` - An optional integer to limit the number of markets returned.
+    /// * `cursor` - An optional cursor returned with the previous page.
+    /// * `event_ticker` - An optional string to filter markets by event ticker.
+    /// * `series_ticker` - An optional string to filter markets by series ticker.
+    /// * `max_close_ts` - An optional timestamp for the maximum close time.
+    /// * `min_close_ts` - An optional timestamp for the minimum close time.
+    /// * `status` - An optional status to filter markets by.
+    /// * `tickers` - An optional list of market tickers to filter by. They are comma-joined and URL-encoded by the crate.
+    ///
+    /// # Returns
+    /// - `Ok((Cursor, Vec<Market>))`: A tuple containing the pagination cursor of the next page and a vector of `Market` objects on success.
+    /// - `Err(KalshiError)`: Error in case of a failure in the HTTP request or response parsing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let markets_result = kalshi_instance.get_multiple_markets(
+    ///     Some(10),
+    ///     None,
+    ///     Some("event_ticker"),
+    ///     None,
+    ///     None,
+    ///     None,
+    ///     None,
+    ///     None
+    /// ).await.unwrap();
+    /// ```
+    pub async fn get_multiple_markets(
+        &self,
+        limit: Option<i64>,
+        cursor: Option<Cursor>,
+        event_ticker: Option<String>,
+        series_ticker: Option<String>,
+        max_close_ts: Option<i64>,
+        min_close_ts: Option<i64>,
+        status: Option<MarketStatusFilter>,
+        tickers: Option<Vec<String>>,
+    ) -> Result<(Cursor, Vec<Market>), KalshiError> {
+        let mut url = self.url_builder(format_args!("/markets"));
+        url.param("limit", limit);
+        url.param("event_ticker", event_ticker);
+        url.param("series_ticker", series_ticker);
+        url.param("status", status);
+        url.param("cursor", cursor);
+        url.param("min_close_ts", min_close_ts);
+        url.param("max_close_ts", max_close_ts);
+        url.param(
+            "tickers",
+            tickers
+                .filter(|tickers| !tickers.is_empty())
+                .map(|tickers| tickers.join(",")),
+        );
 
-#[derive(Debug, Deserialize, Serialize)]
-struct PublicTradesResponse {
-    cursor: Option<String>,
-    trades: Vec<Trade>,
-}
+        let markets_url = url.finish()?;
 
-// PUBLIC STRUCTS
+        let result: PublicMarketsResponse = self
+            .send_public(self.client.get(markets_url))
+            .await?
+            .json()
+            .await?;
 
-/// A market in the Kalshi exchange.
-///
-/// Contains detailed information about the market including its ticker,
-/// type, status, and other relevant data.
-///
-#[derive(Debug, Deserialize, Serialize)]
-pub struct Market {
-    /// Unique identifier for the market.
-    pub ticker: String,
+        Ok((result.cursor, result.markets))
+    }
+
+    /// Asynchronously retrieves the order book for a specific market in the Kalshi exchange.
+    ///
+    /// This method fetches the order book for a market, which includes the bid and ask prices
+    /// for both 'Yes' and 'No' options. It allows specifying the depth of the order book to be retrieved.
+    ///
+    /// # Arguments
+    /// * `ticker` - A reference to a string representing the market's ticker.
+    /// * `depth` - An optional integer specifying the depth of the order book.
+    ///
+    /// # Returns
+    /// - `Ok(Orderbook)`: `Orderbook` object on successful retrieval.
+    /// - `Err(KalshiError)`: Error in case of a failure in the HTTP request or response parsing.
+    ///
+    /// # Example
+    /// Returns an orderbook with a depth of 10 entries for some market.
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let market_ticker = "some_market_ticker";
+    /// let orderbook = kalshi_instance.get_market_orderbook(market_ticker, Some(10)).await.unwrap();
+    /// ```
+    pub async fn get_market_orderbook(
+        &self,
+        ticker: &String,
+        depth: Option<i32>,
+    ) -> Result<Orderbook, KalshiError> {
+        let mut url = self.url_builder(format_args!("/markets/{}/orderbook", ticker));
+        url.param("depth", depth);
+
+        let orderbook_url = url.finish()?;
+
+        let result: OrderBookResponse = self
+            .send_public(self.client.get(orderbook_url))
+            .await?
+            .json()
+            .await?;
+
+        return Ok(result.orderbook);
+    }
+
+    /// Retrieves an order book like [`get_market_orderbook`](Kalshi::get_market_orderbook), unless it is unchanged since the last call.
+    ///
+    /// The validators `cache` holds from the previous response for this market and depth are sent along, so the exchange
+    /// can answer with `304 Not Modified` instead of the full order book.
+    ///
+    /// # Arguments
+    /// * `ticker` - A reference to a string representing the market's ticker.
+    /// * `depth` - An optional integer specifying the depth of the order book.
+    /// * `cache` - The polling loop's validators, updated with the response's.
+    ///
+    /// # Returns
+    /// - `Ok(Conditional<Orderbook>)`: The order book if it changed, or `Conditional::NotModified`.
+    /// - `Err(KalshiError)`: Error in case of a failure in the HTTP request or response parsing.
+    ///
+    /// # Example
+    /// ```
+    /// if let Some(orderbook) = kalshi_instance.get_market_orderbook_if_modified(&ticker, Some(10), &mut cache).await?.modified() {
+    ///     book = orderbook;
+    /// }
+    /// ```
+    pub async fn get_market_orderbook_if_modified(
+        &self,
+        ticker: &String,
+        depth: Option<i32>,
+        cache: &mut ConditionalCache,
+    ) -> Result<Conditional<Orderbook>, KalshiError> {
+        let mut url = self.url_builder(format_args!("/markets/{}/orderbook", ticker));
+        url.param("depth", depth);
+
+        let orderbook_url = url.finish()?;
+        let validator_key = orderbook_url.to_string();
+
+        let response = self
+            .send_public(cache.apply(self.client.get(orderbook_url), &validator_key))
+            .await?;
+
+        let result: Conditional<OrderBookResponse> = cache.read(&validator_key, response).await?;
+
+        Ok(result.map(|result| result.orderbook))
+    }
+
+    /// Asynchronously retrieves the market history for a given market on the Kalshi exchange.
+    ///
+    /// This method fetches historical data for a specific market, which can include
+    /// details like prices, bids, asks, volume, and open interest over time. It allows
+    /// filtering the history based on time and pagination parameters.
+    ///
+    /// # Arguments
+    /// * `ticker` - A reference to a string representing the market's ticker.
+    /// * `limit` - An optional integer to limit the number of history records returned.
+    /// * `cursor` - An optional cursor returned with the previous page.
+    /// * `min_ts` - An optional timestamp to specify the minimum time for history records.
+    /// * `max_ts` - An optional timestamp to specify the maximum time for history records.
+    ///
+    /// # Returns
+    /// - `Ok((Cursor, Vec<Snapshot>))`: A tuple containing the pagination cursor of the next page and a vector of `Snapshot` objects on success.
+    /// - `Err(KalshiError)`: Error in case of a failure in the HTTP request or response parsing.
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let market_history = kalshi_instance.get_market_history(
+    ///     "ticker_name",
+    ///     Some(10),
+    ///     None,
+    ///     None,
+    ///     None
+    /// ).await.unwrap();
+    /// ```
+    pub async fn get_market_history(
+        &self,
+        ticker: &String,
+        limit: Option<i32>,
+        cursor: Option<Cursor>,
+        min_ts: Option<i64>,
+        max_ts: Option<i64>,
+    ) -> Result<(Cursor, Vec<Snapshot>), KalshiError> {
+        let mut url = self.url_builder(format_args!("/markets/{}/history", ticker));
+        url.param("limit", limit);
+        url.param("cursor", cursor);
+        url.param("min_ts", min_ts);
+        url.param("max_ts", max_ts);
+
+        let market_history_url = url.finish()?;
+
+        let result: MarketHistoryResponse = self
+            .send_public(self.client.get(market_history_url))
+            .await?
+            .json()
+            .await?;
+
+        Ok((result.cursor, result.history))
+    }
+
+    /// Streams every market matching the given filters, following pagination cursors transparently.
+    ///
+    /// Pages are requested lazily, one at a time, as the stream is consumed, so consumers can walk the
+    /// entire universe of markets without plumbing cursors through [`get_multiple_markets`](Kalshi::get_multiple_markets).
+    /// The stream owns a clone of this instance and can be moved into a spawned task. If a page fails to load,
+    /// the error is yielded and the stream ends.
+    ///
+    /// # Arguments
+    /// * `page_size` - An optional integer for the number of markets requested per page.
+    /// * `event_ticker` - An optional string to filter markets by event ticker.
+    /// * `series_ticker` - An optional string to filter markets by series ticker.
+    /// * `max_close_ts` - An optional timestamp for the maximum close time.
+    /// * `min_close_ts` - An optional timestamp for the minimum close time.
+    /// * `status` - An optional status to filter markets by.
+    /// * `tickers` - An optional list of market tickers to filter by.
+    ///
+    /// # Returns
+    /// A stream yielding `Ok(Market)` for every matching market, or `Err(KalshiError)` if a page failed to load.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::StreamExt;
+    ///
+    /// let mut markets = Box::pin(kalshi_instance.get_markets_stream(
+    ///     Some(200),
+    ///     None,
+    ///     None,
+    ///     None,
+    ///     None,
+    ///     Some(MarketStatusFilter::Open),
+    ///     None,
+    /// ));
+    /// while let Some(market) = markets.next().await {
+    ///     println!("{}", market?.ticker);
+    /// }
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_markets_stream(
+        &self,
+        page_size: Option<i64>,
+        event_ticker: Option<String>,
+        series_ticker: Option<String>,
+        max_close_ts: Option<i64>,
+        min_close_ts: Option<i64>,
+        status: Option<MarketStatusFilter>,
+        tickers: Option<Vec<String>>,
+    ) -> impl Stream<Item = Result<Market, KalshiError>> + Send + 'static {
+        let kalshi: Kalshi<Anonymous, E> = self.with_state();
+
+        // The state is the cursor of the next page to fetch, or `None` once the last page was returned.
+        let pages = stream::try_unfold(Some(None), move |next: Option<Option<Cursor>>| {
+            let kalshi = kalshi.clone();
+            let event_ticker = event_ticker.clone();
+            let series_ticker = series_ticker.clone();
+            let tickers = tickers.clone();
+
+            async move {
+                let cursor = match next {
+                    Some(cursor) => cursor,
+                    None => return Ok(None),
+                };
+
+                let (cursor, markets) = kalshi
+                    .get_multiple_markets(
+                        page_size,
+                        cursor,
+                        event_ticker,
+                        series_ticker,
+                        max_close_ts,
+                        min_close_ts,
+                        status,
+                        tickers,
+                    )
+                    .await?;
+
+                let next = cursor.into_next().map(Some);
+                Ok::<_, KalshiError>(Some((stream::iter(markets.into_iter().map(Ok)), next)))
+            }
+        });
+
+        pages.try_flatten()
+    }
+
+    /// Retrieves every market matching the given filters, following pagination cursors until the last page.
+    ///
+    /// This is the cursor loop most users of [`get_multiple_markets`](Kalshi::get_multiple_markets) write by hand.
+    /// A politeness delay is awaited between pages so that collecting a large universe doesn't burn through
+    /// the account's rate limit. Use [`get_markets_stream`](Kalshi::get_markets_stream) to process markets
+    /// while pages are still loading.
+    ///
+    /// # Arguments
+    /// * `page_size` - An optional integer for the number of markets requested per page.
+    /// * `page_delay` - The time to wait between two page requests.
+    /// * `event_ticker` - An optional string to filter markets by event ticker.
+    /// * `series_ticker` - An optional string to filter markets by series ticker.
+    /// * `max_close_ts` - An optional timestamp for the maximum close time.
+    /// * `min_close_ts` - An optional timestamp for the minimum close time.
+    /// * `status` - An optional status to filter markets by.
+    /// * `tickers` - An optional list of market tickers to filter by.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Market>)`: Every matching market on success.
+    /// - `Err(KalshiError)`: Error in case of a failure in any of the HTTP requests or response parsing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let open_markets = kalshi_instance
+    ///     .get_all_markets(
+    ///         Some(200),
+    ///         Duration::from_millis(100),
+    ///         None,
+    ///         None,
+    ///         None,
+    ///         None,
+    ///         Some(MarketStatusFilter::Open),
+    ///         None,
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub async fn get_all_markets(
+        &self,
+        page_size: Option<i64>,
+        page_delay: Duration,
+        event_ticker: Option<String>,
+        series_ticker: Option<String>,
+        max_close_ts: Option<i64>,
+        min_close_ts: Option<i64>,
+        status: Option<MarketStatusFilter>,
+        tickers: Option<Vec<String>>,
+    ) -> Result<Vec<Market>, KalshiError> {
+        let mut markets = Vec::new();
+        let mut cursor = None;
+
+        loop {
+            let (next, page) = self
+                .get_multiple_markets(
+                    page_size,
+                    cursor,
+                    event_ticker.clone(),
+                    series_ticker.clone(),
+                    max_close_ts,
+                    min_close_ts,
+                    status,
+                    tickers.clone(),
+                )
+                .await?;
+            markets.extend(page);
+
+            match next.into_next() {
+                Some(next) => cursor = Some(next),
+                None => return Ok(markets),
+            }
+
+            tokio::time::sleep(page_delay).await;
+        }
+    }
+
+    /// Retrieves every market in a category, such as "Economics" or "Politics".
+    ///
+    /// Markets don't carry a category, only their events do, so this pages through the events with
+    /// their nested markets, keeps the ones in `category` and flattens their markets.
+    ///
+    /// # Arguments
+    /// * `category` - The category to retrieve markets for.
+    /// * `status` - An optional status to filter the events by.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Market>)`: Every market in the category on success.
+    /// - `Err(KalshiError)`: Error in case of a failure in any of the HTTP requests or response parsing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let markets = kalshi_instance
+    ///     .get_markets_by_category(&Category::Economics, Some(EventStatusFilter::Open))
+    ///     .await?;
+    /// ```
+    pub async fn get_markets_by_category(
+        &self,
+        category: &Category,
+        status: Option<EventStatusFilter>,
+    ) -> Result<Vec<Market>, KalshiError> {
+        let events = self.get_events_stream(Some(EVENTS_PAGE_SIZE), status, None, Some(true));
+
+        events
+            .try_filter(|event| futures::future::ready(&event.category == category))
+            .map_ok(|event| stream::iter(event.markets.unwrap_or_default().into_iter().map(Ok)))
+            .try_flatten()
+            .try_collect()
+            .await
+    }
+
+    /// Searches every market with the given status for those matching `predicate`.
+    ///
+    /// Pages through the markets like [`get_markets_stream`](Kalshi::get_markets_stream) and keeps
+    /// the ones the predicate accepts. The predicate sees each market once, so it can match titles
+    /// against a regex, compare close times or anything else the API cannot filter on.
+    ///
+    /// # Arguments
+    /// * `status` - An optional status to filter markets by before they reach the predicate.
+    /// * `predicate` - A closure returning `true` for the markets to keep.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Market>)`: Every matching market on success.
+    /// - `Err(KalshiError)`: Error in case of a failure in any of the HTTP requests or response parsing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let fed_markets = kalshi_instance
+    ///     .search_markets(Some(MarketStatusFilter::Open), |market| market.ticker.starts_with("FED"))
+    ///     .await?;
+    /// ```
+    pub async fn search_markets<F>(
+        &self,
+        status: Option<MarketStatusFilter>,
+        mut predicate: F,
+    ) -> Result<Vec<Market>, KalshiError>
+    where
+        F: FnMut(&Market) -> bool,
+    {
+        let markets =
+            self.get_markets_stream(Some(SEARCH_PAGE_SIZE), None, None, None, None, status, None);
+
+        markets
+            .try_filter(|market| futures::future::ready(predicate(market)))
+            .try_collect()
+            .await
+    }
+
+    /// Searches every market with the given status for those whose ticker, title or subtitle
+    /// contains `text`, ignoring case.
+    ///
+    /// # Arguments
+    /// * `status` - An optional status to filter markets by.
+    /// * `text` - The text to look for.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Market>)`: Every matching market on success.
+    /// - `Err(KalshiError)`: Error in case of a failure in any of the HTTP requests or response parsing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let markets = kalshi_instance
+    ///     .search_markets_by_text(Some(MarketStatusFilter::Open), "fed funds")
+    ///     .await?;
+    /// ```
+    pub async fn search_markets_by_text(
+        &self,
+        status: Option<MarketStatusFilter>,
+        text: &str,
+    ) -> Result<Vec<Market>, KalshiError> {
+        let text = text.to_lowercase();
+        self.search_markets(status, |market| {
+            [&market.ticker, &market.title, &market.subtitle]
+                .iter()
+                .any(|field| field.to_lowercase().contains(&text))
+        })
+        .await
+    }
+
+    /// Retrieves many markets by ticker, e.g. for pre-trade checks across a basket.
+    ///
+    /// Instead of one request per market, the tickers are sent in chunks through the `tickers` filter of
+    /// [`get_multiple_markets`](Kalshi::get_multiple_markets), and the chunks are requested concurrently.
+    /// Duplicate tickers are only requested once.
+    ///
+    /// # Arguments
+    /// * `tickers` - The tickers of the markets to retrieve.
+    ///
+    /// # Returns
+    /// - `Ok(HashMap<String, Market>)`: The markets by ticker. Tickers the exchange doesn't know are missing from the map.
+    /// - `Err(KalshiError)`: The first error encountered by any of the requests.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let markets = kalshi_instance.get_markets_batch(&basket).await?;
+    /// for ticker in &basket {
+    ///     match markets.get(ticker) {
+    ///         Some(market) => println!("{} ask {}", ticker, market.yes_ask),
+    ///         None => println!("{} not found", ticker),
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_markets_batch(
+        &self,
+        tickers: &[String],
+    ) -> Result<HashMap<String, Market>, KalshiError> {
+        let mut unique: Vec<String> = tickers.to_vec();
+        unique.sort();
+        unique.dedup();
+
+        let tasks = unique
+            .chunks(BATCH_TICKERS_PER_REQUEST)
+            .map(|chunk| {
+                let markets = self.get_markets_stream(
+                    Some(BATCH_TICKERS_PER_REQUEST as i64),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(chunk.to_vec()),
+                );
+                (chunk.join(","), markets.try_collect::<Vec<Market>>())
+            })
+            .collect();
+
+        let mut markets = HashMap::with_capacity(unique.len());
+        for chunk in utils::join_bounded(tasks, BATCH_CONCURRENCY).await {
+            markets.extend(
+                chunk?
+                    .into_iter()
+                    .map(|market| (market.ticker.clone(), market)),
+            );
+        }
+        Ok(markets)
+    }
+
+    /// Fetches many markets concurrently, optionally together with their order books.
+    ///
+    /// At most `max_concurrency` requests are in flight at once. A request the exchange rejects with
+    /// `429 Too Many Requests` is retried after a growing backoff, so a large universe slows down
+    /// instead of failing. A market that can't be fetched only fails its own entry.
+    ///
+    /// # Arguments
+    /// * `tickers` - The tickers of the markets to fetch.
+    /// * `with_orderbook` - Whether to also fetch the full order book of every market.
+    /// * `max_concurrency` - The maximum number of requests in flight at once.
+    ///
+    /// # Returns
+    /// Each ticker paired with its snapshot, or the error that prevented fetching it, in the order given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// for (ticker, snapshot) in kalshi_instance.snapshot_markets(tickers, true, 16).await {
+    ///     match snapshot {
+    ///         Ok(snapshot) => println!("{} yes bid {}", ticker, snapshot.market.yes_bid),
+    ///         Err(e) => eprintln!("{} failed: {}", ticker, e),
+    ///     }
+    /// }
+    /// ```
+    pub async fn snapshot_markets(
+        &self,
+        tickers: Vec<String>,
+        with_orderbook: bool,
+        max_concurrency: usize,
+    ) -> Vec<(String, Result<MarketSnapshot, KalshiError>)> {
+        let tasks = tickers
+            .iter()
+            .map(|ticker| {
+                (ticker.clone(), async move {
+                    self.fetch_market_snapshot(ticker, with_orderbook).await
+                })
+            })
+            .collect();
+
+        let results = utils::join_bounded(tasks, max_concurrency).await;
+        tickers.into_iter().zip(results).collect()
+    }
+
+    async fn fetch_market_snapshot(
+        &self,
+        ticker: &str,
+        with_orderbook: bool,
+    ) -> Result<MarketSnapshot, KalshiError> {
+        let market_url = self
+            .url_builder(format_args!("/markets/{}", ticker))
+            .finish()?;
+        let market: SingleMarketResponse = self.get_with_backoff(market_url).await?;
+
+        let orderbook = if with_orderbook {
+            let orderbook_url = self
+                .url_builder(format_args!("/markets/{}/orderbook", ticker))
+                .finish()?;
+            let result: OrderBookResponse = self.get_with_backoff(orderbook_url).await?;
+            Some(result.orderbook)
+        } else {
+            None
+        };
+
+        Ok(MarketSnapshot {
+            market: market.market,
+            orderbook,
+        })
+    }
+
+    /// Sends a GET request, retrying with a growing backoff while the exchange answers `429 Too Many Requests`.
+    ///
+    /// Returns a `RateLimitError` once the retries are used up, and the status error of any other failed response.
+    async fn get_with_backoff<T: DeserializeOwned>(
+        &self,
+        url: reqwest::Url,
+    ) -> Result<T, KalshiError> {
+        let mut attempt = 0;
+        loop {
+            let response = self.send_public(self.client.get(url.clone())).await?;
+
+            if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                if attempt == RATE_LIMIT_RETRIES {
+                    return Err(KalshiError::RateLimitError(format!(
+                        "{} was still rate limited after {} retries",
+                        url.path(),
+                        RATE_LIMIT_RETRIES
+                    )));
+                }
+                attempt += 1;
+                tokio::time::sleep(RATE_LIMIT_BACKOFF * attempt).await;
+                continue;
+            }
+
+            return Ok(response.error_for_status()?.json().await?);
+        }
+    }
+}
+
+// PRIVATE STRUCTS
+// used in get_single_event
+#[derive(Debug, Deserialize, Serialize)]
+struct SingleEventResponse {
+    event: Event,
+    markets: Option<Vec<Market>>,
+}
+
+impl SingleEventResponse {
+    // Without `with_nested_markets` the exchange sends the markets next to the event instead of inside it.
+    fn into_event(self) -> Event {
+        let mut event = self.event;
+        if event.markets.as_ref().map_or(true, Vec::is_empty) {
+            if let Some(markets) = self.markets.filter(|markets| !markets.is_empty()) {
+                event.markets = Some(markets);
+            }
+        }
+        event
+    }
+}
+
+// used in get_single_market
+#[derive(Debug, Deserialize, Serialize)]
+struct SingleMarketResponse {
+    market: Market,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct PublicMarketsResponse {
+    #[serde(default)]
+    cursor: Cursor,
+    markets: Vec<Market>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct PublicEventsResponse {
+    #[serde(default)]
+    cursor: Cursor,
+    events: Vec<Event>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct SeriesResponse {
+    series: Series,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct MultipleSeriesResponse {
+    series: Vec<Series>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct OrderBookResponse {
+    orderbook: Orderbook,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct MarketHistoryResponse {
+    #[serde(default)]
+    cursor: Cursor,
+    ticker: String,
+    history: Vec<Snapshot>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct PublicTradesResponse {
+    #[serde(default)]
+    cursor: Cursor,
+    trades: Vec<Trade>,
+}
+
+// PUBLIC STRUCTS
+
+/// A market together with its order book, as returned by [`Kalshi::snapshot_markets`].
+///
+#[derive(Debug)]
+pub struct MarketSnapshot {
+    /// The market.
+    pub market: Market,
+    /// The full order book of the market, if it was requested.
+    pub orderbook: Option<Orderbook>,
+}
+
+/// A market in the Kalshi exchange.
+///
+/// Contains detailed information about the market including its ticker,
+/// type, status, and other relevant data.
+///
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Market {
+    /// Unique identifier for the market.
+    pub ticker: String,
     /// Ticker of the associated event.
     pub event_ticker: String,
     /// Type of the market.
@@ -475,21 +1280,21 @@ pub struct Market {
     /// Subtitle for the 'No' option in the market.
     pub no_sub_title: String,
     /// Opening time of the market.
-    pub open_time: String,
+    pub open_time: Timestamp,
     /// Closing time of the market.
-    pub close_time: String,
+    pub close_time: Timestamp,
     /// Expected expiration time of the market.
-    pub expected_expiration_time: Option<String>,
+    pub expected_expiration_time: Option<Timestamp>,
     /// Actual expiration time of the market.
-    pub expiration_time: Option<String>,
+    pub expiration_time: Option<Timestamp>,
     /// Latest possible expiration time of the market.
-    pub latest_expiration_time: String,
+    pub latest_expiration_time: Timestamp,
     /// Countdown in seconds to the settlement.
     pub settlement_timer_seconds: i64,
     /// Current status of the market.
-    pub status: String,
-    /// Units used for pricing responses.
-    pub response_price_units: String,
+    pub status: MarketStatus,
+    /// Units the market's prices are quoted in.
+    pub response_price_units: PriceUnits,
     /// Notional value of the market.
     pub notional_value: i64,
     /// Minimum price movement in the market.
@@ -526,9 +1331,11 @@ pub struct Market {
     pub can_close_early: bool,
     /// Value at expiration.
     pub expiration_value: String,
-    /// Category of the market.
-    pub category: String,
-    /// Risk limit in cents.
+    /// Category of the market. Deprecated by the exchange, `None` if missing or empty; use the event's category.
+    #[serde(default, deserialize_with = "empty_category_is_none")]
+    pub category: Option<Category>,
+    /// Risk limit in cents. Deprecated by the exchange, zero if missing.
+    #[serde(default)]
     pub risk_limit_cents: i64,
     /// Type of strike, if applicable.
     pub strike_type: Option<String>,
@@ -542,6 +1349,96 @@ pub struct Market {
     pub settlement_value: Option<String>,
     /// Functional strike information, if applicable.
     pub functional_strike: Option<String>,
+    /// Condition under which the market closes early, if it can.
+    pub early_close_condition: Option<String>,
+}
+
+impl Market {
+    /// Returns the time left until the market closes, or zero if it already closed.
+    ///
+    /// Only available with the `chrono` feature enabled.
+    #[cfg(feature = "chrono")]
+    pub fn time_until_close(&self) -> Duration {
+        let now = chrono::DateTime::<chrono::Utc>::from(std::time::SystemTime::now());
+        (self.close_time - now).to_std().unwrap_or(Duration::ZERO)
+    }
+
+    /// Returns whether the market closes within `duration` from now, including if it already closed.
+    ///
+    /// Only available with the `chrono` feature enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// if market.closes_within(Duration::from_secs(15 * 60)) {
+    ///     // Stop quoting and flatten before expiration.
+    /// }
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn closes_within(&self, duration: Duration) -> bool {
+        self.time_until_close() <= duration
+    }
+
+    /// Returns the strike of the market, combining `strike_type`, `floor_strike`, `cap_strike`
+    /// and `functional_strike` into one value.
+    ///
+    /// Returns `None` if the market has no strike, or if its strike type is missing a bound it requires.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// if let Some(Strike::Between(floor, cap)) = market.strike() {
+    ///     println!("{} resolves 'Yes' between {} and {}", market.ticker, floor, cap);
+    /// }
+    /// ```
+    pub fn strike(&self) -> Option<Strike> {
+        Strike::from_parts(
+            self.strike_type.as_deref(),
+            self.floor_strike,
+            self.cap_strike,
+            self.functional_strike.as_deref(),
+        )
+    }
+}
+
+/// The strike of a market: the condition on the underlying value under which it resolves to 'Yes'.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Strike {
+    /// The value must be strictly greater than the floor.
+    Floor(f64),
+    /// The value must be greater than or equal to the floor.
+    FloorInclusive(f64),
+    /// The value must be strictly less than the cap.
+    Cap(f64),
+    /// The value must be less than or equal to the cap.
+    CapInclusive(f64),
+    /// The value must lie between the floor and the cap.
+    Between(f64, f64),
+    /// The condition is described by a formula rather than fixed bounds.
+    Functional(String),
+    /// A strike type not recognized by this version of the crate, holding the raw type.
+    Other(String),
+}
+
+impl Strike {
+    fn from_parts(
+        strike_type: Option<&str>,
+        floor: Option<f64>,
+        cap: Option<f64>,
+        functional: Option<&str>,
+    ) -> Option<Strike> {
+        match strike_type {
+            Some("greater") => floor.map(Strike::Floor),
+            Some("greater_or_equal") => floor.map(Strike::FloorInclusive),
+            Some("less") => cap.map(Strike::Cap),
+            Some("less_or_equal") => cap.map(Strike::CapInclusive),
+            Some("between") => Some(Strike::Between(floor?, cap?)),
+            Some("functional") => functional.map(|f| Strike::Functional(f.to_string())),
+            Some("") | None => None,
+            Some(other) => Some(Strike::Other(other.to_string())),
+        }
+    }
 }
 
 /// An event in the Kalshi exchange.
@@ -562,11 +1459,12 @@ pub struct Event {
     /// Indicates if the event's outcomes are mutually exclusive.
     pub mutually_exclusive: bool,
     /// Category of the event.
-    pub category: String,
-    /// Optional list of markets associated with this event.
+    pub category: Category,
+    /// The markets of this event. Requested with `with_nested_markets`, and always filled in by
+    /// [`Kalshi::get_single_event`].
     pub markets: Option<Vec<Market>>,
     /// Optional date of the event's occurrence.
-    pub strike_date: Option<String>,
+    pub strike_date: Option<Timestamp>,
     /// Optional period of the event.
     pub strike_period: Option<String>,
 }
@@ -586,7 +1484,7 @@ pub struct Series {
     /// Title of the series.
     pub title: String,
     /// Category of the series.
-    pub category: String,
+    pub category: Category,
     /// Tags associated with the series.
     pub tags: Vec<String>,
     /// Sources used for settling the series.
@@ -595,6 +1493,40 @@ pub struct Series {
     pub contract_url: String,
 }
 
+/// A series with all of its events and their markets, as returned by [`Kalshi::get_series_tree`].
+///
+#[derive(Debug)]
+pub struct SeriesTree {
+    /// The series.
+    pub series: Series,
+    /// Every event of the series.
+    pub events: Vec<EventTree>,
+}
+
+/// An event of a [`SeriesTree`] together with its markets.
+///
+/// The markets are moved out of the event, so `event.markets` is always `None`.
+///
+#[derive(Debug)]
+pub struct EventTree {
+    /// The event.
+    pub event: Event,
+    /// Every market of the event.
+    pub markets: Vec<Market>,
+}
+
+/// A market with its order book and recent trades, as returned by [`Kalshi::get_market_bundle`].
+///
+#[derive(Debug)]
+pub struct MarketBundle {
+    /// The market.
+    pub market: Market,
+    /// The market's order book.
+    pub orderbook: Orderbook,
+    /// The market's most recent trades, newest first.
+    pub trades: Vec<Trade>,
+}
+
 /// A source of a settlement in the Kalshi exchange.
 ///
 /// This struct contains information about a source used for settling a series, including the source's URL and name.
@@ -609,16 +1541,356 @@ pub struct SettlementSource {
 
 /// The order book of a market in the Kalshi exchange.
 ///
-/// This struct includes the bid and ask prices for both 'Yes' and 'No' options in a market, structured as nested vectors.
+/// This struct includes the resting bids for both 'Yes' and 'No' options in a market, as price levels sorted best-first.
+/// A bid for 'No' at a given price is equivalent to an ask for 'Yes' at 100 minus that price.
 ///
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(from = "RawOrderbook", into = "RawOrderbook")]
 pub struct Orderbook {
-    /// Nested vector of bids and asks for the 'Yes' option.
-    /// Each inner vector typically contains price and quantity.
-    pub yes: Option<Vec<Vec<i32>>>,
-    /// Nested vector of bids and asks for the 'No' option.
-    /// Each inner vector typically contains price and quantity.
-    pub no: Option<Vec<Vec<i32>>>,
+    /// Bids for the 'Yes' option, highest price first. Empty if there are none.
+    pub yes: Vec<OrderbookLevel>,
+    /// Bids for the 'No' option, highest price first. Empty if there are none.
+    pub no: Vec<OrderbookLevel>,
+}
+
+impl Orderbook {
+    /// Returns the best (highest) bid for the 'Yes' option, if any.
+    pub fn best_yes_bid(&self) -> Option<OrderbookLevel> {
+        self.yes.first().copied()
+    }
+
+    /// Returns the best (highest) bid for the 'No' option, if any.
+    pub fn best_no_bid(&self) -> Option<OrderbookLevel> {
+        self.no.first().copied()
+    }
+
+    /// Returns the 'Yes' levels in the exchange's raw format: `[price, quantity]` pairs sorted by ascending price,
+    /// or `None` if there are no levels. Kept for compatibility with code written against the nested vectors.
+    /// Quantities that don't fit an `i32` are capped at `i32::MAX`.
+    pub fn raw_yes(&self) -> Option<Vec<Vec<i32>>> {
+        raw_levels(&self.yes)
+    }
+
+    /// Returns the 'No' levels in the exchange's raw format: `[price, quantity]` pairs sorted by ascending price,
+    /// or `None` if there are no levels. Kept for compatibility with code written against the nested vectors.
+    /// Quantities that don't fit an `i32` are capped at `i32::MAX`.
+    pub fn raw_no(&self) -> Option<Vec<Vec<i32>>> {
+        raw_levels(&self.no)
+    }
+
+    /// Compares this order book with a later snapshot of the same market, returning every level that changed.
+    ///
+    /// Lets polling bots detect flow, such as new bids, pulled liquidity or fills, without a websocket connection.
+    /// Changes are listed 'Yes' side first, each side by ascending price.
+    ///
+    /// # Arguments
+    ///
+    /// * `newer` - The later snapshot of the order book.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let before = kalshi_instance.get_market_orderbook(&ticker, None).await?;
+    /// let after = kalshi_instance.get_market_orderbook(&ticker, None).await?;
+    /// for change in before.diff(&after) {
+    ///     println!("{:?} {:+}", change, change.quantity_delta());
+    /// }
+    /// ```
+    ///
+    pub fn diff(&self, newer: &Orderbook) -> Vec<LevelChange> {
+        let mut changes = diff_levels(Side::Yes, &self.yes, &newer.yes);
+        changes.extend(diff_levels(Side::No, &self.no, &newer.no));
+        changes
+    }
+
+    /// Simulates sweeping the book with an order for `count` contracts, without sending anything.
+    ///
+    /// Buying 'Yes' takes the 'No' bids, at 100 minus their price, and selling 'Yes' hits the 'Yes' bids;
+    /// the 'No' side mirrors this. Levels are consumed best price first until `count` contracts are filled
+    /// or the book runs out, which gives the average fill price and cost to expect from a market order.
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - The side of the market the order trades.
+    /// * `action` - Whether the order buys or sells contracts of `side`.
+    /// * `count` - The number of contracts to fill.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let book = kalshi_instance.get_market_orderbook(&ticker, None).await?;
+    /// let estimate = book.estimate_fill(Side::Yes, Action::Buy, 500);
+    /// if estimate.unfilled == 0 && estimate.average_price() < Some(60.0) {
+    ///     // Enough liquidity at an acceptable price.
+    /// }
+    /// ```
+    ///
+    pub fn estimate_fill(&self, side: Side, action: Action, count: i64) -> FillEstimate {
+        // Buying takes the opposite side's bids, at the complementary price.
+        let (levels, complement) = match (side, action) {
+            (Side::Yes, Action::Buy) => (&self.no, true),
+            (Side::Yes, Action::Sell) => (&self.yes, false),
+            (Side::No, Action::Buy) => (&self.yes, true),
+            (Side::No, Action::Sell) => (&self.no, false),
+        };
+
+        let mut estimate = FillEstimate {
+            filled: 0,
+            unfilled: count.max(0),
+            total_cost: Cents::ZERO,
+            worst_price: None,
+        };
+
+        for level in levels {
+            if estimate.unfilled == 0 {
+                break;
+            }
+            let price = if complement {
+                Cents(CONTRACT_PAYOUT_CENTS - level.price)
+            } else {
+                Cents(level.price)
+            };
+            let quantity = level.quantity.min(estimate.unfilled);
+            if quantity <= 0 {
+                continue;
+            }
+
+            estimate.filled += quantity;
+            estimate.unfilled -= quantity;
+            estimate.total_cost = price
+                .checked_mul_count(quantity)
+                .and_then(|cost| estimate.total_cost.checked_add(cost))
+                .unwrap_or(Cents(i64::MAX));
+            estimate.worst_price = Some(price);
+        }
+
+        estimate
+    }
+
+    /// Returns the ratio of 'Yes' bid size to 'Yes' ask size within the best `depth_levels` levels of each side.
+    ///
+    /// The 'Yes' asks are the 'No' bids, so this compares the contracts resting on the best levels of each side.
+    /// Values above one mean more size is bidding for 'Yes' than offering it, a common short-term signal of
+    /// buying pressure.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth_levels` - The number of price levels of each side to include, best first.
+    ///
+    /// # Returns
+    ///
+    /// The bid/ask size ratio, or `None` if there's no ask size within the levels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let book = kalshi_instance.get_market_orderbook(&ticker, None).await?;
+    /// if book.imbalance(3).map_or(false, |ratio| ratio > 2.0) {
+    ///     // Bids outweigh asks two to one near the touch.
+    /// }
+    /// ```
+    ///
+    pub fn imbalance(&self, depth_levels: usize) -> Option<f64> {
+        let size = |levels: &[OrderbookLevel]| -> i64 {
+            levels
+                .iter()
+                .take(depth_levels)
+                .map(|level| level.quantity.max(0))
+                .fold(0, i64::saturating_add)
+        };
+
+        let asks = size(&self.no);
+        if asks == 0 {
+            return None;
+        }
+        Some(size(&self.yes) as f64 / asks as f64)
+    }
+}
+
+/// The expected outcome of sweeping an [`Orderbook`], as returned by [`Orderbook::estimate_fill`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FillEstimate {
+    /// Number of contracts the book can fill.
+    pub filled: i64,
+    /// Number of contracts left unfilled once the book is exhausted.
+    pub unfilled: i64,
+    /// Total paid when buying, or received when selling, for the filled contracts.
+    pub total_cost: Cents,
+    /// Price of the last level reached, the worst price the order would trade at. `None` if nothing fills.
+    pub worst_price: Option<Cents>,
+}
+
+impl FillEstimate {
+    /// Returns the average fill price in cents, or `None` if nothing fills.
+    pub fn average_price(&self) -> Option<f64> {
+        if self.filled == 0 {
+            return None;
+        }
+        Some(self.total_cost.value() as f64 / self.filled as f64)
+    }
+}
+
+/// A change to one price level between two [`Orderbook`] snapshots, as returned by [`Orderbook::diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum LevelChange {
+    /// A price level that wasn't in the older snapshot.
+    Added {
+        /// Side of the book the level is on.
+        side: Side,
+        /// The new level.
+        level: OrderbookLevel,
+    },
+    /// A price level that is no longer in the newer snapshot.
+    Removed {
+        /// Side of the book the level was on.
+        side: Side,
+        /// The level as it was in the older snapshot.
+        level: OrderbookLevel,
+    },
+    /// A price level whose resting quantity changed.
+    Changed {
+        /// Side of the book the level is on.
+        side: Side,
+        /// Price of the level, in cents.
+        price: i64,
+        /// Quantity in the older snapshot.
+        before: i64,
+        /// Quantity in the newer snapshot.
+        after: i64,
+    },
+}
+
+impl LevelChange {
+    /// Returns the side of the book the change is on.
+    pub fn side(&self) -> Side {
+        match self {
+            LevelChange::Added { side, .. }
+            | LevelChange::Removed { side, .. }
+            | LevelChange::Changed { side, .. } => *side,
+        }
+    }
+
+    /// Returns the price of the changed level, in cents.
+    pub fn price(&self) -> i64 {
+        match self {
+            LevelChange::Added { level, .. } | LevelChange::Removed { level, .. } => level.price,
+            LevelChange::Changed { price, .. } => *price,
+        }
+    }
+
+    /// Returns how many contracts were added to (positive) or removed from (negative) the level.
+    pub fn quantity_delta(&self) -> i64 {
+        match self {
+            LevelChange::Added { level, .. } => level.quantity,
+            LevelChange::Removed { level, .. } => -level.quantity,
+            LevelChange::Changed { before, after, .. } => after - before,
+        }
+    }
+}
+
+/// A single price level of an [`Orderbook`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct OrderbookLevel {
+    /// Price of the level, in cents.
+    pub price: i64,
+    /// Number of contracts resting at this price.
+    pub quantity: i64,
+}
+
+// The orderbook as sent by the exchange: `[price, quantity]` pairs sorted by ascending price, or null.
+#[derive(Debug, Deserialize, Serialize)]
+struct RawOrderbook {
+    yes: Option<Vec<(i64, i64)>>,
+    no: Option<Vec<(i64, i64)>>,
+}
+
+impl From<RawOrderbook> for Orderbook {
+    fn from(raw: RawOrderbook) -> Self {
+        let levels = |side: Option<Vec<(i64, i64)>>| {
+            let mut levels: Vec<OrderbookLevel> = side
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(price, quantity)| OrderbookLevel { price, quantity })
+                .collect();
+            levels.sort_by_key(|level| std::cmp::Reverse(level.price));
+            levels
+        };
+
+        Orderbook {
+            yes: levels(raw.yes),
+            no: levels(raw.no),
+        }
+    }
+}
+
+impl From<Orderbook> for RawOrderbook {
+    fn from(orderbook: Orderbook) -> Self {
+        let levels = |side: Vec<OrderbookLevel>| {
+            if side.is_empty() {
+                return None;
+            }
+            Some(
+                side.into_iter()
+                    .rev()
+                    .map(|level| (level.price, level.quantity))
+                    .collect(),
+            )
+        };
+
+        RawOrderbook {
+            yes: levels(orderbook.yes),
+            no: levels(orderbook.no),
+        }
+    }
+}
+
+fn diff_levels(side: Side, older: &[OrderbookLevel], newer: &[OrderbookLevel]) -> Vec<LevelChange> {
+    let mut levels: BTreeMap<i64, (i64, i64)> = BTreeMap::new();
+    for level in older {
+        levels.entry(level.price).or_default().0 += level.quantity;
+    }
+    for level in newer {
+        levels.entry(level.price).or_default().1 += level.quantity;
+    }
+
+    levels
+        .into_iter()
+        .filter_map(|(price, (before, after))| match (before, after) {
+            (before, after) if before == after => None,
+            (0, quantity) => Some(LevelChange::Added {
+                side,
+                level: OrderbookLevel { price, quantity },
+            }),
+            (quantity, 0) => Some(LevelChange::Removed {
+                side,
+                level: OrderbookLevel { price, quantity },
+            }),
+            (before, after) => Some(LevelChange::Changed {
+                side,
+                price,
+                before,
+                after,
+            }),
+        })
+        .collect()
+}
+
+fn raw_levels(levels: &[OrderbookLevel]) -> Option<Vec<Vec<i32>>> {
+    if levels.is_empty() {
+        return None;
+    }
+    Some(
+        levels
+            .iter()
+            .rev()
+            .map(|level| vec![saturating_i32(level.price), saturating_i32(level.quantity)])
+            .collect(),
+    )
+}
+
+fn saturating_i32(value: i64) -> i32 {
+    i32::try_from(value).unwrap_or(if value < 0 { i32::MIN } else { i32::MAX })
 }
 
 /// Snapshot of market data in the Kalshi exchange.
@@ -628,23 +1900,45 @@ pub struct Orderbook {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Snapshot {
     /// Last traded price for the 'Yes' option.
-    pub yes_price: i32,
+    pub yes_price: Cents,
     /// Current highest bid price for the 'Yes' option.
-    pub yes_bid: i32,
+    pub yes_bid: Cents,
     /// Current lowest ask price for the 'Yes' option.
-    pub yes_ask: i32,
+    pub yes_ask: Cents,
     /// Current highest bid price for the 'No' option.
-    pub no_bid: i32,
+    pub no_bid: Cents,
     /// Current lowest ask price for the 'No' option.
-    pub no_ask: i32,
+    pub no_ask: Cents,
     /// Total trading volume at the snapshot time.
     pub volume: i32,
     /// Open interest at the snapshot time.
     pub open_interest: i32,
-    /// Timestamp of the snapshot.
+    /// Timestamp of the snapshot, in seconds since the Unix epoch.
     pub ts: i64,
 }
 
+impl Snapshot {
+    /// Returns the midpoint between the best 'Yes' bid and ask, in cents.
+    pub fn mid(&self) -> f64 {
+        (self.yes_bid.value() as f64 + self.yes_ask.value() as f64) / 2.0
+    }
+
+    /// Returns the difference between the best 'Yes' ask and bid, or `None` if it overflows.
+    pub fn spread(&self) -> Option<Cents> {
+        self.yes_ask.checked_sub(self.yes_bid)
+    }
+
+    /// Returns the time of the snapshot.
+    ///
+    /// Only available with the `chrono` feature enabled.
+    #[cfg(feature = "chrono")]
+    pub fn time(&self) -> Timestamp {
+        chrono::DateTime::<chrono::Utc>::from(
+            std::time::UNIX_EPOCH + Duration::from_secs(self.ts.max(0) as u64),
+        )
+    }
+}
+
 /// A trade in the Kalshi exchange.
 ///
 /// This struct contains details of an individual trade, including the trade ID, side, ticker, and executed prices.
@@ -666,7 +1960,7 @@ pub struct Trade {
     /// Executed price for the 'No' option.
     pub no_price: i32,
     /// Time when the trade was created.
-    pub created_time: String,
+    pub created_time: Timestamp,
 }
 
 /// Possible outcomes of a market settlement on the Kalshi exchange.
@@ -692,19 +1986,528 @@ pub enum SettlementResult {
     AllYes,
 }
 
+/// The market statuses [`get_multiple_markets`](Kalshi::get_multiple_markets) can filter by.
+///
+/// These differ from the statuses reported on a [`Market`]: the exchange only accepts these values
+/// as a filter and silently returns no markets for anything else.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MarketStatusFilter {
+    /// Markets that have not opened for trading yet.
+    Unopened,
+    /// Markets that are open for trading.
+    Open,
+    /// Markets that are closed but not settled yet.
+    Closed,
+    /// Markets that have been settled.
+    Settled,
+}
+
+impl fmt::Display for MarketStatusFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarketStatusFilter::Unopened => write!(f, "unopened"),
+            MarketStatusFilter::Open => write!(f, "open"),
+            MarketStatusFilter::Closed => write!(f, "closed"),
+            MarketStatusFilter::Settled => write!(f, "settled"),
+        }
+    }
+}
+
+/// The event statuses [`get_multiple_events`](Kalshi::get_multiple_events) can filter by.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventStatusFilter {
+    /// Events with markets open for trading.
+    Open,
+    /// Events whose markets are closed but not settled yet.
+    Closed,
+    /// Events whose markets have been settled.
+    Settled,
+}
+
+impl fmt::Display for EventStatusFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventStatusFilter::Open => write!(f, "open"),
+            EventStatusFilter::Closed => write!(f, "closed"),
+            EventStatusFilter::Settled => write!(f, "settled"),
+        }
+    }
+}
+
 /// The different statuses a market can have on the Kalshi exchange.
 ///
 /// This enum is used to represent the current operational state of a market.
+/// Statuses this crate doesn't know about yet are kept in [`Unknown`](MarketStatus::Unknown)
+/// instead of failing to deserialize the whole market.
 ///
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MarketStatus {
+    /// The market has been created but has not opened for trading yet.
+    Unopened,
+
+    /// The market has been initialized and will open for trading at its open time.
+    Initialized,
+
     /// The market is open for trading.
     Open,
 
+    /// The market is open and actively trading.
+    Active,
+
     /// The market is closed and not currently available for trading.
     Closed,
 
+    /// The market's outcome has been determined but not settled yet.
+    Determined,
+
     /// The market has been settled, and the outcome is determined.
     Settled,
+
+    /// The market has been settled and its payouts are final.
+    Finalized,
+
+    /// A status not recognized by this version of the crate, holding the raw value.
+    Unknown(String),
+}
+
+impl fmt::Display for MarketStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarketStatus::Unopened => write!(f, "unopened"),
+            MarketStatus::Initialized => write!(f, "initialized"),
+            MarketStatus::Open => write!(f, "open"),
+            MarketStatus::Active => write!(f, "active"),
+            MarketStatus::Closed => write!(f, "closed"),
+            MarketStatus::Determined => write!(f, "determined"),
+            MarketStatus::Settled => write!(f, "settled"),
+            MarketStatus::Finalized => write!(f, "finalized"),
+            MarketStatus::Unknown(status) => write!(f, "{}", status),
+        }
+    }
+}
+
+impl From<&str> for MarketStatus {
+    fn from(status: &str) -> Self {
+        match status {
+            "unopened" => MarketStatus::Unopened,
+            "initialized" => MarketStatus::Initialized,
+            "open" => MarketStatus::Open,
+            "active" => MarketStatus::Active,
+            "closed" => MarketStatus::Closed,
+            "determined" => MarketStatus::Determined,
+            "settled" => MarketStatus::Settled,
+            "finalized" => MarketStatus::Finalized,
+            other => MarketStatus::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl Serialize for MarketStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MarketStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let status = String::deserialize(deserializer)?;
+        Ok(MarketStatus::from(status.as_str()))
+    }
+}
+
+/// The categories markets, events and series are grouped into on the Kalshi exchange.
+///
+/// Known categories are matched ignoring case. Categories this crate doesn't know about yet are kept
+/// in [`Other`](Category::Other) with their raw name, so routing logic can match on the known ones
+/// without failing on new ones.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Category {
+    /// Economic data releases, such as inflation, jobs and GDP.
+    Economics,
+    /// Financial markets, such as index and commodity prices.
+    Financials,
+    /// Cryptocurrency prices.
+    Crypto,
+    /// Politics and government.
+    Politics,
+    /// Election outcomes.
+    Elections,
+    /// Weather and climate measurements.
+    ClimateAndWeather,
+    /// Scientific and technological developments.
+    ScienceAndTechnology,
+    /// Company announcements and results.
+    Companies,
+    /// Film, music, television and awards.
+    Entertainment,
+    /// Sports results.
+    Sports,
+    /// Public health.
+    Health,
+    /// World events.
+    World,
+    /// Transportation, such as flights and travel.
+    Transportation,
+    /// Social media and culture.
+    Social,
+    /// A category not recognized by this version of the crate, holding the raw name.
+    Other(String),
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Category::Economics => write!(f, "Economics"),
+            Category::Financials => write!(f, "Financials"),
+            Category::Crypto => write!(f, "Crypto"),
+            Category::Politics => write!(f, "Politics"),
+            Category::Elections => write!(f, "Elections"),
+            Category::ClimateAndWeather => write!(f, "Climate and Weather"),
+            Category::ScienceAndTechnology => write!(f, "Science and Technology"),
+            Category::Companies => write!(f, "Companies"),
+            Category::Entertainment => write!(f, "Entertainment"),
+            Category::Sports => write!(f, "Sports"),
+            Category::Health => write!(f, "Health"),
+            Category::World => write!(f, "World"),
+            Category::Transportation => write!(f, "Transportation"),
+            Category::Social => write!(f, "Social"),
+            Category::Other(category) => write!(f, "{}", category),
+        }
+    }
+}
+
+impl From<&str> for Category {
+    fn from(category: &str) -> Self {
+        match category.to_ascii_lowercase().as_str() {
+            "economics" => Category::Economics,
+            "financials" => Category::Financials,
+            "crypto" => Category::Crypto,
+            "politics" => Category::Politics,
+            "elections" => Category::Elections,
+            "climate and weather" => Category::ClimateAndWeather,
+            "science and technology" => Category::ScienceAndTechnology,
+            "companies" => Category::Companies,
+            "entertainment" => Category::Entertainment,
+            "sports" => Category::Sports,
+            "health" => Category::Health,
+            "world" => Category::World,
+            "transportation" => Category::Transportation,
+            "social" => Category::Social,
+            _ => Category::Other(category.to_string()),
+        }
+    }
+}
+
+impl Serialize for Category {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Category {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let category = String::deserialize(deserializer)?;
+        Ok(Category::from(category.as_str()))
+    }
+}
+
+// The exchange sends an empty string for markets without a category.
+fn empty_category_is_none<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Category>, D::Error> {
+    let category = Option::<String>::deserialize(deserializer)?;
+    Ok(category
+        .filter(|category| !category.is_empty())
+        .map(|category| Category::from(category.as_str())))
+}
+
+/// The units a market's prices are quoted in.
+///
+/// Every market is currently quoted in cents. Units this crate doesn't know about yet are kept in
+/// [`Unknown`](PriceUnits::Unknown), so a new unit can be detected instead of misread as cents.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PriceUnits {
+    /// Prices are in US cents.
+    UsdCent,
+
+    /// Units not recognized by this version of the crate, holding the raw value.
+    Unknown(String),
+}
+
+impl PriceUnits {
+    /// Converts a price quoted in these units to [`Cents`], or `None` if the units are unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let yes_bid = market.response_price_units.to_cents(market.yes_bid).expect("unsupported units");
+    /// println!("yes bid {}", yes_bid.to_display());
+    /// ```
+    ///
+    pub fn to_cents(&self, price: i64) -> Option<Cents> {
+        match self {
+            PriceUnits::UsdCent => Some(Cents(price)),
+            PriceUnits::Unknown(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for PriceUnits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PriceUnits::UsdCent => write!(f, "usd_cent"),
+            PriceUnits::Unknown(units) => write!(f, "{}", units),
+        }
+    }
+}
+
+impl From<&str> for PriceUnits {
+    fn from(units: &str) -> Self {
+        match units {
+            "usd_cent" => PriceUnits::UsdCent,
+            other => PriceUnits::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl Serialize for PriceUnits {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PriceUnits {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let units = String::deserialize(deserializer)?;
+        Ok(PriceUnits::from(units.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MarketStatus;
+
+    #[test]
+    fn test_market_status_falls_back_to_unknown() {
+        let active: MarketStatus = serde_json::from_str("\"active\"").unwrap();
+        assert_eq!(active, MarketStatus::Active);
+
+        let unknown: MarketStatus = serde_json::from_str("\"paused\"").unwrap();
+        assert_eq!(unknown, MarketStatus::Unknown("paused".to_string()));
+        assert_eq!(serde_json::to_string(&unknown).unwrap(), "\"paused\"");
+    }
+
+    #[test]
+    fn test_category_matches_known_names_ignoring_case() {
+        use super::Category;
+
+        let categories: Vec<Category> =
+            serde_json::from_str(r#"["Economics","climate and weather","Esports"]"#).unwrap();
+        assert_eq!(
+            categories,
+            vec![
+                Category::Economics,
+                Category::ClimateAndWeather,
+                Category::Other("Esports".to_string())
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&categories).unwrap(),
+            r#"["Economics","Climate and Weather","Esports"]"#
+        );
+    }
+
+    #[test]
+    fn test_price_units_convert_only_known_units() {
+        use super::PriceUnits;
+        use crate::price::Cents;
+
+        let cents: PriceUnits = serde_json::from_str("\"usd_cent\"").unwrap();
+        assert_eq!(cents.to_cents(42), Some(Cents(42)));
+
+        let unknown: PriceUnits = serde_json::from_str("\"usd_centicent\"").unwrap();
+        assert_eq!(unknown.to_cents(4200), None);
+        assert_eq!(
+            serde_json::to_string(&unknown).unwrap(),
+            "\"usd_centicent\""
+        );
+    }
+
+    #[test]
+    fn test_orderbook_diff_reports_level_changes() {
+        use super::{LevelChange, Orderbook, OrderbookLevel};
+        use crate::portfolio::Side;
+
+        let before: Orderbook =
+            serde_json::from_str(r#"{"yes":[[40,10],[42,5]],"no":[[55,3]]}"#).unwrap();
+        let after: Orderbook =
+            serde_json::from_str(r#"{"yes":[[41,2],[42,8]],"no":[[55,3]]}"#).unwrap();
+
+        let changes = before.diff(&after);
+        assert_eq!(
+            changes,
+            vec![
+                LevelChange::Removed {
+                    side: Side::Yes,
+                    level: OrderbookLevel {
+                        price: 40,
+                        quantity: 10
+                    },
+                },
+                LevelChange::Added {
+                    side: Side::Yes,
+                    level: OrderbookLevel {
+                        price: 41,
+                        quantity: 2
+                    },
+                },
+                LevelChange::Changed {
+                    side: Side::Yes,
+                    price: 42,
+                    before: 5,
+                    after: 8,
+                },
+            ]
+        );
+        assert_eq!(
+            changes.iter().map(LevelChange::quantity_delta).sum::<i64>(),
+            -5
+        );
+        assert!(after.diff(&after).is_empty());
+    }
+
+    #[test]
+    fn test_estimate_fill_walks_opposite_side() {
+        use super::Orderbook;
+        use crate::portfolio::{Action, Side};
+        use crate::price::Cents;
+
+        let book: Orderbook =
+            serde_json::from_str(r#"{"yes":[[40,10],[42,5]],"no":[[55,3],[56,4]]}"#).unwrap();
+
+        // Buying 'Yes' takes the 'No' bids: 4 at 44 cents, then 3 at 45 cents.
+        let buy = book.estimate_fill(Side::Yes, Action::Buy, 10);
+        assert_eq!((buy.filled, buy.unfilled), (7, 3));
+        assert_eq!(buy.total_cost, Cents(4 * 44 + 3 * 45));
+        assert_eq!(buy.worst_price, Some(Cents(45)));
+
+        let sell = book.estimate_fill(Side::Yes, Action::Sell, 8);
+        assert_eq!((sell.filled, sell.unfilled), (8, 0));
+        assert_eq!(sell.total_cost, Cents(5 * 42 + 3 * 40));
+        assert_eq!(sell.average_price(), Some(41.25));
+    }
+
+    #[test]
+    fn test_imbalance_counts_best_levels_of_each_side() {
+        use super::Orderbook;
+
+        let book: Orderbook =
+            serde_json::from_str(r#"{"yes":[[38,20],[40,10],[42,5]],"no":[[55,3],[56,4]]}"#)
+                .unwrap();
+
+        assert_eq!(book.imbalance(1), Some(5.0 / 4.0));
+        assert_eq!(book.imbalance(2), Some(15.0 / 7.0));
+        assert_eq!(book.imbalance(10), Some(35.0 / 7.0));
+        assert_eq!(book.imbalance(0), None);
+    }
+
+    #[test]
+    fn test_single_event_merges_sibling_markets() {
+        let market = super::Market {
+            early_close_condition: Some("On resolution".to_string()),
+            ..crate::fixtures::market("EV-A")
+        };
+        let response = format!(
+            r#"{{"event":{{"event_ticker":"EV","series_ticker":"S","sub_title":"","title":"Event",
+                "mutually_exclusive":true,"category":"Economics","markets":null}},"markets":[{}]}}"#,
+            serde_json::to_string(&market).unwrap()
+        );
+
+        let event = serde_json::from_str::<super::SingleEventResponse>(&response)
+            .unwrap()
+            .into_event();
+        let markets = event.markets.unwrap();
+        assert_eq!(markets.len(), 1);
+        assert_eq!(markets[0].ticker, "EV-A");
+        assert_eq!(markets[0].category, None);
+        assert_eq!(
+            markets[0].early_close_condition.as_deref(),
+            Some("On resolution")
+        );
+    }
+
+    #[test]
+    fn test_snapshot_mid_and_spread() {
+        let snapshot: super::Snapshot = serde_json::from_str(
+            r#"{"yes_price":45,"yes_bid":44,"yes_ask":47,"no_bid":53,"no_ask":56,"volume":10,"open_interest":4,"ts":1700000000}"#,
+        )
+        .unwrap();
+
+        assert_eq!(snapshot.mid(), 45.5);
+        assert_eq!(snapshot.spread(), Some(crate::Cents(3)));
+    }
+
+    #[test]
+    fn test_strike_combines_type_and_bounds() {
+        use super::Strike;
+
+        assert_eq!(
+            Strike::from_parts(Some("between"), Some(10.0), Some(20.0), None),
+            Some(Strike::Between(10.0, 20.0))
+        );
+        assert_eq!(
+            Strike::from_parts(Some("less_or_equal"), None, Some(5.5), None),
+            Some(Strike::CapInclusive(5.5))
+        );
+        assert_eq!(
+            Strike::from_parts(Some("greater"), None, Some(5.5), None),
+            None
+        );
+        assert_eq!(
+            Strike::from_parts(Some("custom"), None, None, None),
+            Some(Strike::Other("custom".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_orderbook_levels_are_sorted_best_first() {
+        let orderbook: super::Orderbook =
+            serde_json::from_str(r#"{"yes":[[1,200],[42,10],[30,5]],"no":[[55,4294967296]]}"#)
+                .unwrap();
+
+        assert_eq!(orderbook.best_yes_bid().map(|level| level.price), Some(42));
+        assert_eq!(orderbook.no[0].quantity, 4_294_967_296);
+        assert_eq!(
+            orderbook.raw_yes(),
+            Some(vec![vec![1, 200], vec![30, 5], vec![42, 10]])
+        );
+        assert_eq!(orderbook.raw_no(), Some(vec![vec![55, i32::MAX]]));
+        let empty: super::Orderbook = serde_json::from_str(r#"{"yes":null,"no":null}"#).unwrap();
+        assert_eq!(empty.raw_yes(), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_trade_created_time_parses_as_datetime() {
+        let trade: super::Trade = serde_json::from_str(
+            r#"{
+                "trade_id": "c3a5b1e2",
+                "taker_side": "yes",
+                "ticker": "HIGHNY-23NOV13-T51",
+                "count": 5,
+                "yes_price": 44,
+                "no_price": 56,
+                "created_time": "2023-11-13T15:04:05.123Z"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(trade.created_time.timestamp(), 1699887845);
+    }
 }
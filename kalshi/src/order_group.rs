@@ -0,0 +1,212 @@
+use super::{Authenticated, Environment, Kalshi};
+use crate::kalshi_error::*;
+
+use serde::{Deserialize, Serialize};
+
+impl<E: Environment> Kalshi<Authenticated, E> {
+    /// Creates an order group on the Kalshi exchange.
+    ///
+    /// An order group caps how many contracts its orders may fill in total. Once the limit is hit,
+    /// the exchange cancels every resting order in the group. Orders join a group through the
+    /// `order_group_id` field of [`OrderCreationField`](crate::OrderCreationField).
+    ///
+    /// # Arguments
+    ///
+    /// * `contracts_limit` - The number of contracts the group's orders may fill before they are canceled.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(String)`: The id of the new order group on success.
+    /// - `Err(KalshiError)`: An error if the limit is not positive or if there is an issue with the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let group_id = kalshi_instance.create_order_group(100).await.unwrap();
+    /// ```
+    ///
+    pub async fn create_order_group(&self, contracts_limit: i32) -> Result<String, KalshiError> {
+        self.ensure_writable("create_order_group")?;
+
+        if contracts_limit <= 0 {
+            return Err(KalshiError::UserInputError(
+                "Order group contracts limit must be positive".to_string(),
+            ));
+        }
+
+        let order_group_url: &str = &format!("{}/portfolio/order_groups/create", self.base_url());
+        let payload = CreateOrderGroupPayload { contracts_limit };
+
+        let result: CreateOrderGroupResponse = self
+            .send_authenticated(
+                self.order_client
+                    .post(order_group_url)
+                    .header("content-type", "application/json".to_string())
+                    .body(self.json_body(&payload)?),
+            )
+            .await?
+            .json()
+            .await?;
+
+        Ok(result.order_group_id)
+    }
+
+    /// Retrieves all order groups of the logged-in user.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<OrderGroup>)`: The user's order groups on success.
+    /// - `Err(KalshiError)`: An error if there is an issue with the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let groups = kalshi_instance.get_order_groups().await.unwrap();
+    /// ```
+    ///
+    pub async fn get_order_groups(&self) -> Result<Vec<OrderGroup>, KalshiError> {
+        let order_groups_url: &str = &format!("{}/portfolio/order_groups", self.base_url());
+
+        let result: OrderGroupsResponse = self
+            .send_authenticated(self.client.get(order_groups_url))
+            .await?
+            .json()
+            .await?;
+
+        Ok(result.order_groups)
+    }
+
+    /// Retrieves a single order group and the ids of the orders in it.
+    ///
+    /// # Arguments
+    ///
+    /// * `order_group_id` - The id of the order group.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(OrderGroupDetail)`: The order group on success.
+    /// - `Err(KalshiError)`: An error if there is an issue with the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let group = kalshi_instance.get_order_group(&group_id).await.unwrap();
+    /// ```
+    ///
+    pub async fn get_order_group(
+        &self,
+        order_group_id: &str,
+    ) -> Result<OrderGroupDetail, KalshiError> {
+        let order_group_url: &str = &format!(
+            "{}/portfolio/order_groups/{}",
+            self.base_url(),
+            order_group_id
+        );
+
+        let result: OrderGroupDetail = self
+            .send_authenticated(self.client.get(order_group_url))
+            .await?
+            .json()
+            .await?;
+
+        Ok(result)
+    }
+
+    /// Deletes an order group, canceling every resting order in it.
+    ///
+    /// # Arguments
+    ///
+    /// * `order_group_id` - The id of the order group to delete.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())`: On success.
+    /// - `Err(KalshiError)`: An error if there is an issue with the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// kalshi_instance.delete_order_group(&group_id).await.unwrap();
+    /// ```
+    ///
+    pub async fn delete_order_group(&self, order_group_id: &str) -> Result<(), KalshiError> {
+        self.ensure_writable("delete_order_group")?;
+
+        let order_group_url: &str = &format!(
+            "{}/portfolio/order_groups/{}",
+            self.base_url(),
+            order_group_id
+        );
+
+        self.send_authenticated(self.order_client.delete(order_group_url))
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+}
+
+// PRIVATE STRUCTS
+// -----------------------------------------------
+
+#[derive(Debug, Serialize)]
+struct CreateOrderGroupPayload {
+    contracts_limit: i32,
+}
+
+#[derive(Debug, Deserialize)]
+struct CreateOrderGroupResponse {
+    order_group_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OrderGroupsResponse {
+    #[serde(default)]
+    order_groups: Vec<OrderGroup>,
+}
+
+// PUBLIC STRUCTS
+// -----------------------------------------------
+
+/// An order group of the logged-in user, as listed by [`Kalshi::get_order_groups`].
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct OrderGroup {
+    /// The order group's id.
+    pub id: String,
+    /// Whether the group cancels its orders once its contracts limit is hit.
+    pub is_auto_cancel_enabled: bool,
+}
+
+/// A single order group, as returned by [`Kalshi::get_order_group`].
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct OrderGroupDetail {
+    /// Whether the group cancels its orders once its contracts limit is hit.
+    pub is_auto_cancel_enabled: bool,
+    /// Ids of the orders in the group.
+    #[serde(default)]
+    pub orders: Vec<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_order_group_responses_deserialize() {
+        let groups: OrderGroupsResponse = serde_json::from_str(
+            r#"{"order_groups":[{"id":"og-1","is_auto_cancel_enabled":true},{"id":"og-2","is_auto_cancel_enabled":false}]}"#,
+        )
+        .unwrap();
+        assert_eq!(groups.order_groups.len(), 2);
+        assert_eq!(groups.order_groups[0].id, "og-1");
+        assert!(!groups.order_groups[1].is_auto_cancel_enabled);
+
+        let detail: OrderGroupDetail =
+            serde_json::from_str(r#"{"is_auto_cancel_enabled":true,"orders":["a","b"]}"#).unwrap();
+        assert_eq!(detail.orders, vec!["a".to_string(), "b".to_string()]);
+    }
+}
@@ -0,0 +1,276 @@
+use crate::market::{Market, SettlementResult};
+use crate::portfolio::{MarketPosition, Side};
+use crate::price::Cents;
+use std::collections::HashMap;
+
+/// The amount, in cents, a winning contract pays out at settlement.
+pub const CONTRACT_PAYOUT_CENTS: i64 = 100;
+
+/// Calculates the gross amount paid out to a position when its market settles with `result`, in cents.
+///
+/// Every winning contract pays out one dollar, losing contracts pay nothing. A voided market
+/// refunds the cost of the position. Saturates at the bounds of `i64` instead of overflowing.
+///
+/// # Arguments
+///
+/// * `result` - The hypothetical settlement result of the market.
+/// * `position` - The position held in the market.
+///
+/// # Example
+///
+/// ```
+/// // Assuming `position` holds 10 'Yes' contracts
+/// assert_eq!(settlement_payout(&SettlementResult::Yes, &position), 1000);
+/// ```
+pub fn settlement_payout(result: &SettlementResult, position: &MarketPosition) -> i64 {
+    match result {
+        SettlementResult::Yes | SettlementResult::AllYes => {
+            (position.net_yes_contracts() as i64).saturating_mul(CONTRACT_PAYOUT_CENTS)
+        }
+        SettlementResult::No | SettlementResult::AllNo => {
+            (position.net_no_contracts() as i64).saturating_mul(CONTRACT_PAYOUT_CENTS)
+        }
+        SettlementResult::Void => position.market_exposure,
+    }
+}
+
+/// Calculates the profit or loss of a position if its market settles with `result`, in cents.
+///
+/// This is the settlement payout minus the cost of the position and the fees paid in the market.
+/// Negative values are losses. Saturates at the bounds of `i64` instead of overflowing.
+///
+/// # Arguments
+///
+/// * `result` - The hypothetical settlement result of the market.
+/// * `position` - The position held in the market.
+///
+/// # Example
+///
+/// ```
+/// let pnl_if_no = payout_if(&SettlementResult::No, &position);
+/// ```
+pub fn payout_if(result: &SettlementResult, position: &MarketPosition) -> i64 {
+    settlement_payout(result, position)
+        .saturating_sub(position.market_exposure)
+        .saturating_sub(position.fees_paid)
+}
+
+/// Calculates the largest amount a position can lose at settlement, in cents.
+///
+/// Considers both the 'Yes' and 'No' outcomes, including fees paid. Returns zero if
+/// the position cannot lose money.
+///
+/// # Arguments
+///
+/// * `position` - The position held in the market.
+///
+/// # Example
+///
+/// ```
+/// let worst_case = max_loss(&position);
+/// ```
+pub fn max_loss(position: &MarketPosition) -> i64 {
+    let worst =
+        payout_if(&SettlementResult::Yes, position).min(payout_if(&SettlementResult::No, position));
+
+    worst.saturating_neg().max(0)
+}
+
+/// The price an open position is valued at before its market settles.
+///
+/// Last trade prices are easily stale or moved by a single small print in thin books,
+/// so unrealized profit and equity can be marked with a quote-based rule instead.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MarkRule {
+    /// The last traded price of the market.
+    #[default]
+    LastTrade,
+    /// The midpoint between the best bid and ask of the side held.
+    Mid,
+    /// The best bid of the side held, i.e. what the position could be sold for right now.
+    /// A side without bids is marked at zero.
+    ConservativeBid,
+}
+
+impl MarkRule {
+    /// Returns the price one contract on `side` of `market` is marked at.
+    ///
+    /// A [`Mid`](MarkRule::Mid) between two prices an odd number of cents apart is rounded down to a whole cent.
+    /// Returns `None` if the rule can't price the market: [`LastTrade`](MarkRule::LastTrade)
+    /// before the market's first trade, or [`Mid`](MarkRule::Mid) when either side of the book is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `market` - The market the position is held in, e.g. from [`Kalshi::get_single_market`](crate::Kalshi::get_single_market).
+    /// * `side` - The side the position is held on.
+    ///
+    pub fn mark_price(&self, market: &Market, side: Side) -> Option<Cents> {
+        let (bid, ask) = match side {
+            Side::Yes => (market.yes_bid, market.yes_ask),
+            Side::No => (market.no_bid, market.no_ask),
+        };
+
+        match self {
+            MarkRule::LastTrade if market.last_price > 0 => match side {
+                Side::Yes => Some(Cents(market.last_price)),
+                Side::No => CONTRACT_PAYOUT_CENTS
+                    .checked_sub(market.last_price)
+                    .map(Cents),
+            },
+            MarkRule::LastTrade => None,
+            // Halving each price first keeps the sum from overflowing.
+            MarkRule::Mid if bid > 0 && ask > 0 => {
+                Some(Cents(bid / 2 + ask / 2 + (bid % 2 + ask % 2) / 2))
+            }
+            MarkRule::Mid => None,
+            MarkRule::ConservativeBid => Some(Cents(bid.max(0))),
+        }
+    }
+}
+
+/// Calculates the value of a position marked at `rule`, in cents.
+///
+/// A flat position is worth zero. Returns `None` if the rule can't price the market or the value overflows.
+///
+/// # Arguments
+///
+/// * `position` - The position held in the market.
+/// * `market` - The market the position is held in.
+/// * `rule` - The marking rule to value the contracts with.
+///
+/// # Example
+///
+/// ```
+/// let value = marked_value(&position, &market, MarkRule::ConservativeBid);
+/// ```
+pub fn marked_value(position: &MarketPosition, market: &Market, rule: MarkRule) -> Option<i64> {
+    let side = match position.direction() {
+        Some(side) => side,
+        None => return Some(0),
+    };
+    let contracts = position.position.unsigned_abs() as i64;
+
+    rule.mark_price(market, side)?
+        .checked_mul_count(contracts)
+        .map(Cents::value)
+}
+
+/// Calculates the unrealized profit or loss of a position marked at `rule`, in cents.
+///
+/// This is the marked value minus the cost of the position and the fees paid in the market,
+/// matching [`payout_if`] once the market settles. Returns `None` if the rule can't price the market.
+///
+/// # Arguments
+///
+/// * `position` - The position held in the market.
+/// * `market` - The market the position is held in.
+/// * `rule` - The marking rule to value the contracts with.
+///
+/// # Example
+///
+/// ```
+/// let pnl = unrealized_pnl(&position, &market, MarkRule::Mid);
+/// ```
+pub fn unrealized_pnl(position: &MarketPosition, market: &Market, rule: MarkRule) -> Option<i64> {
+    marked_value(position, market, rule).map(|value| {
+        value
+            .saturating_sub(position.market_exposure)
+            .saturating_sub(position.fees_paid)
+    })
+}
+
+/// Calculates the equity of an account, in cents: its cash balance plus its positions marked at `rule`.
+///
+/// # Arguments
+///
+/// * `balance` - The cash balance of the account, e.g. from [`Kalshi::get_balance`](crate::Kalshi::get_balance).
+/// * `positions` - The positions held.
+/// * `markets` - The markets of the positions, by ticker.
+/// * `rule` - The marking rule to value the positions with.
+///
+/// # Returns
+///
+/// The marked equity, or `None` if an open position's market is missing from `markets`, can't be priced by the rule,
+/// or the equity overflows.
+///
+/// # Example
+///
+/// ```
+/// let equity = marked_equity(balance, &positions, &markets, MarkRule::ConservativeBid);
+/// ```
+pub fn marked_equity(
+    balance: i64,
+    positions: &[MarketPosition],
+    markets: &HashMap<String, Market>,
+    rule: MarkRule,
+) -> Option<i64> {
+    positions.iter().try_fold(balance, |equity, position| {
+        let value = match position.direction() {
+            Some(_) => marked_value(position, markets.get(&position.ticker)?, rule)?,
+            None => 0,
+        };
+        equity.checked_add(value)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::{market, position};
+
+    #[test]
+    fn test_payout_and_max_loss() {
+        // 10 'No' contracts bought at 40 cents with 7 cents of fees.
+        let no_position = MarketPosition {
+            fees_paid: 7,
+            ..position("TEST", -10, 400)
+        };
+
+        assert_eq!(payout_if(&SettlementResult::No, &no_position), 593);
+        assert_eq!(payout_if(&SettlementResult::Yes, &no_position), -407);
+        assert_eq!(payout_if(&SettlementResult::Void, &no_position), -7);
+        assert_eq!(max_loss(&no_position), 407);
+    }
+
+    #[test]
+    fn test_mark_rules_value_thin_book_differently() {
+        let market = Market {
+            yes_bid: 30,
+            yes_ask: 45,
+            no_bid: 55,
+            no_ask: 70,
+            last_price: 50,
+            ..market("TEST")
+        };
+        // 10 'Yes' contracts bought at 40 cents with 5 cents of fees.
+        let yes_position = MarketPosition {
+            fees_paid: 5,
+            ..position("TEST", 10, 400)
+        };
+
+        assert_eq!(
+            unrealized_pnl(&yes_position, &market, MarkRule::LastTrade),
+            Some(95)
+        );
+        assert_eq!(
+            unrealized_pnl(&yes_position, &market, MarkRule::Mid),
+            Some(-35)
+        );
+        assert_eq!(
+            unrealized_pnl(&yes_position, &market, MarkRule::ConservativeBid),
+            Some(-105)
+        );
+        assert_eq!(
+            MarkRule::LastTrade.mark_price(&market, Side::No),
+            Some(Cents(50))
+        );
+
+        let markets = HashMap::from([("TEST".to_string(), market)]);
+        let flat = position("OTHER", 0, 0);
+        assert_eq!(
+            marked_equity(1_000, &[yes_position, flat], &markets, MarkRule::Mid),
+            Some(1_370)
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use crate::portfolio::{Fill, Settlement, Side};
+use crate::price::Cents;
+
+/// How efficiently a period of trading used its capital, as computed by [`capital_metrics`].
+///
+/// Lets strategies be compared on efficiency rather than just profit: a strategy making the same profit
+/// with half the capital tied up can be given twice the allocation.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapitalMetrics {
+    /// Time-weighted average balance over the period, in cents.
+    pub average_capital: f64,
+    /// Total paid or received for contracts traded in the period.
+    pub traded_notional: Cents,
+    /// Traded notional divided by average capital, i.e. how many times the capital was traded over.
+    /// `None` if the average capital isn't positive.
+    pub turnover: Option<f64>,
+    /// Profit or loss of the positions settled in the period: their revenue minus their cost.
+    pub settled_pnl: Cents,
+    /// Settled profit or loss divided by average capital. `None` if the average capital isn't positive.
+    pub return_on_capital: Option<f64>,
+}
+
+/// Computes the average capital, turnover and return on capital of a period of trading.
+///
+/// The exchange only reports the current balance, so the balance history is whatever the caller
+/// sampled, e.g. by calling [`Kalshi::get_balance`](crate::Kalshi::get_balance) on a schedule.
+/// The balance is assumed to hold between samples. The fills and settlements should cover the same
+/// period as the samples.
+///
+/// # Arguments
+///
+/// * `balances` - Balance samples as Unix timestamps in seconds with the balance at that time. They don't have to be sorted.
+/// * `fills` - The fills of the period.
+/// * `settlements` - The settlements of the period.
+///
+/// # Returns
+///
+/// The metrics of the period, or `None` if there are no balance samples.
+///
+/// # Example
+///
+/// ```
+/// if let Some(metrics) = capital_metrics(&balances, &fills, &settlements) {
+///     println!("turnover {:?}, return on capital {:?}", metrics.turnover, metrics.return_on_capital);
+/// }
+/// ```
+///
+pub fn capital_metrics(
+    balances: &[(i64, Cents)],
+    fills: &[Fill],
+    settlements: &[Settlement],
+) -> Option<CapitalMetrics> {
+    let mut sorted = balances.to_vec();
+    sorted.sort_by_key(|(ts, _)| *ts);
+
+    let (first_ts, first_balance) = *sorted.first()?;
+    let (last_ts, _) = *sorted.last()?;
+
+    let average_capital = if last_ts > first_ts {
+        let weighted: f64 = sorted
+            .windows(2)
+            .map(|pair| pair[0].1.value() as f64 * (pair[1].0 - pair[0].0) as f64)
+            .sum();
+        weighted / (last_ts - first_ts) as f64
+    } else {
+        first_balance.value() as f64
+    };
+
+    let traded_notional = fills.iter().fold(Cents::ZERO, |total, fill| {
+        let price = match fill.side {
+            Side::Yes => fill.yes_price,
+            Side::No => fill.no_price,
+        };
+        Cents(price.saturating_mul(fill.count as i64))
+            .checked_add(total)
+            .unwrap_or(Cents(i64::MAX))
+    });
+
+    let settled_pnl = settlements.iter().fold(Cents::ZERO, |total, settlement| {
+        Cents(
+            total
+                .value()
+                .saturating_add(settlement.revenue)
+                .saturating_sub(settlement.yes_total_cost)
+                .saturating_sub(settlement.no_total_cost),
+        )
+    });
+
+    let per_capital = |amount: Cents| {
+        if average_capital > 0.0 {
+            Some(amount.value() as f64 / average_capital)
+        } else {
+            None
+        }
+    };
+
+    Some(CapitalMetrics {
+        average_capital,
+        traded_notional,
+        turnover: per_capital(traded_notional),
+        settled_pnl,
+        return_on_capital: per_capital(settled_pnl),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::capital_metrics;
+    use crate::portfolio::{Fill, Settlement};
+    use crate::price::Cents;
+
+    #[test]
+    fn test_capital_metrics_weight_balance_by_time() {
+        let fill: Fill = serde_json::from_str(
+            r#"{"action":"buy","count":100,"created_time":"2023-11-14T22:13:30Z","is_taker":true,"no_price":60,
+                "order_id":"o","side":"no","ticker":"EV-A","trade_id":"t","yes_price":40}"#,
+        )
+        .unwrap();
+        let settlement: Settlement = serde_json::from_str(
+            r#"{"market_result":"no","no_count":100,"no_total_cost":6000,"revenue":10000,
+                "settled_time":"2023-11-15T00:00:00Z","ticker":"EV-A","yes_count":0,"yes_total_cost":0}"#,
+        )
+        .unwrap();
+        // 10,000 cents for 3 hours, then 40,000 cents for 1 hour.
+        let balances = [
+            (10_800, Cents(40_000)),
+            (0, Cents(10_000)),
+            (14_400, Cents(44_000)),
+        ];
+
+        let metrics = capital_metrics(&balances, &[fill], &[settlement]).unwrap();
+
+        assert_eq!(metrics.average_capital, 17_500.0);
+        assert_eq!(metrics.traded_notional, Cents(6_000));
+        assert_eq!(metrics.settled_pnl, Cents(4_000));
+        assert_eq!(metrics.turnover, Some(6_000.0 / 17_500.0));
+        assert_eq!(metrics.return_on_capital, Some(4_000.0 / 17_500.0));
+        assert!(capital_metrics(&[], &[], &[]).is_none());
+    }
+}
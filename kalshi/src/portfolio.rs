@@ -1,17 +1,27 @@
-use super::Kalshi;
+use super::{Authenticated, Environment, Kalshi};
+use crate::cursor::Cursor;
 use crate::kalshi_error::*;
+use crate::utils;
+use crate::Timestamp;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
-use std::sync::Arc;
-use tokio::task;
 use uuid::Uuid;
 
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{Deserialize, Serialize};
 
-impl<'a> Kalshi {
+/// Number of orders or positions requested per page when listing all of them.
+const LISTING_PAGE_SIZE: i64 = 200;
+
+/// Number of orders the batched order endpoint accepts in one request.
+const BATCH_CREATE_ORDERS_PER_REQUEST: usize = 20;
+
+/// Number of order ids the batched cancellation endpoint accepts in one request.
+const BATCH_CANCEL_ORDERS_PER_REQUEST: usize = 20;
+
+impl<'a, E: Environment> Kalshi<Authenticated, E> {
     /// Retrieves the current balance of the authenticated user from the Kalshi exchange.
     ///
     /// This method fetches the user's balance, requiring a valid authentication token.
-    /// If the user is not logged in or the token is missing, it returns an error.
     ///
     /// # Returns
     ///
@@ -26,20 +36,10 @@ impl<'a> Kalshi {
     /// ```
     ///
     pub async fn get_balance(&self) -> Result<i64, KalshiError> {
-        if self.curr_token == None {
-            return Err(KalshiError::UserInputError(
-                "Not logged in, a valid token is required for requests that require authentication"
-                    .to_string(),
-            ));
-        }
-
-        let balance_url: &str = &format!("{}/portfolio/balance", self.base_url.to_string());
+        let balance_url: &str = &format!("{}/portfolio/balance", self.base_url());
 
         let result: BalanceResponse = self
-            .client
-            .get(balance_url)
-            .header("Authorization", self.curr_token.clone().unwrap())
-            .send()
+            .send_authenticated(self.client.get(balance_url))
             .await?
             .json()
             .await?;
@@ -51,7 +51,6 @@ impl<'a> Kalshi {
     ///
     /// This method fetches multiple orders, allowing for filtering by ticker, event ticker, time range,
     /// status, and pagination. A valid authentication token is required to access this information.
-    /// If the user is not logged in or the token is missing, it returns an error.
     ///
     /// # Arguments
     ///
@@ -61,11 +60,11 @@ impl<'a> Kalshi {
     /// * `max_ts` - An optional maximum timestamp for order creation time.
     /// * `status` - An optional string to filter orders by their status.
     /// * `limit` - An optional integer to limit the number of orders returned.
-    /// * `cursor` - An optional string for pagination cursor.
+    /// * `cursor` - An optional cursor returned with the previous page.
     ///
     /// # Returns
     ///
-    /// - `Ok((Option<String>, Vec<Order>))`: A tuple containing an optional pagination cursor
+    /// - `Ok((Cursor, Vec<Order>))`: A tuple containing the pagination cursor of the next page
     ///   and a vector of `Order` objects on successful retrieval.
     /// - `Err(KalshiError)`: An error if the user is not authenticated or if there is an issue with the request.
     ///
@@ -86,37 +85,21 @@ impl<'a> Kalshi {
         max_ts: Option<i64>,
         status: Option<String>,
         limit: Option<i32>,
-        cursor: Option<String>,
-    ) -> Result<(Option<String>, Vec<Order>), KalshiError> {
-        if self.curr_token == None {
-            return Err(KalshiError::UserInputError(
-                "Not logged in, a valid token is required for requests that require authentication"
-                    .to_string(),
-            ));
-        }
-        let user_orders_url: &str = &format!("{}/portfolio/orders", self.base_url.to_string());
-
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(7);
-
-        add_param!(params, "ticker", ticker);
-        add_param!(params, "limit", limit);
-        add_param!(params, "cursor", cursor);
-        add_param!(params, "min_ts", min_ts);
-        add_param!(params, "max_ts", max_ts);
-        add_param!(params, "event_ticker", event_ticker);
-        add_param!(params, "status", status);
-
-        let user_orders_url = reqwest::Url::parse_with_params(user_orders_url, &params)
-            .unwrap_or_else(|err| {
-                eprintln!("{:?}", err);
-                panic!("Internal Parse Error, please contact developer!");
-            });
+        cursor: Option<Cursor>,
+    ) -> Result<(Cursor, Vec<Order>), KalshiError> {
+        let mut url = self.url_builder(format_args!("/portfolio/orders"));
+        url.param("ticker", ticker);
+        url.param("limit", limit);
+        url.param("cursor", cursor);
+        url.param("min_ts", min_ts);
+        url.param("max_ts", max_ts);
+        url.param("event_ticker", event_ticker);
+        url.param("status", status);
+
+        let user_orders_url = url.finish()?;
 
         let result: MultipleOrderResponse = self
-            .client
-            .get(user_orders_url)
-            .header("Authorization", self.curr_token.clone().unwrap())
-            .send()
+            .send_authenticated(self.client.get(user_orders_url))
             .await?
             .json()
             .await?;
@@ -127,7 +110,7 @@ impl<'a> Kalshi {
     /// Retrieves detailed information about a specific order from the Kalshi exchange.
     ///
     /// This method fetches data for a single order identified by its order ID. A valid authentication token
-    /// is required to access this information. If the user is not logged in or the token is missing, it returns an error.
+    /// is required to access this information.
     ///
     /// # Arguments
     ///
@@ -147,23 +130,10 @@ impl<'a> Kalshi {
     /// ```
     ///
     pub async fn get_single_order(&self, order_id: &String) -> Result<Order, KalshiError> {
-        if self.curr_token == None {
-            return Err(KalshiError::UserInputError(
-                "Not logged in, a valid token is required for requests that require authentication"
-                    .to_string(),
-            ));
-        }
-        let user_order_url: &str = &format!(
-            "{}/portfolio/orders/{}",
-            self.base_url.to_string(),
-            order_id
-        );
+        let user_order_url: &str = &format!("{}/portfolio/orders/{}", self.base_url(), order_id);
 
         let result: SingleOrderResponse = self
-            .client
-            .get(user_order_url)
-            .header("Authorization", self.curr_token.clone().unwrap())
-            .send()
+            .send_authenticated(self.client.get(user_order_url))
             .await?
             .json()
             .await?;
@@ -174,8 +144,7 @@ impl<'a> Kalshi {
     /// Cancels an existing order on the Kalshi exchange.
     ///
     /// This method cancels an order specified by its ID. A valid authentication token is
-    /// required to perform this action. If the user is not logged in or the token is missing,
-    /// it returns an error.
+    /// required to perform this action.
     ///
     /// # Arguments
     ///
@@ -196,23 +165,12 @@ impl<'a> Kalshi {
     /// ```
     ///
     pub async fn cancel_order(&self, order_id: &str) -> Result<(Order, i32), KalshiError> {
-        if self.curr_token == None {
-            return Err(KalshiError::UserInputError(
-                "Not logged in, a valid token is required for requests that require authentication"
-                    .to_string(),
-            ));
-        }
-        let cancel_order_url: &str = &format!(
-            "{}/portfolio/orders/{}",
-            self.base_url.to_string(),
-            order_id
-        );
+        self.ensure_writable("cancel_order")?;
+
+        let cancel_order_url: &str = &format!("{}/portfolio/orders/{}", self.base_url(), order_id);
 
         let result: DeleteOrderResponse = self
-            .client
-            .delete(cancel_order_url)
-            .header("Authorization", self.curr_token.clone().unwrap())
-            .send()
+            .send_authenticated(self.order_client.delete(cancel_order_url))
             .await?
             .json()
             .await?;
@@ -252,17 +210,10 @@ impl<'a> Kalshi {
         reduce_by: Option<i32>,
         reduce_to: Option<i32>,
     ) -> Result<Order, KalshiError> {
-        if self.curr_token == None {
-            return Err(KalshiError::UserInputError(
-                "Not logged in, a valid token is required for requests that require authentication"
-                    .to_string(),
-            ));
-        }
-        let decrease_order_url: &str = &format!(
-            "{}/portfolio/orders/{}",
-            self.base_url.to_string(),
-            order_id
-        );
+        self.ensure_writable("decrease_order")?;
+
+        let decrease_order_url: &str =
+            &format!("{}/portfolio/orders/{}", self.base_url(), order_id);
 
         match (reduce_by, reduce_to) {
             (Some(_), Some(_)) => {
@@ -286,12 +237,12 @@ impl<'a> Kalshi {
         };
 
         let result: SingleOrderResponse = self
-            .client
-            .post(decrease_order_url)
-            .header("Authorization", self.curr_token.clone().unwrap())
-            .header("content-type", "application/json".to_string())
-            .json(&decrease_payload)
-            .send()
+            .send_authenticated(
+                self.order_client
+                    .post(decrease_order_url)
+                    .header("content-type", "application/json".to_string())
+                    .body(self.json_body(&decrease_payload)?),
+            )
             .await?
             .json()
             .await?;
@@ -303,7 +254,6 @@ impl<'a> Kalshi {
     ///
     /// This method fetches multiple fills, allowing for filtering by ticker, order ID, time range,
     /// and pagination. A valid authentication token is required to access this information.
-    /// If the user is not logged in or the token is missing, it returns an error.
     ///
     /// # Arguments
     ///
@@ -312,11 +262,11 @@ impl<'a> Kalshi {
     /// * `min_ts` - An optional minimum timestamp for fill creation time.
     /// * `max_ts` - An optional maximum timestamp for fill creation time.
     /// * `limit` - An optional integer to limit the number of fills returned.
-    /// * `cursor` - An optional string for pagination cursor.
+    /// * `cursor` - An optional cursor returned with the previous page.
     ///
     /// # Returns
     ///
-    /// - `Ok((Option<String>, Vec<Fill>))`: A tuple containing an optional pagination cursor
+    /// - `Ok((Cursor, Vec<Fill>))`: A tuple containing the pagination cursor of the next page
     ///   and a vector of `Fill` objects on successful retrieval.
     /// - `Err(KalshiError)`: An error if the user is not authenticated or if there is an issue with the request.
     ///
@@ -336,36 +286,20 @@ impl<'a> Kalshi {
         min_ts: Option<i64>,
         max_ts: Option<i64>,
         limit: Option<i32>,
-        cursor: Option<String>,
-    ) -> Result<(Option<String>, Vec<Fill>), KalshiError> {
-        if self.curr_token == None {
-            return Err(KalshiError::UserInputError(
-                "Not logged in, a valid token is required for requests that require authentication"
-                    .to_string(),
-            ));
-        }
-        let user_fills_url: &str = &format!("{}/portfolio/fills", self.base_url.to_string());
-
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(7);
-
-        add_param!(params, "ticker", ticker);
-        add_param!(params, "limit", limit);
-        add_param!(params, "cursor", cursor);
-        add_param!(params, "min_ts", min_ts);
-        add_param!(params, "max_ts", max_ts);
-        add_param!(params, "order_id", order_id);
-
-        let user_fills_url = reqwest::Url::parse_with_params(user_fills_url, &params)
-            .unwrap_or_else(|err| {
-                eprintln!("{:?}", err);
-                panic!("Internal Parse Error, please contact developer!");
-            });
+        cursor: Option<Cursor>,
+    ) -> Result<(Cursor, Vec<Fill>), KalshiError> {
+        let mut url = self.url_builder(format_args!("/portfolio/fills"));
+        url.param("ticker", ticker);
+        url.param("limit", limit);
+        url.param("cursor", cursor);
+        url.param("min_ts", min_ts);
+        url.param("max_ts", max_ts);
+        url.param("order_id", order_id);
+
+        let user_fills_url = url.finish()?;
 
         let result: MultipleFillsResponse = self
-            .client
-            .get(user_fills_url)
-            .header("Authorization", self.curr_token.clone().unwrap())
-            .send()
+            .send_authenticated(self.client.get(user_fills_url))
             .await?
             .json()
             .await?;
@@ -377,16 +311,15 @@ impl<'a> Kalshi {
     ///
     /// This method fetches settlements in the user's portfolio, with options for pagination using limit and cursor.
     /// A valid authentication token is required to access this information.
-    /// If the user is not logged in or the token is missing, it returns an error.
     ///
     /// # Arguments
     ///
     /// * `limit` - An optional integer to limit the number of settlements returned.
-    /// * `cursor` - An optional string for pagination cursor.
+    /// * `cursor` - An optional cursor returned with the previous page.
     ///
     /// # Returns
     ///
-    /// - `Ok((Option<String>, Vec<Settlement>))`: A tuple containing an optional pagination cursor
+    /// - `Ok((Cursor, Vec<Settlement>))`: A tuple containing the pagination cursor of the next page
     ///   and a vector of `Settlement` objects on successful retrieval.
     /// - `Err(KalshiError)`: An error if the user is not authenticated or if there is an issue with the request.
     ///
@@ -400,32 +333,16 @@ impl<'a> Kalshi {
     pub async fn get_portfolio_settlements(
         &self,
         limit: Option<i64>,
-        cursor: Option<String>,
-    ) -> Result<(Option<String>, Vec<Settlement>), KalshiError> {
-        if self.curr_token == None {
-            return Err(KalshiError::UserInputError(
-                "Not logged in, a valid token is required for requests that require authentication"
-                    .to_string(),
-            ));
-        }
-        let settlements_url: &str = &format!("{}/portfolio/settlements", self.base_url.to_string());
-
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(6);
-
-        add_param!(params, "limit", limit);
-        add_param!(params, "cursor", cursor);
+        cursor: Option<Cursor>,
+    ) -> Result<(Cursor, Vec<Settlement>), KalshiError> {
+        let mut url = self.url_builder(format_args!("/portfolio/settlements"));
+        url.param("limit", limit);
+        url.param("cursor", cursor);
 
-        let settlements_url = reqwest::Url::parse_with_params(settlements_url, &params)
-            .unwrap_or_else(|err| {
-                eprintln!("{:?}", err);
-                panic!("Internal Parse Error, please contact developer!");
-            });
+        let settlements_url = url.finish()?;
 
         let result: PortfolioSettlementResponse = self
-            .client
-            .get(settlements_url)
-            .header("Authorization", self.curr_token.clone().unwrap())
-            .send()
+            .send_authenticated(self.client.get(settlements_url))
             .await?
             .json()
             .await?;
@@ -437,20 +354,19 @@ impl<'a> Kalshi {
     ///
     /// This method fetches the user's positions, providing options for filtering by settlement status,
     /// specific ticker, and event ticker, as well as pagination using limit and cursor. A valid
-    /// authentication token is required to access this information. If the user is not logged in
-    /// or the token is missing, it returns an error.
+    /// authentication token is required to access this information.
     ///
     /// # Arguments
     ///
     /// * `limit` - An optional integer to limit the number of positions returned.
-    /// * `cursor` - An optional string for pagination cursor.
+    /// * `cursor` - An optional cursor returned with the previous page.
     /// * `settlement_status` - An optional string to filter positions by their settlement status.
     /// * `ticker` - An optional string to filter positions by market ticker.
     /// * `event_ticker` - An optional string to filter positions by event ticker.
     ///
     /// # Returns
     ///
-    /// - `Ok((Option<String>, Vec<EventPosition>, Vec<MarketPosition>))`: A tuple containing an optional pagination cursor,
+    /// - `Ok((Cursor, Vec<EventPosition>, Vec<MarketPosition>))`: A tuple containing the pagination cursor of the next page,
     ///   a vector of `EventPosition` objects, and a vector of `MarketPosition` objects on successful retrieval.
     /// - `Err(KalshiError)`: An error if the user is not authenticated or if there is an issue with the request.
     ///
@@ -464,38 +380,22 @@ impl<'a> Kalshi {
     pub async fn get_user_positions(
         &self,
         limit: Option<i64>,
-        cursor: Option<String>,
+        cursor: Option<Cursor>,
         settlement_status: Option<String>,
         ticker: Option<String>,
         event_ticker: Option<String>,
-    ) -> Result<(Option<String>, Vec<EventPosition>, Vec<MarketPosition>), KalshiError> {
-        if self.curr_token == None {
-            return Err(KalshiError::UserInputError(
-                "Not logged in, a valid token is required for requests that require authentication"
-                    .to_string(),
-            ));
-        }
-        let positions_url: &str = &format!("{}/portfolio/positions", self.base_url.to_string());
-
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(6);
+    ) -> Result<(Cursor, Vec<EventPosition>, Vec<MarketPosition>), KalshiError> {
+        let mut url = self.url_builder(format_args!("/portfolio/positions"));
+        url.param("limit", limit);
+        url.param("cursor", cursor);
+        url.param("settlement_status", settlement_status);
+        url.param("ticker", ticker);
+        url.param("event_ticker", event_ticker);
 
-        add_param!(params, "limit", limit);
-        add_param!(params, "cursor", cursor);
-        add_param!(params, "settlement_status", settlement_status);
-        add_param!(params, "ticker", ticker);
-        add_param!(params, "event_ticker", event_ticker);
-
-        let positions_url =
-            reqwest::Url::parse_with_params(positions_url, &params).unwrap_or_else(|err| {
-                eprintln!("{:?}", err);
-                panic!("Internal Parse Error, please contact developer!");
-            });
+        let positions_url = url.finish()?;
 
         let result: GetPositionsResponse = self
-            .client
-            .get(positions_url)
-            .header("Authorization", self.curr_token.clone().unwrap())
-            .send()
+            .send_authenticated(self.client.get(positions_url))
             .await?
             .json()
             .await?;
@@ -555,7 +455,7 @@ impl<'a> Kalshi {
     /// ).await.unwrap();
     /// ```
     ///
-    
+
     // todo: rewrite using generics
     pub async fn create_order(
         &self,
@@ -571,59 +471,78 @@ impl<'a> Kalshi {
         sell_position_floor: Option<i32>,
         yes_price: Option<i64>,
     ) -> Result<Order, KalshiError> {
-        if self.curr_token == None {
-            return Err(KalshiError::UserInputError(
-                "Not logged in, a valid token is required for requests that require authentication"
-                    .to_string(),
-            ));
-        }
-        let order_url: &str = &format!("{}/portfolio/orders", self.base_url.to_string());
+        self.ensure_writable("create_order")?;
+
+        let order_url: &str = &format!("{}/portfolio/orders", self.base_url());
+
+        let order_payload = CreateOrderPayload::from_params((
+            action,
+            client_order_id,
+            count,
+            side,
+            ticker,
+            input_type,
+            buy_max_cost,
+            expiration_ts,
+            no_price,
+            sell_position_floor,
+            yes_price,
+        ))?;
+
+        self.post_order(order_url, &order_payload).await
+    }
 
-        match input_type {
-            OrderType::Limit => match (no_price, yes_price) {
-                (Some(_), Some(_)) => {
-                    return Err(KalshiError::UserInputError(
-                        "Can only provide no_price exclusive or yes_price, can't provide both"
-                            .to_string(),
-                    ));
-                }
-                (None, None) => {
-                    return Err(KalshiError::UserInputError(
-                            "Must provide either no_price exclusive or yes_price, can't provide neither"
-                                .to_string(),
-                        ));
-                }
-                _ => {}
-            },
-            _ => {}
-        }
+    /// Creates a single order described by an [`OrderCreationField`].
+    ///
+    /// Works like [`create_order`](Kalshi::create_order), and also places the order in the order group
+    /// named by `order_group_id`, if any, so it is canceled when the group's contracts limit is hit.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The order to create.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Order)`: The created order on success.
+    /// - `Err(KalshiError)`: An error if the order's prices are invalid or if there is an issue with the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let group_id = kalshi_instance.create_order_group(50).await?;
+    /// let order = kalshi_instance
+    ///     .create_order_from_field(OrderCreationField {
+    ///         order_group_id: Some(group_id),
+    ///         ..order
+    ///     })
+    ///     .await?;
+    /// ```
+    ///
+    pub async fn create_order_from_field(
+        &self,
+        order: OrderCreationField,
+    ) -> Result<Order, KalshiError> {
+        self.ensure_writable("create_order")?;
 
-        let unwrapped_id = match client_order_id {
-            Some(id) => id,
-            _ => String::from(Uuid::new_v4()),
-        };
+        let order_url: &str = &format!("{}/portfolio/orders", self.base_url());
+        let order_payload = CreateOrderPayload::from_field(order)?;
 
-        let order_payload = CreateOrderPayload {
-            action: action,
-            client_order_id: unwrapped_id,
-            count: count,
-            side: side,
-            ticker: ticker,
-            r#type: input_type,
-            buy_max_cost: buy_max_cost,
-            expiration_ts: expiration_ts,
-            no_price: no_price,
-            sell_position_floor: sell_position_floor,
-            yes_price: yes_price,
-        };
+        self.post_order(order_url, &order_payload).await
+    }
 
+    async fn post_order(
+        &self,
+        order_url: &str,
+        order_payload: &CreateOrderPayload,
+    ) -> Result<Order, KalshiError> {
         let response = self
-            .client
-            .post(order_url)
-            .header("Authorization", self.curr_token.clone().unwrap())
-            .header("content-type", "application/json".to_string())
-            .json(&order_payload)
-            .send()
+            .send_authenticated(
+                self.order_client
+                    .post(order_url)
+                    .header("content-type", "application/json".to_string())
+                    .body(self.json_body(order_payload)?),
+            )
             .await;
 
         match response {
@@ -646,6 +565,7 @@ impl<'a> Kalshi {
                     Err(KalshiError::InternalError(error_message))
                 }
             }
+            Err(KalshiError::AuthenticationError(e)) => Err(KalshiError::AuthenticationError(e)),
             Err(request_err) => {
                 // Handle errors in sending the request
                 let error_message = format!("Failed to send request: {}", request_err);
@@ -655,43 +575,434 @@ impl<'a> Kalshi {
         }
     }
 
+    /// Cancels several orders through the exchange's batched cancellation endpoint.
+    ///
+    /// Order ids are sent 20 per request. The batched endpoint requires advanced API access; if the
+    /// exchange refuses it, the remaining orders are canceled with concurrent
+    /// [`cancel_order`](Kalshi::cancel_order) calls instead. Every order succeeds or fails on its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch` - The ids of the orders to cancel.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(BatchOutcome<CanceledOrder>)`: The outcome of each cancellation, in the order of `batch`.
+    /// - `Err(KalshiError)`: A `ReadOnlyError` if the client is read-only.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let outcome = kalshi_instance.batch_cancel_order(order_ids).await?;
+    /// for canceled in outcome.successes() {
+    ///     println!("{} reduced by {}", canceled.order.order_id, canceled.reduced_by);
+    /// }
+    /// ```
+    ///
     pub async fn batch_cancel_order(
-        &mut self,
+        &self,
         batch: Vec<String>,
-    ) -> Result<Vec<Result<(Order, i32), KalshiError>>, KalshiError> {
-        let temp_instance = Arc::new(self.clone());
-        let mut futures = Vec::new();
+    ) -> Result<BatchOutcome<CanceledOrder>, KalshiError> {
+        self.ensure_writable("batch_cancel_order")?;
+
+        let batch_url: &str = &format!("{}/portfolio/orders/batched", self.base_url());
+
+        let mut results: Vec<Result<CanceledOrder, KalshiError>> = Vec::with_capacity(batch.len());
+        let mut remaining = batch.into_iter();
+        loop {
+            let ids: Vec<String> = remaining
+                .by_ref()
+                .take(BATCH_CANCEL_ORDERS_PER_REQUEST)
+                .collect();
+            if ids.is_empty() {
+                break;
+            }
+
+            let response = match self.send_batch_cancel_order(batch_url, &ids).await {
+                Ok(Some(response)) => response,
+                Ok(None) => {
+                    // No advanced access: cancel this chunk and the rest one order at a time.
+                    let rest = ids.into_iter().chain(remaining).collect();
+                    results.extend(self.cancel_orders_concurrently(rest).await);
+                    break;
+                }
+                Err(e) => {
+                    results.extend(
+                        ids.iter()
+                            .map(|_| Err(batch_request_error(&e, "batched cancellation"))),
+                    );
+                    continue;
+                }
+            };
+
+            let mut canceled: HashMap<String, BatchCancelOrderItem> = response
+                .orders
+                .into_iter()
+                .map(|item| (item.order_id.clone(), item))
+                .collect();
+            for order_id in ids {
+                results.push(match canceled.remove(&order_id) {
+                    Some(item) => item.into_result(),
+                    None => Err(KalshiError::InternalError(format!(
+                        "Batched cancellation response has no result for order {}",
+                        order_id
+                    ))),
+                });
+            }
+        }
+
+        Ok(BatchOutcome::from(results))
+    }
+
+    // Returns `None` if the account isn't allowed to use the batched endpoint.
+    async fn send_batch_cancel_order(
+        &self,
+        batch_url: &str,
+        ids: &[String],
+    ) -> Result<Option<BatchCancelOrderResponse>, KalshiError> {
+        let response = self
+            .send_authenticated(
+                self.order_client
+                    .delete(batch_url)
+                    .header("content-type", "application/json".to_string())
+                    .body(self.json_body(&BatchCancelOrderPayload { ids })?),
+            )
+            .await?;
 
-        for order_id in batch {
-            let kalshi_ref = Arc::clone(&temp_instance);
-            let order_id = order_id.clone();
+        if response.status() == reqwest::StatusCode::FORBIDDEN {
+            return Ok(None);
+        }
+
+        Ok(Some(response.error_for_status()?.json().await?))
+    }
 
-            let future = task::spawn(async move { kalshi_ref.cancel_order(&order_id).await });
-            futures.push(future);
+    async fn cancel_orders_concurrently(
+        &self,
+        order_ids: Vec<String>,
+    ) -> Vec<Result<CanceledOrder, KalshiError>> {
+        let tasks = order_ids
+            .into_iter()
+            .map(|order_id| {
+                let task_order_id = order_id.clone();
+                (order_id, async move {
+                    let (order, reduced_by) = self.cancel_order(&task_order_id).await?;
+                    Ok(CanceledOrder { order, reduced_by })
+                })
+            })
+            .collect();
+
+        utils::join_bounded(tasks, utils::DEFAULT_MAX_CONCURRENCY).await
+    }
+
+    /// Cancels every resting order in the account.
+    ///
+    /// Lists the resting orders page by page and cancels them concurrently. Each cancellation
+    /// succeeds or fails on its own, like [`batch_cancel_order`](Kalshi::batch_cancel_order).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(BatchOutcome<CanceledOrder>)`: The outcome of each cancellation.
+    /// - `Err(KalshiError)`: An error if the client is read-only or the resting orders could not be listed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let outcome = kalshi_instance.cancel_all_orders().await?;
+    /// println!("canceled {} orders", outcome.successes().count());
+    /// ```
+    ///
+    pub async fn cancel_all_orders(&self) -> Result<BatchOutcome<CanceledOrder>, KalshiError> {
+        self.ensure_writable("cancel_all_orders")?;
+
+        let mut order_ids = Vec::new();
+        let mut cursor = None;
+        loop {
+            let (next, orders) = self
+                .get_multiple_orders(
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some("resting".to_string()),
+                    Some(LISTING_PAGE_SIZE as i32),
+                    cursor,
+                )
+                .await?;
+            order_ids.extend(orders.into_iter().map(|order| order.order_id));
+
+            match next.into_next() {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
         }
 
-        let mut outputs = Vec::new();
+        self.batch_cancel_order(order_ids).await
+    }
 
-        // TODO: improve error process for joining, I don't believe it's specific enough.
-        for future in futures {
-            match future.await {
-                Ok(result) => outputs.push(result),
-                Err(e) => {
-                    return Err(KalshiError::UserInputError(format!(
-                        "Join of concurrent requests failed, check input or message developer: {}",
-                        e
-                    )));
-                }
+    /// Closes every open position in the account with market sell orders.
+    ///
+    /// Each position is sold on the side it is held, for its full size. Resting orders are left
+    /// untouched, so call [`cancel_all_orders`](Kalshi::cancel_all_orders) first to keep them from
+    /// reopening positions.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(BatchOutcome<Order>)`: The outcome of the sell order for each open position.
+    /// - `Err(KalshiError)`: An error if the client is read-only or the positions could not be listed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// kalshi_instance.cancel_all_orders().await?;
+    /// let outcome = kalshi_instance.flatten_positions().await?;
+    /// ```
+    ///
+    pub async fn flatten_positions(&self) -> Result<BatchOutcome<Order>, KalshiError> {
+        self.ensure_writable("flatten_positions")?;
+
+        let positions = self.get_all_market_positions().await?;
+
+        let tasks = positions
+            .into_iter()
+            .filter_map(|position| {
+                let side = position.direction()?;
+                let count = position.position.abs();
+                let ticker = position.ticker;
+                Some((ticker.clone(), async move {
+                    self.create_order(
+                        Action::Sell,
+                        None,
+                        count,
+                        side,
+                        ticker,
+                        OrderType::Market,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                    )
+                    .await
+                }))
+            })
+            .collect();
+
+        let outputs = utils::join_bounded(tasks, utils::DEFAULT_MAX_CONCURRENCY).await;
+        Ok(BatchOutcome::from(outputs))
+    }
+
+    // Lists the market positions of every page.
+    pub(crate) async fn get_all_market_positions(
+        &self,
+    ) -> Result<Vec<MarketPosition>, KalshiError> {
+        let mut positions = Vec::new();
+        let mut cursor = None;
+        loop {
+            let (next, _, market_positions) = self
+                .get_user_positions(Some(LISTING_PAGE_SIZE), cursor, None, None, None)
+                .await?;
+            positions.extend(market_positions);
+
+            match next.into_next() {
+                Some(next) => cursor = Some(next),
+                None => break,
             }
         }
-        Ok(outputs)
+        Ok(positions)
     }
 
+    /// Submits several orders through the exchange's batched order endpoint.
+    ///
+    /// Orders are sent 20 per request, so a large batch costs a fraction of the rate limit of
+    /// submitting each order on its own. The exchange accepts or rejects every order of a request
+    /// individually, and orders that fail validation locally are never sent, so the outcome holds
+    /// one result per order, in submission order.
+    ///
+    /// If a request times out or fails with a server error, the exchange may still have placed its
+    /// orders. Those orders fail with [`KalshiError::OrderOutcomeUnknown`], carrying the `client_order_id`
+    /// they were sent with, so they can be looked up before being retried.
+    ///
+    /// The batched endpoint requires advanced API access; if the exchange refuses it, the remaining
+    /// orders are created with concurrent single order requests instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch` - The orders to create. Orders without a `client_order_id` get a random one.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(BatchOutcome<Order>)`: The created order or the error of every order in the batch.
+    /// - `Err(KalshiError)`: A `ReadOnlyError` if the client is read-only, or a `UserInputError` if two
+    ///   orders share a `client_order_id`, in which case nothing is sent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let outcome = kalshi_instance.batch_create_order(orders).await?;
+    /// for error in outcome.failures() {
+    ///     eprintln!("order rejected: {}", error);
+    /// }
+    /// ```
+    ///
     pub async fn batch_create_order(
-        &mut self,
+        &self,
         batch: Vec<OrderCreationField>,
-    ) -> Result<Vec<Result<(Order, i32), KalshiError>>, KalshiError> {
-        todo!()
+    ) -> Result<BatchOutcome<Order>, KalshiError> {
+        self.ensure_writable("batch_create_order")?;
+
+        let batch_url: &str = &format!("{}/portfolio/orders/batched", self.base_url());
+
+        let mut results: Vec<Option<Result<Order, KalshiError>>> = Vec::with_capacity(batch.len());
+        let mut pending = Vec::new();
+        for (index, field) in batch.into_iter().enumerate() {
+            match CreateOrderPayload::from_field(field) {
+                Ok(payload) => {
+                    results.push(None);
+                    pending.push((index, payload));
+                }
+                Err(e) => results.push(Some(Err(e))),
+            }
+        }
+
+        if let Some(client_order_id) = duplicate_client_order_id(&pending) {
+            return Err(KalshiError::UserInputError(format!(
+                "Client order id {} is used by more than one order of the batch",
+                client_order_id
+            )));
+        }
+
+        while !pending.is_empty() {
+            let chunk = pending.drain(..pending.len().min(BATCH_CREATE_ORDERS_PER_REQUEST));
+            let (indices, orders): (Vec<usize>, Vec<CreateOrderPayload>) = chunk.unzip();
+            let client_order_ids: HashMap<String, usize> = orders
+                .iter()
+                .zip(&indices)
+                .map(|(order, index)| (order.client_order_id.clone(), *index))
+                .collect();
+
+            match self.send_batch_create_order(batch_url, &orders).await {
+                Ok(Some(response)) => {
+                    for (index, result) in match_batch_results(client_order_ids, response.orders) {
+                        results[index] = Some(result);
+                    }
+                }
+                Ok(None) => {
+                    // No advanced access: create this chunk and the rest one order at a time.
+                    let rest: Vec<(usize, CreateOrderPayload)> = indices
+                        .into_iter()
+                        .zip(orders)
+                        .chain(pending.drain(..))
+                        .collect();
+                    for (index, result) in self.create_orders_concurrently(rest).await {
+                        results[index] = Some(result);
+                    }
+                    break;
+                }
+                Err(e) if outcome_is_known(&e) => {
+                    for index in indices {
+                        results[index] = Some(Err(batch_request_error(&e, "batched orders")));
+                    }
+                }
+                Err(e) => {
+                    for (client_order_id, index) in client_order_ids {
+                        results[index] = Some(Err(KalshiError::OrderOutcomeUnknown(
+                            client_order_id,
+                            format!("Failed to send batched orders: {}", e),
+                        )));
+                    }
+                }
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .map(|result| {
+                result.unwrap_or_else(|| {
+                    Err(KalshiError::InternalError(
+                        "Order was never submitted".to_string(),
+                    ))
+                })
+            })
+            .collect())
+    }
+
+    async fn send_batch_create_order(
+        &self,
+        batch_url: &str,
+        orders: &[CreateOrderPayload],
+    ) -> Result<Option<BatchCreateOrderResponse>, KalshiError> {
+        let response = self
+            .send_authenticated(
+                self.order_client
+                    .post(batch_url)
+                    .header("content-type", "application/json".to_string())
+                    .body(self.json_body(&BatchCreateOrderPayload { orders })?),
+            )
+            .await?;
+
+        if response.status() == reqwest::StatusCode::FORBIDDEN {
+            return Ok(None);
+        }
+
+        Ok(Some(response.error_for_status()?.json().await?))
+    }
+
+    async fn create_orders_concurrently(
+        &self,
+        orders: Vec<(usize, CreateOrderPayload)>,
+    ) -> Vec<(usize, Result<Order, KalshiError>)> {
+        let order_url: &str = &format!("{}/portfolio/orders", self.base_url());
+
+        let (indices, orders): (Vec<usize>, Vec<CreateOrderPayload>) = orders.into_iter().unzip();
+        let tasks = orders
+            .iter()
+            .map(|order| {
+                (
+                    order.client_order_id.clone(),
+                    self.post_order(order_url, order),
+                )
+            })
+            .collect();
+
+        indices
+            .into_iter()
+            .zip(utils::join_bounded(tasks, utils::DEFAULT_MAX_CONCURRENCY).await)
+            .collect()
+    }
+
+    /// Opens the connection reserved for order placement ahead of time.
+    ///
+    /// Order creation, decreases and cancellations are sent over their own HTTP client, so a burst of
+    /// orders is never queued behind market data requests. Calling this before a burst, e.g. right
+    /// before the market opens, establishes that connection so the first order doesn't pay for the
+    /// TCP and TLS handshakes. Idle connections on the order client are kept alive indefinitely.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())`: The order connection is established.
+    /// - `Err(KalshiError)`: An error if there is an issue with the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// kalshi_instance.warm_order_connection().await.unwrap();
+    /// ```
+    ///
+    pub async fn warm_order_connection(&self) -> Result<(), KalshiError> {
+        let exchange_status_url: &str = &format!("{}/exchange/status", self.base_url());
+
+        self.order_client
+            .get(exchange_status_url)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
     }
 }
 
@@ -710,20 +1021,8 @@ struct SingleOrderResponse {
 #[derive(Debug, Deserialize, Serialize)]
 struct MultipleOrderResponse {
     orders: Vec<Order>,
-    #[serde(deserialize_with = "empty_string_is_none")]
-    cursor: Option<String>,
-}
-
-fn empty_string_is_none<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    let s = String::deserialize(deserializer)?;
-    if s.is_empty() {
-        Ok(None)
-    } else {
-        Ok(Some(s))
-    }
+    #[serde(default)]
+    cursor: Cursor,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -746,18 +1045,21 @@ struct DecreaseOrderPayload {
 #[derive(Debug, Deserialize, Serialize)]
 struct MultipleFillsResponse {
     fills: Vec<Fill>,
-    cursor: Option<String>,
+    #[serde(default)]
+    cursor: Cursor,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 struct PortfolioSettlementResponse {
-    cursor: Option<String>,
+    #[serde(default)]
+    cursor: Cursor,
     settlements: Vec<Settlement>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 struct GetPositionsResponse {
-    cursor: Option<String>,
+    #[serde(default)]
+    cursor: Cursor,
     event_positions: Vec<EventPosition>,
     market_positions: Vec<MarketPosition>,
 }
@@ -775,6 +1077,218 @@ struct CreateOrderPayload {
     no_price: Option<i64>,
     sell_position_floor: Option<i32>,
     yes_price: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_group_id: Option<String>,
+}
+
+impl CreateOrderPayload {
+    fn from_field(mut field: OrderCreationField) -> Result<CreateOrderPayload, KalshiError> {
+        let order_group_id = field.order_group_id.take();
+        let mut payload = CreateOrderPayload::from_params(field)?;
+        payload.order_group_id = order_group_id;
+        Ok(payload)
+    }
+
+    // Validates the prices of a limit order and fills in a random client order id if none was given.
+    fn from_params(params: impl OrderParams) -> Result<CreateOrderPayload, KalshiError> {
+        let (
+            action,
+            client_order_id,
+            count,
+            side,
+            ticker,
+            input_type,
+            buy_max_cost,
+            expiration_ts,
+            no_price,
+            sell_position_floor,
+            yes_price,
+        ) = params.get_params();
+
+        if let OrderType::Limit = input_type {
+            match (no_price, yes_price) {
+                (Some(_), Some(_)) => {
+                    return Err(KalshiError::UserInputError(
+                        "Can only provide no_price exclusive or yes_price, can't provide both"
+                            .to_string(),
+                    ));
+                }
+                (None, None) => {
+                    return Err(KalshiError::UserInputError(
+                        "Must provide either no_price exclusive or yes_price, can't provide neither"
+                            .to_string(),
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(CreateOrderPayload {
+            action,
+            client_order_id: client_order_id.unwrap_or_else(|| String::from(Uuid::new_v4())),
+            count,
+            side,
+            ticker,
+            r#type: input_type,
+            buy_max_cost,
+            expiration_ts,
+            no_price,
+            sell_position_floor,
+            yes_price,
+            order_group_id: None,
+        })
+    }
+}
+
+// The exchange reports a failed request of a batch once, but every order in it needs its own error.
+// Pairs the results of a batched creation with the orders sent, by `client_order_id`, whatever order
+// the exchange returns them in. Orders the response has no result for have an unknown outcome.
+fn match_batch_results(
+    mut client_order_ids: HashMap<String, usize>,
+    items: Vec<BatchCreateOrderItem>,
+) -> Vec<(usize, Result<Order, KalshiError>)> {
+    let mut matched = Vec::with_capacity(client_order_ids.len());
+
+    for item in items {
+        let index = item
+            .client_order_id()
+            .and_then(|id| client_order_ids.remove(id));
+        if let Some(index) = index {
+            matched.push((index, item.into_result()));
+        }
+    }
+
+    for (client_order_id, index) in client_order_ids {
+        matched.push((
+            index,
+            Err(KalshiError::OrderOutcomeUnknown(
+                client_order_id,
+                "Batched order response has no result for the order".to_string(),
+            )),
+        ));
+    }
+
+    matched
+}
+
+// Whether a failed order request was certainly not acted on by the exchange: it was refused before
+// being sent, or rejected with a client error. Timeouts, server errors and unreadable responses leave
+// open whether the orders were placed.
+// Returns a client order id shared by two orders, whose results couldn't be told apart.
+fn duplicate_client_order_id(orders: &[(usize, CreateOrderPayload)]) -> Option<&str> {
+    let mut seen = HashSet::with_capacity(orders.len());
+    orders
+        .iter()
+        .map(|(_, order)| order.client_order_id.as_str())
+        .find(|client_order_id| !seen.insert(*client_order_id))
+}
+
+fn outcome_is_known(e: &KalshiError) -> bool {
+    matches!(
+        e,
+        KalshiError::AuthenticationError(_)
+            | KalshiError::ReadOnlyError(_)
+            | KalshiError::UserInputError(_)
+            | KalshiError::RequestError(RequestError::ClientError(_))
+    )
+}
+
+fn batch_request_error(e: &KalshiError, request: &str) -> KalshiError {
+    match e {
+        KalshiError::AuthenticationError(message) => {
+            KalshiError::AuthenticationError(message.clone())
+        }
+        other => KalshiError::InternalError(format!("Failed to send {}: {}", request, other)),
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct BatchCancelOrderPayload<'a> {
+    ids: &'a [String],
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchCancelOrderResponse {
+    orders: Vec<BatchCancelOrderItem>,
+}
+
+// One order of a batched cancellation: the canceled order, or the exchange's reason for refusing.
+#[derive(Debug, Deserialize)]
+struct BatchCancelOrderItem {
+    order_id: String,
+    order: Option<Order>,
+    #[serde(default)]
+    reduced_by: i32,
+    error: Option<BatchOrderError>,
+}
+
+impl BatchCancelOrderItem {
+    fn into_result(self) -> Result<CanceledOrder, KalshiError> {
+        match (self.order, self.error) {
+            (Some(order), None) => Ok(CanceledOrder {
+                order,
+                reduced_by: self.reduced_by,
+            }),
+            (_, Some(error)) => Err(KalshiError::InternalError(format!(
+                "Cancellation of order {} refused by the exchange: {} ({})",
+                self.order_id, error.message, error.code
+            ))),
+            (None, None) => Err(KalshiError::InternalError(format!(
+                "Batched cancellation response has neither an order nor an error for order {}",
+                self.order_id
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct BatchCreateOrderPayload<'a> {
+    orders: &'a [CreateOrderPayload],
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchCreateOrderResponse {
+    orders: Vec<BatchCreateOrderItem>,
+}
+
+// One order of a batched creation: the created order, or the exchange's reason for rejecting it.
+#[derive(Debug, Deserialize)]
+struct BatchCreateOrderItem {
+    #[serde(default)]
+    client_order_id: Option<String>,
+    order: Option<Order>,
+    error: Option<BatchOrderError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchOrderError {
+    #[serde(default)]
+    code: String,
+    #[serde(default)]
+    message: String,
+}
+
+impl BatchCreateOrderItem {
+    fn client_order_id(&self) -> Option<&str> {
+        self.client_order_id.as_deref().or_else(|| {
+            self.order
+                .as_ref()
+                .map(|order| order.client_order_id.as_str())
+        })
+    }
+
+    fn into_result(self) -> Result<Order, KalshiError> {
+        match (self.order, self.error) {
+            (Some(order), _) => Ok(order),
+            (None, Some(error)) => Err(KalshiError::InternalError(format!(
+                "Order rejected by the exchange: {} ({})",
+                error.message, error.code
+            ))),
+            (None, None) => Err(KalshiError::InternalError(
+                "Batched order response has neither an order nor an error".to_string(),
+            )),
+        }
+    }
 }
 
 // PUBLIC STRUCTS
@@ -799,11 +1313,11 @@ pub struct Order {
     /// Price of the 'No' option in the order.
     pub no_price: i32,
     /// Timestamp when the order was created. Optional.
-    pub created_time: Option<String>,
+    pub created_time: Option<Timestamp>,
     /// Count of fills where the order acted as a taker. Optional.
     pub taker_fill_count: Option<i32>,
-    /// Total cost of taker fills. Optional.
-    pub taker_fill_cost: Option<i32>,
+    /// Total cost of taker fills in cents. Optional.
+    pub taker_fill_cost: Option<i64>,
     /// Count of order placements. Optional.
     pub place_count: Option<i32>,
     /// Count of order decreases. Optional.
@@ -819,9 +1333,9 @@ pub struct Order {
     /// Position of the order in the queue. Optional.
     pub queue_position: Option<i32>,
     /// Expiration time of the order. Optional.
-    pub expiration_time: Option<String>,
-    /// Fees incurred as a taker. Optional.
-    pub taker_fees: Option<i32>,
+    pub expiration_time: Option<Timestamp>,
+    /// Fees incurred as a taker in cents. Optional.
+    pub taker_fees: Option<i64>,
     /// The action (buy/sell) of the order.
     pub action: Action,
     /// The side (Yes/No) of the order.
@@ -829,11 +1343,12 @@ pub struct Order {
     /// Type of the order (e.g., market, limit).
     pub r#type: String,
     /// Last update time of the order. Optional.
-    pub last_update_time: Option<String>,
+    pub last_update_time: Option<Timestamp>,
     /// Client-side identifier for the order.
     pub client_order_id: String,
-    /// Group identifier for the order.
-    pub order_group_id: String,
+    /// The order group the order belongs to, if any. See [`Kalshi::create_order_group`].
+    #[serde(default)]
+    pub order_group_id: Option<String>,
 }
 
 /// A completed transaction (a 'fill') in the Kalshi exchange.
@@ -848,7 +1363,7 @@ pub struct Fill {
     /// The number of contracts or shares involved in the fill.
     pub count: i32,
     /// The timestamp when the fill was created.
-    pub created_time: String,
+    pub created_time: Timestamp,
     /// Indicates if the fill was made by a taker.
     pub is_taker: bool,
     /// The price of the 'No' option in the fill.
@@ -881,7 +1396,7 @@ pub struct Settlement {
     /// The revenue generated from the settlement, in cents.
     pub revenue: i64,
     /// The timestamp when the settlement occurred.
-    pub settled_time: String,
+    pub settled_time: Timestamp,
     /// The ticker of the market that was settled.
     pub ticker: String,
     /// The quantity involved in the 'Yes' position.
@@ -915,13 +1430,15 @@ pub struct EventPosition {
 /// This struct includes details about the user's market position, including exposure, fees,
 /// profits, and the number of resting orders.
 ///
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct MarketPosition {
     /// The total fees paid in the market in cents.
     pub fees_paid: i64,
     /// The total exposure amount in the market.
     pub market_exposure: i64,
     /// The current position of the user in the market.
+    /// Positive values are 'Yes' contracts held, negative values are 'No' contracts held.
+    /// See [`net_yes_contracts`](MarketPosition::net_yes_contracts) and [`direction`](MarketPosition::direction).
     pub position: i32,
     /// The realized profit or loss in the market in cents.
     pub realized_pnl: i64,
@@ -933,6 +1450,27 @@ pub struct MarketPosition {
     pub total_traded: i64,
 }
 
+impl MarketPosition {
+    /// The number of 'Yes' contracts held, or zero if the position is flat or on the 'No' side.
+    pub fn net_yes_contracts(&self) -> i32 {
+        self.position.max(0)
+    }
+
+    /// The number of 'No' contracts held, or zero if the position is flat or on the 'Yes' side.
+    pub fn net_no_contracts(&self) -> i32 {
+        (-self.position).max(0)
+    }
+
+    /// The side the position is held on, or `None` if the position is flat.
+    pub fn direction(&self) -> Option<Side> {
+        match self.position {
+            p if p > 0 => Some(Side::Yes),
+            p if p < 0 => Some(Side::No),
+            _ => None,
+        }
+    }
+}
+
 /// Represents the necessary fields for creating an order in the Kalshi exchange.
 ///
 /// This struct is used to encapsulate all the data needed to create a new order. It includes details about the order type,
@@ -962,6 +1500,9 @@ pub struct OrderCreationField {
     pub sell_position_floor: Option<i32>,
     /// Price of the 'Yes' option in the order. Optional.
     pub yes_price: Option<i64>,
+    /// The order group the order joins, as returned by [`Kalshi::create_order_group`]. Optional.
+    #[serde(default)]
+    pub order_group_id: Option<String>,
 }
 
 impl OrderParams for OrderCreationField {
@@ -996,11 +1537,202 @@ impl OrderParams for OrderCreationField {
     }
 }
 
+/// An order canceled by [`Kalshi::batch_cancel_order`], with the number of contracts the cancellation removed.
+///
+#[derive(Debug)]
+pub struct CanceledOrder {
+    /// The order after cancellation.
+    pub order: Order,
+    /// The number of resting contracts the cancellation removed.
+    pub reduced_by: i32,
+}
+
+/// The per-item results of a batch or concurrent operation.
+///
+/// Wraps the results of each individual request in the batch, in submission order, and offers
+/// helpers to split them into successes and failures or collapse them into a single `Result`.
+///
+/// # Example
+///
+/// ```
+/// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+/// let outcome = kalshi_instance.batch_cancel_order(order_ids).await?;
+/// for canceled in outcome.successes() {
+///     println!("{} reduced by {}", canceled.order.order_id, canceled.reduced_by);
+/// }
+/// let all_cancelled = outcome.into_result()?;
+/// ```
+#[derive(Debug)]
+pub struct BatchOutcome<T> {
+    results: Vec<Result<T, KalshiError>>,
+}
+
+impl<T> BatchOutcome<T> {
+    /// Returns the results of every item in the batch, in submission order.
+    pub fn results(&self) -> &[Result<T, KalshiError>] {
+        &self.results
+    }
+
+    /// Consumes the outcome, returning the results of every item in the batch.
+    pub fn into_results(self) -> Vec<Result<T, KalshiError>> {
+        self.results
+    }
+
+    /// Returns an iterator over the values of the items that succeeded.
+    pub fn successes(&self) -> impl Iterator<Item = &T> {
+        self.results
+            .iter()
+            .filter_map(|result| result.as_ref().ok())
+    }
+
+    /// Returns an iterator over the errors of the items that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &KalshiError> {
+        self.results
+            .iter()
+            .filter_map(|result| result.as_ref().err())
+    }
+
+    /// Returns `true` if every item in the batch succeeded.
+    pub fn is_complete_success(&self) -> bool {
+        self.results.iter().all(|result| result.is_ok())
+    }
+
+    /// The number of items in the batch.
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Returns `true` if the batch contained no items.
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    /// Collapses the outcome into a single `Result`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<T>)`: The values of every item, if all of them succeeded.
+    /// - `Err(KalshiError)`: The first error encountered in submission order.
+    pub fn into_result(self) -> Result<Vec<T>, KalshiError> {
+        self.results.into_iter().collect()
+    }
+}
+
+impl BatchOutcome<Order> {
+    /// Summarizes a batch of order submissions. See [`BatchFillReport`].
+    pub fn fill_report(&self) -> Result<BatchFillReport, KalshiError> {
+        BatchFillReport::from_results(&self.results)
+    }
+}
+
+impl<T> From<Vec<Result<T, KalshiError>>> for BatchOutcome<T> {
+    fn from(results: Vec<Result<T, KalshiError>>) -> Self {
+        BatchOutcome { results }
+    }
+}
+
+impl<T> FromIterator<Result<T, KalshiError>> for BatchOutcome<T> {
+    fn from_iter<I: IntoIterator<Item = Result<T, KalshiError>>>(iter: I) -> Self {
+        BatchOutcome {
+            results: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> IntoIterator for BatchOutcome<T> {
+    type Item = Result<T, KalshiError>;
+    type IntoIter = std::vec::IntoIter<Result<T, KalshiError>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.into_iter()
+    }
+}
+
+/// A consolidated summary of a batch of order submissions.
+///
+/// Rather than folding over a vector of nested results by hand, users can build a report from the
+/// results of a batch submission to see how many orders were accepted or rejected, how many contracts
+/// filled immediately, the average fill price and the fees paid.
+///
+/// # Example
+///
+/// ```
+/// // Assuming `outcome` is a BatchOutcome<Order> from a batch submission
+/// let report = BatchFillReport::from_results(outcome.results())?;
+/// println!("{} accepted, {} filled", report.accepted, report.filled_count);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BatchFillReport {
+    /// Number of orders submitted.
+    pub submitted: usize,
+    /// Number of orders accepted by the exchange.
+    pub accepted: usize,
+    /// Number of orders rejected by the exchange or that failed to send.
+    pub rejected: usize,
+    /// Number of contracts filled immediately on submission.
+    pub filled_count: i64,
+    /// Total cost of the immediate fills, in cents.
+    pub filled_cost: i64,
+    /// Average price of the immediate fills, in cents. `None` if nothing filled.
+    pub average_price: Option<f64>,
+    /// Total fees paid on the immediate fills, in cents.
+    pub fees: i64,
+}
+
+impl BatchFillReport {
+    /// Builds a report from the per-order results of a batch submission.
+    ///
+    /// # Arguments
+    ///
+    /// * `results` - The per-order results, in submission order.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(BatchFillReport)`: The summary of the batch.
+    /// - `Err(KalshiError)`: An `InternalError` if a fill total overflows.
+    pub fn from_results(
+        results: &[Result<Order, KalshiError>],
+    ) -> Result<BatchFillReport, KalshiError> {
+        let overflow = || KalshiError::InternalError("Batch fill totals overflow".to_string());
+        let mut report = BatchFillReport {
+            submitted: results.len(),
+            ..Default::default()
+        };
+
+        for result in results {
+            match result {
+                Ok(order) => {
+                    report.accepted += 1;
+                    report.filled_count = report
+                        .filled_count
+                        .checked_add(order.taker_fill_count.unwrap_or(0) as i64)
+                        .ok_or_else(overflow)?;
+                    report.filled_cost = report
+                        .filled_cost
+                        .checked_add(order.taker_fill_cost.unwrap_or(0))
+                        .ok_or_else(overflow)?;
+                    report.fees = report
+                        .fees
+                        .checked_add(order.taker_fees.unwrap_or(0))
+                        .ok_or_else(overflow)?;
+                }
+                Err(_) => report.rejected += 1,
+            }
+        }
+
+        if report.filled_count > 0 {
+            report.average_price = Some(report.filled_cost as f64 / report.filled_count as f64);
+        }
+
+        Ok(report)
+    }
+}
+
 /// The side of a market position in the Kalshi exchange.
 ///
 /// This enum is used to indicate whether a market position, order, or trade is associated with the 'Yes' or 'No' outcome of a market event.
 ///
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Side {
     /// Represents a position, order, or trade associated with the 'Yes' outcome of a market event.
@@ -1011,7 +1743,7 @@ pub enum Side {
 
 /// This enum is used to specify the type of action a user wants to take in an order, either buying or selling.
 ///
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Action {
     /// Represents a buy action.
@@ -1020,6 +1752,53 @@ pub enum Action {
     Sell,
 }
 
+impl Side {
+    /// The opposite side of the market.
+    pub fn opposite(&self) -> Side {
+        match self {
+            Side::Yes => Side::No,
+            Side::No => Side::Yes,
+        }
+    }
+}
+
+/// Converts a trade of `count` contracts into a change of the signed position, using the same
+/// convention as [`MarketPosition::position`]: positive for 'Yes' contracts, negative for 'No' contracts.
+///
+/// Buying 'Yes' or selling 'No' increases the position, buying 'No' or selling 'Yes' decreases it.
+///
+/// # Example
+///
+/// ```
+/// use kalshi::{signed_position_change, Action, Side};
+/// assert_eq!(signed_position_change(Action::Buy, Side::No, 5), -5);
+/// ```
+pub fn signed_position_change(action: Action, side: Side, count: i64) -> i64 {
+    match (action, side) {
+        (Action::Buy, Side::Yes) | (Action::Sell, Side::No) => count,
+        (Action::Buy, Side::No) | (Action::Sell, Side::Yes) => -count,
+    }
+}
+
+impl Fill {
+    /// The change this fill made to the signed market position. See [`signed_position_change`].
+    pub fn position_change(&self) -> i64 {
+        signed_position_change(self.action, self.side, self.count as i64)
+    }
+}
+
+impl Order {
+    /// The change to the signed market position if the rest of this order fills.
+    /// See [`signed_position_change`].
+    pub fn remaining_position_change(&self) -> i64 {
+        signed_position_change(
+            self.action,
+            self.side,
+            self.remaining_count.unwrap_or(0) as i64,
+        )
+    }
+}
+
 impl fmt::Display for Action {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -1126,14 +1905,245 @@ impl OrderParams
 
 #[cfg(test)]
 mod test {
-    use crate::portfolio::MultipleOrderResponse;
+    use crate::fixtures::position;
+    use crate::portfolio::{
+        duplicate_client_order_id, match_batch_results, signed_position_change, Action,
+        BatchCancelOrderResponse, BatchCreateOrderResponse, BatchFillReport, BatchOutcome,
+        CreateOrderPayload, MultipleOrderResponse, Order, OrderCreationField, OrderType, Side,
+    };
+    use crate::KalshiError;
+    use std::collections::HashMap;
 
     #[test]
     fn test_serialize_multiple_order_response() -> serde_json::Result<()> {
         let json = r#"{"orders":[],"cursor":""}"#;
         let result = serde_json::from_str::<MultipleOrderResponse>(json)?;
         assert!(result.orders.is_empty());
-        assert!(result.cursor.is_none());
+        assert!(result.cursor.is_end());
         Ok(())
     }
+
+    #[test]
+    fn test_batch_outcome_splits_results() {
+        let outcome: BatchOutcome<i32> = vec![
+            Ok(1),
+            Err(KalshiError::UserInputError("bad".to_string())),
+            Ok(3),
+        ]
+        .into();
+
+        assert_eq!(outcome.successes().copied().collect::<Vec<_>>(), vec![1, 3]);
+        assert_eq!(outcome.failures().count(), 1);
+        assert!(!outcome.is_complete_success());
+        assert!(outcome.into_result().is_err());
+    }
+
+    #[test]
+    fn test_market_position_sign_helpers() {
+        let cases = [
+            (7, 7, 0, Some(Side::Yes)),
+            (-4, 0, 4, Some(Side::No)),
+            (0, 0, 0, None),
+        ];
+
+        for (contracts, yes, no, direction) in cases {
+            let position = position("EV-A", contracts, 0);
+            assert_eq!(position.net_yes_contracts(), yes, "position {}", contracts);
+            assert_eq!(position.net_no_contracts(), no, "position {}", contracts);
+            assert_eq!(position.direction(), direction, "position {}", contracts);
+        }
+    }
+
+    #[test]
+    fn test_signed_position_change_per_action_and_side() {
+        let cases = [
+            (Action::Buy, Side::Yes, 5),
+            (Action::Buy, Side::No, -5),
+            (Action::Sell, Side::Yes, -5),
+            (Action::Sell, Side::No, 5),
+        ];
+
+        for (action, side, change) in cases {
+            assert_eq!(
+                signed_position_change(action, side, 5),
+                change,
+                "{:?} {:?}",
+                action,
+                side
+            );
+        }
+        assert_eq!(signed_position_change(Action::Sell, Side::Yes, 0), 0);
+    }
+
+    fn order(taker_fill_count: i32, taker_fill_cost: i64, taker_fees: i64) -> Order {
+        serde_json::from_str(&format!(
+            r#"{{"order_id":"o","ticker":"EV-A","status":"executed","yes_price":45,"no_price":55,
+                "taker_fill_count":{},"taker_fill_cost":{},"taker_fees":{},
+                "action":"buy","side":"yes","type":"limit","client_order_id":"c"}}"#,
+            taker_fill_count, taker_fill_cost, taker_fees
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_order_group_id_is_optional() {
+        assert_eq!(order(0, 0, 0).order_group_id, None);
+
+        let grouped: Order = serde_json::from_str(
+            r#"{"order_id":"o","ticker":"EV-A","status":"resting","yes_price":45,"no_price":55,
+                "action":"buy","side":"yes","type":"limit","client_order_id":"c","order_group_id":"og-1"}"#,
+        )
+        .unwrap();
+        assert_eq!(grouped.order_group_id.as_deref(), Some("og-1"));
+
+        let ungrouped: Order = serde_json::from_str(
+            r#"{"order_id":"o","ticker":"EV-A","status":"resting","yes_price":45,"no_price":55,
+                "action":"buy","side":"yes","type":"limit","client_order_id":"c","order_group_id":null}"#,
+        )
+        .unwrap();
+        assert_eq!(ungrouped.order_group_id, None);
+    }
+
+    #[test]
+    fn test_batch_fill_report_sums_immediate_fills() {
+        let report = BatchFillReport::from_results(&[
+            Ok(order(10, 450, 16)),
+            Err(KalshiError::UserInputError("bad".to_string())),
+            Ok(order(5, 250, 9)),
+            Ok(order(0, 0, 0)),
+        ])
+        .unwrap();
+
+        assert_eq!(report.submitted, 4);
+        assert_eq!(report.accepted, 3);
+        assert_eq!(report.rejected, 1);
+        assert_eq!(report.filled_count, 15);
+        assert_eq!(report.filled_cost, 700);
+        assert_eq!(report.fees, 25);
+        assert!((report.average_price.unwrap() - 700.0 / 15.0).abs() < 1e-9);
+
+        let unfilled = BatchFillReport::from_results(&[
+            Ok(order(0, 0, 0)),
+            Err(KalshiError::UserInputError("bad".to_string())),
+        ])
+        .unwrap();
+        assert_eq!(unfilled.accepted, 1);
+        assert_eq!(unfilled.filled_count, 0);
+        assert_eq!(unfilled.average_price, None);
+
+        assert_eq!(
+            BatchFillReport::from_results(&[]).unwrap(),
+            BatchFillReport::default()
+        );
+
+        assert!(
+            BatchFillReport::from_results(&[Ok(order(10, i64::MAX, 0)), Ok(order(10, 1, 0)),])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_batch_create_order_response_keeps_per_order_errors() {
+        let json = r#"{"orders":[{"order":null,"error":{"code":"insufficient_balance","message":"Insufficient balance"}},{"order":null,"error":null}]}"#;
+        let response = serde_json::from_str::<BatchCreateOrderResponse>(json).unwrap();
+
+        let outcome: BatchOutcome<_> = response
+            .orders
+            .into_iter()
+            .map(|item| item.into_result())
+            .collect();
+        assert_eq!(outcome.len(), 2);
+        assert!(outcome
+            .failures()
+            .next()
+            .unwrap()
+            .to_string()
+            .contains("Insufficient balance"));
+        assert_eq!(outcome.successes().count(), 0);
+    }
+
+    #[test]
+    fn test_batch_create_order_results_match_by_client_order_id() {
+        let json = r#"{"orders":[{"client_order_id":"c","order":null,"error":{"code":"market_closed","message":"Market closed"}},{"client_order_id":"a","order":null,"error":{"code":"insufficient_balance","message":"Insufficient balance"}},{"client_order_id":"z","order":null,"error":null}]}"#;
+        let response = serde_json::from_str::<BatchCreateOrderResponse>(json).unwrap();
+        let sent = HashMap::from([
+            ("a".to_string(), 0),
+            ("b".to_string(), 1),
+            ("c".to_string(), 2),
+        ]);
+
+        let mut matched = match_batch_results(sent, response.orders);
+        matched.sort_by_key(|(index, _)| *index);
+
+        assert_eq!(matched.len(), 3);
+        assert!(matched[0]
+            .1
+            .as_ref()
+            .unwrap_err()
+            .to_string()
+            .contains("Insufficient balance"));
+        match &matched[1].1 {
+            Err(KalshiError::OrderOutcomeUnknown(client_order_id, _)) => {
+                assert_eq!(client_order_id, "b")
+            }
+            other => panic!("expected an unknown outcome, got {:?}", other),
+        }
+        assert!(matched[2]
+            .1
+            .as_ref()
+            .unwrap_err()
+            .to_string()
+            .contains("Market closed"));
+    }
+
+    #[test]
+    fn test_batch_create_order_finds_duplicate_client_order_ids() {
+        let payload = |client_order_id: Option<&str>| {
+            CreateOrderPayload::from_field(OrderCreationField {
+                action: Action::Buy,
+                client_order_id: client_order_id.map(str::to_string),
+                count: 1,
+                side: Side::Yes,
+                ticker: "TEST".to_string(),
+                input_type: OrderType::Limit,
+                buy_max_cost: None,
+                expiration_ts: None,
+                no_price: None,
+                sell_position_floor: None,
+                yes_price: Some(50),
+                order_group_id: None,
+            })
+            .unwrap()
+        };
+
+        let unique = vec![
+            (0, payload(Some("a"))),
+            (1, payload(None)),
+            (2, payload(None)),
+        ];
+        assert_eq!(duplicate_client_order_id(&unique), None);
+
+        let duplicated = vec![
+            (0, payload(Some("a"))),
+            (1, payload(None)),
+            (2, payload(Some("a"))),
+        ];
+        assert_eq!(duplicate_client_order_id(&duplicated), Some("a"));
+    }
+
+    #[test]
+    fn test_batch_cancel_order_response_names_refused_orders() {
+        let json = r#"{"orders":[{"order_id":"abc","order":null,"error":{"code":"not_found","message":"Order not found"}}]}"#;
+        let response = serde_json::from_str::<BatchCancelOrderResponse>(json).unwrap();
+
+        let error = response
+            .orders
+            .into_iter()
+            .next()
+            .unwrap()
+            .into_result()
+            .unwrap_err();
+        assert!(error.to_string().contains("abc"));
+        assert!(error.to_string().contains("Order not found"));
+    }
 }
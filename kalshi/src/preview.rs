@@ -0,0 +1,439 @@
+use crate::concentration::ConcentrationLimits;
+use crate::kalshi_error::*;
+use crate::market::Category;
+use crate::payoff::CONTRACT_PAYOUT_CENTS;
+use crate::portfolio::{Action, MarketPosition, OrderCreationField, OrderType, Side};
+use std::collections::HashMap;
+
+/// The exchange's taker fee rate, in percent, applied to `contracts × price × (1 − price)`.
+const TAKER_FEE_PERCENT: i64 = 7;
+
+/// The projected effect of an order on the portfolio, as returned by [`preview_order`].
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderPreview {
+    /// The position in the order's market once the order fills completely.
+    pub position: MarketPosition,
+    /// The most the order costs, in cents.
+    pub cost: i64,
+    /// The taker fee the order pays if it fills completely against resting orders, in cents.
+    pub estimated_fees: i64,
+    /// The exposure of the projected portfolio to each event, by event ticker.
+    pub exposure_by_event: HashMap<String, i64>,
+    /// The exposure of the projected portfolio to each category.
+    pub exposure_by_category: HashMap<Category, i64>,
+    /// The concentration cap the order would breach, if any, as reported by
+    /// [`ConcentrationLimits::check_positions`].
+    pub limit_breach: Option<String>,
+}
+
+/// The projected portfolio after a whole list of orders, as returned by [`preview_orders`].
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanPreview {
+    /// Every position once all orders fill completely, including markets the plan opens.
+    pub positions: Vec<MarketPosition>,
+    /// The most the orders cost together, in cents.
+    pub cost: i64,
+    /// The taker fees the orders pay if they all fill completely against resting orders, in cents.
+    pub estimated_fees: i64,
+    /// The exposure of the projected portfolio to each event, by event ticker.
+    pub exposure_by_event: HashMap<String, i64>,
+    /// The exposure of the projected portfolio to each category.
+    pub exposure_by_category: HashMap<Category, i64>,
+    /// Every concentration cap the plan as a whole would breach. Empty if the plan is within limits.
+    pub limit_breaches: Vec<String>,
+}
+
+/// Estimates the taker fee of trading `count` contracts at `price` cents, in cents.
+///
+/// The exchange charges 7% of `count × price × (1 − price)` in dollars, rounded up to the next cent.
+/// Some series charge a different rate; the fee actually charged is reported on the order.
+///
+/// # Arguments
+///
+/// * `count` - The number of contracts traded.
+/// * `price` - The price of each contract, in cents.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(estimate_taker_fee(100, 50), 175);
+/// ```
+pub fn estimate_taker_fee(count: i64, price: i64) -> i64 {
+    let price = price.clamp(0, CONTRACT_PAYOUT_CENTS);
+    let scaled = TAKER_FEE_PERCENT
+        .saturating_mul(count.max(0))
+        .saturating_mul(price)
+        .saturating_mul(CONTRACT_PAYOUT_CENTS - price);
+    let divisor = 100 * CONTRACT_PAYOUT_CENTS;
+
+    scaled / divisor + i64::from(scaled % divisor != 0)
+}
+
+/// Projects the effect of an order on the portfolio without sending anything to the exchange.
+///
+/// The order is assumed to fill completely at its limit price. Market orders are assumed to fill at
+/// their `buy_max_cost`, or at the worst possible price without one, so the preview is an upper bound.
+/// Selling contracts of one side is treated like buying the other side at the complementary price,
+/// which closes the position first, as the exchange does.
+///
+/// # Arguments
+///
+/// * `order` - The order to preview.
+/// * `positions` - The positions currently held, e.g. from [`Kalshi::get_user_positions`](crate::Kalshi::get_user_positions).
+/// * `limits` - The concentration limits the order is checked against.
+/// * `equity` - The account's equity in cents, e.g. from [`marked_equity`](crate::marked_equity).
+///
+/// # Returns
+///
+/// - `Ok(OrderPreview)`: The projected position, cost, fees, exposures and limit breach.
+/// - `Err(KalshiError)`: A `UserInputError` if the order's prices are invalid.
+///
+/// # Example
+///
+/// ```
+/// let preview = preview_order(&order, &positions, &limits, equity)?;
+/// if let Some(breach) = &preview.limit_breach {
+///     println!("not sending: {}", breach);
+/// }
+/// ```
+pub fn preview_order(
+    order: &OrderCreationField,
+    positions: &[MarketPosition],
+    limits: &ConcentrationLimits,
+    equity: i64,
+) -> Result<OrderPreview, KalshiError> {
+    let mut projected = positions.to_vec();
+    let (cost, estimated_fees) = apply_order(&mut projected, order)?;
+
+    let position = projected
+        .iter()
+        .find(|position| position.ticker == order.ticker)
+        .cloned()
+        .unwrap_or_else(|| flat_position(&order.ticker));
+
+    Ok(OrderPreview {
+        position,
+        cost,
+        estimated_fees,
+        exposure_by_event: limits.exposure_by_event(&projected),
+        exposure_by_category: limits.exposure_by_category(&projected),
+        limit_breach: limits
+            .check_positions(&order.ticker, positions, &projected, equity)
+            .err()
+            .map(|e| e.to_string()),
+    })
+}
+
+/// Projects the portfolio after a list of orders, such as a rebalance plan, without sending anything.
+///
+/// The orders are applied in turn with the same assumptions as [`preview_order`], so later orders see the
+/// positions opened or closed by earlier ones. Limits are checked once, from the current positions to the
+/// projected portfolio, so a plan that sells one market to buy another within a capped category passes
+/// even if its buy would breach the cap on its own.
+///
+/// # Arguments
+///
+/// * `orders` - The orders of the plan.
+/// * `positions` - The positions currently held.
+/// * `limits` - The concentration limits the plan is checked against.
+/// * `equity` - The account's equity in cents.
+///
+/// # Returns
+///
+/// - `Ok(PlanPreview)`: The projected portfolio, total cost and fees, exposures and limit breaches.
+/// - `Err(KalshiError)`: A `UserInputError` naming the first order whose prices are invalid.
+///
+/// # Example
+///
+/// ```
+/// let preview = preview_orders(&plan, &positions, &limits, equity)?;
+/// if preview.limit_breaches.is_empty() {
+///     kalshi_instance.batch_create_order(plan).await?;
+/// }
+/// ```
+pub fn preview_orders(
+    orders: &[OrderCreationField],
+    positions: &[MarketPosition],
+    limits: &ConcentrationLimits,
+    equity: i64,
+) -> Result<PlanPreview, KalshiError> {
+    let mut projected = positions.to_vec();
+    let mut cost: i64 = 0;
+    let mut estimated_fees: i64 = 0;
+
+    for (index, order) in orders.iter().enumerate() {
+        let (order_cost, order_fees) = apply_order(&mut projected, order).map_err(|e| {
+            KalshiError::UserInputError(format!("Order {} of the plan is invalid: {}", index, e))
+        })?;
+        cost = cost.saturating_add(order_cost);
+        estimated_fees = estimated_fees.saturating_add(order_fees);
+    }
+
+    let mut limit_breaches: Vec<String> = Vec::new();
+    for order in orders {
+        if let Err(e) = limits.check_positions(&order.ticker, positions, &projected, equity) {
+            let breach = e.to_string();
+            if !limit_breaches.contains(&breach) {
+                limit_breaches.push(breach);
+            }
+        }
+    }
+
+    Ok(PlanPreview {
+        exposure_by_event: limits.exposure_by_event(&projected),
+        exposure_by_category: limits.exposure_by_category(&projected),
+        positions: projected,
+        cost,
+        estimated_fees,
+        limit_breaches,
+    })
+}
+
+// Applies a complete fill of the order to the positions, returning its cost and estimated fees.
+fn apply_order(
+    positions: &mut Vec<MarketPosition>,
+    order: &OrderCreationField,
+) -> Result<(i64, i64), KalshiError> {
+    let count = order.count.max(0);
+    let (side, price) = buy_side_price(order)?;
+    let cost = match (&order.input_type, order.action, order.buy_max_cost) {
+        (OrderType::Market, Action::Buy, Some(max_cost)) => max_cost.max(0),
+        _ => price.saturating_mul(count as i64),
+    };
+    let average_price = if count > 0 { cost / count as i64 } else { 0 };
+    let fees = estimate_taker_fee(count as i64, average_price);
+
+    let index = match positions
+        .iter()
+        .position(|position| position.ticker == order.ticker)
+    {
+        Some(index) => index,
+        None => {
+            positions.push(flat_position(&order.ticker));
+            positions.len() - 1
+        }
+    };
+    let position = &mut positions[index];
+
+    let delta = match side {
+        Side::Yes => count,
+        Side::No => -count,
+    };
+    let held = position.position;
+    if held == 0 || (held > 0) == (delta > 0) {
+        position.market_exposure = position.market_exposure.saturating_add(cost);
+    } else if count > 0 {
+        // The order closes the held contracts first, releasing their share of the exposure.
+        let closed = count.min(held.abs());
+        let remaining = (held.abs() - closed) as i64;
+        let opened = (count - closed) as i64;
+        position.market_exposure = position.market_exposure.saturating_mul(remaining)
+            / held.abs() as i64
+            + cost.saturating_mul(opened) / count as i64;
+    }
+    position.position = held.saturating_add(delta);
+    position.fees_paid = position.fees_paid.saturating_add(fees);
+    position.total_traded = position.total_traded.saturating_add(cost);
+
+    Ok((cost, fees))
+}
+
+// The side an order effectively buys and its price per contract on that side, in cents.
+fn buy_side_price(order: &OrderCreationField) -> Result<(Side, i64), KalshiError> {
+    let opposite = match order.side {
+        Side::Yes => Side::No,
+        Side::No => Side::Yes,
+    };
+
+    let price = match (&order.input_type, order.yes_price, order.no_price) {
+        (OrderType::Limit, Some(_), Some(_)) | (OrderType::Limit, None, None) => {
+            return Err(KalshiError::UserInputError(
+                "Must provide exactly one of no_price and yes_price for a limit order".to_string(),
+            ));
+        }
+        (OrderType::Limit, Some(yes_price), None) => {
+            let yes_price = check_limit_price(yes_price, "yes_price")?;
+            match order.side {
+                Side::Yes => yes_price,
+                Side::No => CONTRACT_PAYOUT_CENTS - yes_price,
+            }
+        }
+        (OrderType::Limit, None, Some(no_price)) => {
+            let no_price = check_limit_price(no_price, "no_price")?;
+            match order.side {
+                Side::Yes => CONTRACT_PAYOUT_CENTS - no_price,
+                Side::No => no_price,
+            }
+        }
+        // A market order can fill at any price: buying at the payout, or selling for nothing.
+        (OrderType::Market, _, _) => match order.action {
+            Action::Buy => CONTRACT_PAYOUT_CENTS,
+            Action::Sell => 0,
+        },
+    };
+
+    Ok(match order.action {
+        Action::Buy => (order.side, price),
+        Action::Sell => (opposite, CONTRACT_PAYOUT_CENTS - price),
+    })
+}
+
+// A limit price must leave both sides of the contract a price the exchange accepts, 1 to 99 cents.
+fn check_limit_price(price: i64, name: &str) -> Result<i64, KalshiError> {
+    if (1..CONTRACT_PAYOUT_CENTS).contains(&price) {
+        Ok(price)
+    } else {
+        Err(KalshiError::UserInputError(format!(
+            "{} must be between 1 and {} cents, got {}",
+            name,
+            CONTRACT_PAYOUT_CENTS - 1,
+            price
+        )))
+    }
+}
+
+fn flat_position(ticker: &str) -> MarketPosition {
+    MarketPosition {
+        fees_paid: 0,
+        market_exposure: 0,
+        position: 0,
+        realized_pnl: 0,
+        resting_orders_count: 0,
+        ticker: ticker.to_string(),
+        total_traded: 0,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::concentration::MarketGroup;
+    use crate::fixtures::position;
+
+    fn order(action: Action, side: Side, count: i32, yes_price: i64) -> OrderCreationField {
+        OrderCreationField {
+            action,
+            client_order_id: None,
+            count,
+            side,
+            ticker: "NY-1".to_string(),
+            input_type: OrderType::Limit,
+            buy_max_cost: None,
+            expiration_ts: None,
+            no_price: None,
+            sell_position_floor: None,
+            yes_price: Some(yes_price),
+            order_group_id: None,
+        }
+    }
+
+    #[test]
+    fn test_preview_order_projects_position_fees_and_breach() {
+        let mut limits = ConcentrationLimits::new().with_series_cap("KXHIGHNY", 0.1);
+        limits.add_market(
+            "NY-1",
+            MarketGroup {
+                event_ticker: "NY".to_string(),
+                series_ticker: "KXHIGHNY".to_string(),
+                category: Category::ClimateAndWeather,
+            },
+        );
+        let positions = [position("NY-1", 10, 400)];
+
+        assert_eq!(estimate_taker_fee(100, 50), 175);
+        assert_eq!(estimate_taker_fee(1, 50), 2);
+
+        // Buying 10 more 'Yes' at 50 cents: 900 of exposure against a 1_000 cap.
+        let buy = preview_order(
+            &order(Action::Buy, Side::Yes, 10, 50),
+            &positions,
+            &limits,
+            10_000,
+        )
+        .unwrap();
+        assert_eq!(
+            (buy.position.position, buy.position.market_exposure),
+            (20, 900)
+        );
+        assert_eq!((buy.cost, buy.estimated_fees), (500, 18));
+        assert_eq!(buy.exposure_by_event.get("NY"), Some(&900));
+        assert_eq!(buy.limit_breach, None);
+
+        // Selling 15 'Yes' at 60 cents closes the 10 held and opens 5 'No' at 40 cents.
+        let sell = preview_order(
+            &order(Action::Sell, Side::Yes, 15, 60),
+            &positions,
+            &limits,
+            10_000,
+        )
+        .unwrap();
+        assert_eq!(
+            (sell.position.position, sell.position.market_exposure),
+            (-5, 200)
+        );
+
+        let big = preview_order(
+            &order(Action::Buy, Side::Yes, 20, 50),
+            &positions,
+            &limits,
+            10_000,
+        )
+        .unwrap();
+        assert!(big.limit_breach.is_some());
+
+        for yes_price in [0, 100, 150] {
+            assert!(preview_order(
+                &order(Action::Buy, Side::No, 10, yes_price),
+                &positions,
+                &limits,
+                10_000,
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn test_preview_orders_checks_the_plan_as_a_whole() {
+        let group = |event: &str| MarketGroup {
+            event_ticker: event.to_string(),
+            series_ticker: "KXHIGHNY".to_string(),
+            category: Category::ClimateAndWeather,
+        };
+        let mut limits = ConcentrationLimits::new().with_series_cap("KXHIGHNY", 0.1);
+        limits.add_market("NY-1", group("NY"));
+        limits.add_market("NY-2", group("NY2"));
+        let positions = [position("NY-1", 10, 800)];
+        let rotate = |ticker: &str, action: Action, yes_price: i64| OrderCreationField {
+            ticker: ticker.to_string(),
+            ..order(action, Side::Yes, 10, yes_price)
+        };
+
+        // Moving the 800 of exposure from NY-1 to NY-2 stays within the 1_000 series cap.
+        let plan = [
+            rotate("NY-1", Action::Sell, 80),
+            rotate("NY-2", Action::Buy, 60),
+        ];
+        let preview = preview_orders(&plan, &positions, &limits, 10_000).unwrap();
+        assert!(preview.limit_breaches.is_empty());
+        assert_eq!(preview.cost, 200 + 600);
+        assert_eq!(preview.exposure_by_event.get("NY"), Some(&0));
+        assert_eq!(preview.exposure_by_event.get("NY2"), Some(&600));
+        assert_eq!(preview.positions.len(), 2);
+
+        // Buying without selling breaches it, reported once for both orders of the series.
+        let plan = [
+            rotate("NY-2", Action::Buy, 60),
+            rotate("NY-2", Action::Buy, 60),
+        ];
+        let preview = preview_orders(&plan, &positions, &limits, 10_000).unwrap();
+        assert_eq!(preview.limit_breaches.len(), 1);
+
+        let invalid = OrderCreationField {
+            no_price: Some(40),
+            ..rotate("NY-2", Action::Buy, 60)
+        };
+        assert!(preview_orders(&[invalid], &positions, &limits, 10_000).is_err());
+    }
+}
@@ -0,0 +1,247 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A price or amount of money, in cents.
+///
+/// Serialized as the plain integer the exchange uses, so it can replace raw cent fields without
+/// changing the wire format. Arithmetic goes through the `checked_*` methods, so an overflowing amount
+/// is never silently wrapped or clamped.
+///
+/// # Example
+///
+/// ```
+/// use kalshi::Cents;
+/// let spread = Cents::new(47).checked_sub(Cents::new(45));
+/// assert_eq!(spread, Some(Cents::new(2)));
+/// ```
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Deserialize, Serialize,
+)]
+#[serde(transparent)]
+pub struct Cents(pub i64);
+
+impl Cents {
+    /// Zero cents.
+    pub const ZERO: Cents = Cents(0);
+
+    /// Creates an amount of `cents`.
+    pub fn new(cents: i64) -> Cents {
+        Cents(cents)
+    }
+
+    /// Returns the amount as a number of cents.
+    pub fn value(self) -> i64 {
+        self.0
+    }
+
+    /// Returns the amount in dollars, e.g. for charts or ratios. Use [`Cents`] itself for arithmetic.
+    pub fn as_dollars(self) -> f64 {
+        self.0 as f64 / 100.0
+    }
+
+    /// Returns the amount formatted as dollars, e.g. `$1,234.50`. See [`MoneyFormat`] for other formats.
+    pub fn to_display(self) -> String {
+        MoneyFormat::dollars().format(self)
+    }
+
+    /// Adds two amounts, returning `None` on overflow.
+    pub fn checked_add(self, other: Cents) -> Option<Cents> {
+        self.0.checked_add(other.0).map(Cents)
+    }
+
+    /// Subtracts `other` from this amount, returning `None` on overflow.
+    pub fn checked_sub(self, other: Cents) -> Option<Cents> {
+        self.0.checked_sub(other.0).map(Cents)
+    }
+
+    /// Returns the cost of `count` contracts at this price, or `None` on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalshi::Cents;
+    /// assert_eq!(Cents::new(45).checked_mul_count(10), Some(Cents::new(450)));
+    /// assert_eq!(Cents::new(i64::MAX).checked_mul_count(2), None);
+    /// ```
+    pub fn checked_mul_count(self, count: i64) -> Option<Cents> {
+        self.0.checked_mul(count).map(Cents)
+    }
+}
+
+impl From<i64> for Cents {
+    fn from(cents: i64) -> Self {
+        Cents(cents)
+    }
+}
+
+impl From<i32> for Cents {
+    fn from(cents: i32) -> Self {
+        Cents(cents as i64)
+    }
+}
+
+/// How [`MoneyFormat`] renders an amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoneyUnit {
+    /// A dollar amount with two decimals and a `$` sign, e.g. `-$1,234.50`.
+    Dollars,
+    /// A raw number of cents, e.g. `-123,450`.
+    Cents,
+}
+
+/// Formats amounts of money for reports and exports.
+///
+/// The separators are explicit rather than taken from the system locale, so a report renders the
+/// same wherever it is generated. Create one format per export and reuse it for every amount.
+///
+/// # Example
+///
+/// ```
+/// use kalshi::{Cents, MoneyFormat};
+/// assert_eq!(MoneyFormat::dollars().format(Cents::new(123450)), "$1,234.50");
+/// assert_eq!(MoneyFormat::cents().format(Cents::new(-123450)), "-123,450");
+///
+/// let european = MoneyFormat::dollars()
+///     .with_thousands_separator(Some('.'))
+///     .with_decimal_separator(',');
+/// assert_eq!(european.format(Cents::new(123450)), "$1.234,50");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MoneyFormat {
+    unit: MoneyUnit,
+    thousands_separator: Option<char>,
+    decimal_separator: char,
+}
+
+impl MoneyFormat {
+    /// Formats amounts in dollars, with `,` between thousands and `.` before the cents.
+    pub fn dollars() -> MoneyFormat {
+        MoneyFormat {
+            unit: MoneyUnit::Dollars,
+            thousands_separator: Some(','),
+            decimal_separator: '.',
+        }
+    }
+
+    /// Formats amounts as raw cents, with `,` between thousands.
+    pub fn cents() -> MoneyFormat {
+        MoneyFormat {
+            unit: MoneyUnit::Cents,
+            ..MoneyFormat::dollars()
+        }
+    }
+
+    /// Sets the separator between groups of thousands, or `None` to not group digits.
+    pub fn with_thousands_separator(mut self, separator: Option<char>) -> MoneyFormat {
+        self.thousands_separator = separator;
+        self
+    }
+
+    /// Sets the separator between dollars and cents. Unused when formatting raw cents.
+    pub fn with_decimal_separator(mut self, separator: char) -> MoneyFormat {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Returns the unit amounts are rendered in.
+    pub fn unit(&self) -> MoneyUnit {
+        self.unit
+    }
+
+    /// Formats `amount` as a string.
+    pub fn format(&self, amount: Cents) -> String {
+        let mut formatted = String::new();
+        // Writing to a `String` cannot fail.
+        let _ = self.write(&mut formatted, amount);
+        formatted
+    }
+
+    /// Writes `amount` to `out`, e.g. a CSV row being built, without an intermediate allocation.
+    pub fn write<W: fmt::Write>(&self, out: &mut W, amount: Cents) -> fmt::Result {
+        if amount.0 < 0 {
+            out.write_char('-')?;
+        }
+        let magnitude = amount.0.unsigned_abs();
+
+        match self.unit {
+            MoneyUnit::Dollars => {
+                out.write_char('$')?;
+                self.write_grouped(out, magnitude / 100)?;
+                out.write_char(self.decimal_separator)?;
+                write!(out, "{:02}", magnitude % 100)
+            }
+            MoneyUnit::Cents => self.write_grouped(out, magnitude),
+        }
+    }
+
+    fn write_grouped<W: fmt::Write>(&self, out: &mut W, mut value: u64) -> fmt::Result {
+        // `u64::MAX` has 20 digits. They are filled in from the end, least significant first.
+        let mut buf = [0u8; 20];
+        let mut start = buf.len();
+        loop {
+            start -= 1;
+            buf[start] = b'0' + (value % 10) as u8;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+        let digits = &buf[start..];
+
+        for (index, digit) in digits.iter().enumerate() {
+            if index > 0 && (digits.len() - index) % 3 == 0 {
+                if let Some(separator) = self.thousands_separator {
+                    out.write_char(separator)?;
+                }
+            }
+            out.write_char(char::from(*digit))?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for MoneyFormat {
+    fn default() -> Self {
+        MoneyFormat::dollars()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Cents;
+
+    #[test]
+    fn test_checked_arithmetic_detects_overflow() {
+        assert_eq!(Cents::new(45).checked_mul_count(-3), Some(Cents::new(-135)));
+        assert_eq!(Cents::new(i64::MAX / 2 + 1).checked_mul_count(2), None);
+        assert_eq!(Cents::new(i64::MAX).checked_add(Cents::new(1)), None);
+        assert_eq!(Cents::new(i64::MIN).checked_sub(Cents::new(1)), None);
+        assert_eq!(
+            Cents::new(10).checked_sub(Cents::new(15)),
+            Some(Cents::new(-5))
+        );
+    }
+
+    #[test]
+    fn test_money_format_and_dollar_conversions() {
+        use super::MoneyFormat;
+
+        let dollars = MoneyFormat::dollars();
+        assert_eq!(dollars.format(Cents::new(5)), "$0.05");
+        assert_eq!(dollars.format(Cents::new(-123_456_789)), "-$1,234,567.89");
+        assert_eq!(
+            dollars
+                .with_thousands_separator(None)
+                .format(Cents::new(100_000)),
+            "$1000.00"
+        );
+        assert_eq!(MoneyFormat::cents().format(Cents::new(999)), "999");
+        assert_eq!(MoneyFormat::cents().format(Cents::new(0)), "0");
+        assert_eq!(Cents::new(123_450).to_display(), "$1,234.50");
+        assert_eq!(Cents::new(-1_250).as_dollars(), -12.5);
+        assert_eq!(
+            MoneyFormat::cents().format(Cents::new(i64::MIN)),
+            "-9,223,372,036,854,775,808"
+        );
+    }
+}
@@ -0,0 +1,417 @@
+use super::{Authenticated, Environment, Kalshi};
+use crate::kalshi_error::*;
+use crate::market::Market;
+use crate::payoff::{marked_equity, MarkRule, CONTRACT_PAYOUT_CENTS};
+use crate::portfolio::{
+    Action, BatchOutcome, MarketPosition, Order, OrderCreationField, OrderType, Side,
+};
+use crate::price::Cents;
+use std::collections::HashMap;
+
+/// How aggressively [`Kalshi::rebalance_to`] prices the orders that move positions to their targets.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Aggressiveness {
+    /// Limit orders joining the best bid of the side bought. Cheapest, but may not fill.
+    #[default]
+    Passive,
+    /// Limit orders at the best ask of the side bought, filling against the resting orders at the touch.
+    Cross,
+    /// Market orders, filling at whatever price the book offers.
+    Market,
+}
+
+/// The size a position should be rebalanced to.
+///
+/// Positive values hold 'Yes' contracts and negative values hold 'No' contracts. Notional and equity
+/// targets are converted to contracts at the price the rebalancing order pays, see [`target_contracts`].
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PositionTarget {
+    /// A number of contracts.
+    Contracts(i32),
+    /// The amount the position should cost.
+    Notional(Cents),
+    /// The share of the account's equity the position should cost, e.g. `0.05` for 5% in 'Yes'.
+    EquityFraction(f64),
+}
+
+impl From<i32> for PositionTarget {
+    fn from(contracts: i32) -> Self {
+        PositionTarget::Contracts(contracts)
+    }
+}
+
+/// How notional and equity targets are rounded to whole contracts.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContractRounding {
+    /// Toward zero, so the position never costs more than its target.
+    #[default]
+    Down,
+    /// To the nearest whole contract.
+    Nearest,
+    /// Away from zero, so the position costs at least its target.
+    Up,
+}
+
+/// Configuration for [`Kalshi::rebalance_to`].
+///
+/// The default sends passive orders, rounds targets down and marks positions at their best bid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RebalanceConfig {
+    /// How the orders are priced.
+    pub aggressiveness: Aggressiveness,
+    /// How notional and equity targets are rounded to whole contracts.
+    pub rounding: ContractRounding,
+    /// How open positions are valued when sizing equity targets.
+    pub mark_rule: MarkRule,
+}
+
+impl Default for RebalanceConfig {
+    fn default() -> Self {
+        RebalanceConfig {
+            aggressiveness: Aggressiveness::default(),
+            rounding: ContractRounding::default(),
+            mark_rule: MarkRule::ConservativeBid,
+        }
+    }
+}
+
+impl<E: Environment> Kalshi<Authenticated, E> {
+    /// Moves the account's positions to target sizes, sending the orders that close the gap.
+    ///
+    /// Targets are net 'Yes' sizes: positive values hold 'Yes', negative values hold 'No' and zero closes
+    /// the position. Markets without a target are left untouched. Targets are converted to contracts with
+    /// [`target_contracts`], the orders are planned with [`plan_rebalance`] from the current positions and
+    /// quotes, and sent with [`batch_create_order`](Kalshi::batch_create_order).
+    ///
+    /// Equity targets are sized against the balance plus every open position marked at the configured
+    /// [`MarkRule`], which fetches the balance and the markets of all open positions.
+    ///
+    /// # Arguments
+    ///
+    /// * `targets` - The target position of each market, by ticker.
+    /// * `config` - How the orders are priced, how targets are rounded and how positions are marked.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(BatchOutcome<Order>)`: The outcome of each order sent. Empty if every position is on target.
+    /// - `Err(KalshiError)`: An error if the positions or markets can't be fetched, if a market has no
+    ///   quote to price its order at, or if an open position can't be marked. Nothing is sent in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let targets = HashMap::from([
+    ///     ("HIGHNY-23NOV13-T51".to_string(), PositionTarget::Contracts(20)),
+    ///     ("HIGHNY-23NOV13-T53".to_string(), PositionTarget::EquityFraction(-0.05)),
+    /// ]);
+    /// let config = RebalanceConfig {
+    ///     aggressiveness: Aggressiveness::Cross,
+    ///     mark_rule: MarkRule::Mid,
+    ///     ..RebalanceConfig::default()
+    /// };
+    /// let outcome = kalshi_instance.rebalance_to(&targets, &config).await?;
+    /// println!("{} orders sent", outcome.len());
+    /// ```
+    ///
+    pub async fn rebalance_to(
+        &self,
+        targets: &HashMap<String, PositionTarget>,
+        config: &RebalanceConfig,
+    ) -> Result<BatchOutcome<Order>, KalshiError> {
+        self.ensure_writable("rebalance_to")?;
+
+        let needs_equity = targets
+            .values()
+            .any(|target| matches!(target, PositionTarget::EquityFraction(_)));
+
+        let positions = self.get_all_market_positions().await?;
+        let mut tickers: Vec<String> = targets.keys().cloned().collect();
+        if needs_equity {
+            tickers.extend(
+                positions
+                    .iter()
+                    .filter(|position| position.position != 0)
+                    .map(|position| position.ticker.clone()),
+            );
+        }
+        let markets = self.get_markets_batch(&tickers).await?;
+
+        let equity = if needs_equity {
+            let balance = self.get_balance().await?;
+            marked_equity(balance, &positions, &markets, config.mark_rule).ok_or_else(|| {
+                KalshiError::InternalError(
+                    "Couldn't mark every open position to size equity targets".to_string(),
+                )
+            })?
+        } else {
+            0
+        };
+
+        let contracts = target_contracts(
+            targets,
+            &markets,
+            equity,
+            config.aggressiveness,
+            config.rounding,
+        )?;
+        let orders = plan_rebalance(&contracts, &positions, &markets, config.aggressiveness)?;
+        if orders.is_empty() {
+            return Ok(BatchOutcome::from(Vec::new()));
+        }
+        self.batch_create_order(orders).await
+    }
+}
+
+/// Converts position targets to whole contracts at the price the rebalancing orders pay.
+///
+/// A target is priced on the side it holds: at its best bid for [`Aggressiveness::Passive`], at its best ask
+/// otherwise. For example $50 of 'Yes' in a market asking 42 cents is 119 contracts rounded down.
+///
+/// # Arguments
+///
+/// * `targets` - The target position of each market, by ticker.
+/// * `markets` - The markets of the targets, by ticker.
+/// * `equity` - The account's equity in cents, used by [`PositionTarget::EquityFraction`] targets.
+/// * `aggressiveness` - How the orders are priced.
+/// * `rounding` - How targets are rounded to whole contracts.
+///
+/// # Returns
+///
+/// - `Ok(HashMap<String, i32>)`: The target of each market in net 'Yes' contracts.
+/// - `Err(KalshiError)`: A `UserInputError` if a notional or equity target's market is missing or has no quote.
+///
+pub fn target_contracts(
+    targets: &HashMap<String, PositionTarget>,
+    markets: &HashMap<String, Market>,
+    equity: i64,
+    aggressiveness: Aggressiveness,
+    rounding: ContractRounding,
+) -> Result<HashMap<String, i32>, KalshiError> {
+    targets
+        .iter()
+        .map(|(ticker, target)| {
+            let notional = match *target {
+                PositionTarget::Contracts(contracts) => return Ok((ticker.clone(), contracts)),
+                PositionTarget::Notional(notional) => notional.value() as f64,
+                PositionTarget::EquityFraction(fraction) => fraction * equity as f64,
+            };
+            if notional == 0.0 {
+                return Ok((ticker.clone(), 0));
+            }
+
+            let side = if notional > 0.0 { Side::Yes } else { Side::No };
+            let market = markets.get(ticker).ok_or_else(|| {
+                KalshiError::UserInputError(format!(
+                    "Market {} is missing, can't size its target",
+                    ticker
+                ))
+            })?;
+            let price = quote(market, side, aggressiveness);
+            if price <= 0 || price >= CONTRACT_PAYOUT_CENTS {
+                return Err(KalshiError::UserInputError(format!(
+                    "Market {} has no quote to size a '{:?}' target at",
+                    ticker, side
+                )));
+            }
+
+            let contracts = notional / price as f64;
+            let rounded = match rounding {
+                ContractRounding::Down => contracts.trunc(),
+                ContractRounding::Nearest => contracts.round(),
+                ContractRounding::Up => contracts.abs().ceil() * contracts.signum(),
+            };
+            Ok((ticker.clone(), rounded as i32))
+        })
+        .collect()
+}
+
+/// Plans the orders that move positions to their targets, without sending anything.
+///
+/// Each market off target gets a single buy order for the difference: buying 'Yes' closes held 'No'
+/// contracts before opening 'Yes' ones and vice versa, so the order also covers a change of side.
+/// The plan can be checked with [`preview_orders`](crate::preview_orders) before it is sent.
+///
+/// # Arguments
+///
+/// * `targets` - The target position of each market, by ticker, in net 'Yes' contracts.
+/// * `positions` - The positions currently held.
+/// * `markets` - The markets of the targets, by ticker, e.g. from [`Kalshi::get_markets_batch`].
+/// * `aggressiveness` - How the orders are priced.
+///
+/// # Returns
+///
+/// - `Ok(Vec<OrderCreationField>)`: The orders, sorted by ticker. Empty if every position is on target.
+/// - `Err(KalshiError)`: A `UserInputError` if a market is missing or has no quote to price its order at.
+///
+pub fn plan_rebalance(
+    targets: &HashMap<String, i32>,
+    positions: &[MarketPosition],
+    markets: &HashMap<String, Market>,
+    aggressiveness: Aggressiveness,
+) -> Result<Vec<OrderCreationField>, KalshiError> {
+    let held: HashMap<&str, i32> = positions
+        .iter()
+        .map(|position| (position.ticker.as_str(), position.position))
+        .collect();
+
+    let mut tickers: Vec<&String> = targets.keys().collect();
+    tickers.sort();
+
+    let mut orders = Vec::new();
+    for ticker in tickers {
+        let gap = targets[ticker].saturating_sub(held.get(ticker.as_str()).copied().unwrap_or(0));
+        let side = match gap {
+            0 => continue,
+            gap if gap > 0 => Side::Yes,
+            _ => Side::No,
+        };
+
+        let market = markets.get(ticker).ok_or_else(|| {
+            KalshiError::UserInputError(format!(
+                "Market {} is missing, can't price its order",
+                ticker
+            ))
+        })?;
+
+        let (input_type, price) = match aggressiveness {
+            Aggressiveness::Market => (OrderType::Market, None),
+            Aggressiveness::Passive | Aggressiveness::Cross => {
+                let price = quote(market, side, aggressiveness);
+                if price <= 0 || price >= CONTRACT_PAYOUT_CENTS {
+                    return Err(KalshiError::UserInputError(format!(
+                        "Market {} has no quote to price a {:?} order for '{:?}' at",
+                        ticker, aggressiveness, side
+                    )));
+                }
+                (OrderType::Limit, Some(price))
+            }
+        };
+
+        let (yes_price, no_price) = match side {
+            Side::Yes => (price, None),
+            Side::No => (None, price),
+        };
+        orders.push(OrderCreationField {
+            action: Action::Buy,
+            client_order_id: None,
+            count: gap.saturating_abs(),
+            side,
+            ticker: ticker.clone(),
+            input_type,
+            buy_max_cost: None,
+            expiration_ts: None,
+            no_price,
+            sell_position_floor: None,
+            yes_price,
+            order_group_id: None,
+        });
+    }
+
+    Ok(orders)
+}
+
+// The price, in cents, of buying `side`: its best bid when passive, its best ask when crossing.
+fn quote(market: &Market, side: Side, aggressiveness: Aggressiveness) -> i64 {
+    match (side, aggressiveness) {
+        (Side::Yes, Aggressiveness::Passive) => market.yes_bid,
+        (Side::No, Aggressiveness::Passive) => market.no_bid,
+        (Side::Yes, _) => market.yes_ask,
+        (Side::No, _) => market.no_ask,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::{market, position};
+
+    #[test]
+    fn test_plan_rebalance_sends_one_order_per_gap() {
+        let markets: HashMap<String, Market> = ["A", "B", "C"]
+            .into_iter()
+            .map(|ticker| (ticker.to_string(), market(ticker)))
+            .collect();
+        let positions = [
+            position("A", 5, 0),
+            position("B", -3, 0),
+            position("C", 4, 0),
+            position("D", 9, 0),
+        ];
+        let targets = HashMap::from([
+            ("A".to_string(), 8),
+            ("B".to_string(), 2),
+            ("C".to_string(), 4),
+        ]);
+
+        let passive =
+            plan_rebalance(&targets, &positions, &markets, Aggressiveness::Passive).unwrap();
+        let summary: Vec<(&str, Side, i32, Option<i64>)> = passive
+            .iter()
+            .map(|order| {
+                (
+                    order.ticker.as_str(),
+                    order.side,
+                    order.count,
+                    order.yes_price,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("A", Side::Yes, 3, Some(40)), ("B", Side::Yes, 5, Some(40))]
+        );
+
+        let targets = HashMap::from([("A".to_string(), 0)]);
+        let cross = plan_rebalance(&targets, &positions, &markets, Aggressiveness::Cross).unwrap();
+        assert_eq!(
+            (cross[0].side, cross[0].count, cross[0].no_price),
+            (Side::No, 5, Some(60))
+        );
+
+        let missing = HashMap::from([("E".to_string(), 1)]);
+        assert!(plan_rebalance(&missing, &positions, &markets, Aggressiveness::Market).is_err());
+    }
+
+    #[test]
+    fn test_target_contracts_prices_the_side_held() {
+        let markets = HashMap::from([("A".to_string(), market("A"))]);
+        let contracts = |target: PositionTarget, aggressiveness, rounding| {
+            let targets = HashMap::from([("A".to_string(), target)]);
+            target_contracts(&targets, &markets, 100_000, aggressiveness, rounding).unwrap()["A"]
+        };
+
+        // $50 of 'Yes' at the 42 cent ask is 119.05 contracts.
+        let fifty = PositionTarget::Notional(Cents(5_000));
+        assert_eq!(
+            contracts(fifty, Aggressiveness::Cross, ContractRounding::Down),
+            119
+        );
+        assert_eq!(
+            contracts(fifty, Aggressiveness::Cross, ContractRounding::Up),
+            120
+        );
+        assert_eq!(
+            contracts(fifty, Aggressiveness::Passive, ContractRounding::Nearest),
+            125
+        );
+
+        // 3% of $1,000 in 'No' at the 60 cent ask.
+        let short = PositionTarget::EquityFraction(-0.03);
+        assert_eq!(
+            contracts(short, Aggressiveness::Market, ContractRounding::Down),
+            -50
+        );
+        assert_eq!(
+            contracts(
+                PositionTarget::from(7),
+                Aggressiveness::Passive,
+                ContractRounding::Up
+            ),
+            7
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::market::{LevelChange, Orderbook, Trade};
+use serde::{Deserialize, Serialize};
+
+/// One entry of a replay timeline built by [`replay_timeline`].
+///
+/// Serializes with a `type` tag, so a timeline written as JSON lines can be consumed by visualizers
+/// in any language.
+///
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReplayEvent {
+    /// The order book changed since the previous snapshot.
+    Book {
+        /// Unix timestamp of the snapshot, in seconds.
+        ts: i64,
+        /// Every level that changed. The first snapshot lists all of its levels as added.
+        changes: Vec<LevelChange>,
+    },
+    /// A trade printed.
+    Trade {
+        /// Unix timestamp of the trade, in seconds.
+        ts: i64,
+        /// The trade.
+        trade: Trade,
+    },
+}
+
+impl ReplayEvent {
+    /// Returns the Unix timestamp of the event, in seconds.
+    pub fn ts(&self) -> i64 {
+        match self {
+            ReplayEvent::Book { ts, .. } | ReplayEvent::Trade { ts, .. } => *ts,
+        }
+    }
+}
+
+/// Merges recorded order book snapshots and trades of a market into a single time-ordered timeline,
+/// e.g. to step through a post-mortem of an execution.
+///
+/// Each snapshot is replaced by its changes from the previous one, and snapshots that changed nothing are
+/// dropped. At equal timestamps book changes come before trades. Only available with the `chrono` feature
+/// enabled, which parses the trades' timestamps.
+///
+/// # Arguments
+///
+/// * `books` - Order book snapshots with their Unix timestamp in seconds, e.g. recorded by polling
+///   [`Kalshi::get_market_orderbook`](crate::Kalshi::get_market_orderbook). They don't have to be sorted.
+/// * `trades` - The trades of the same market, e.g. from [`Kalshi::get_trades`](crate::Kalshi::get_trades).
+///
+/// # Returns
+///
+/// The timeline, sorted by timestamp.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Write;
+/// let mut file = std::fs::File::create("replay.jsonl")?;
+/// for event in replay_timeline(books, trades) {
+///     writeln!(file, "{}", serde_json::to_string(&event)?)?;
+/// }
+/// ```
+///
+pub fn replay_timeline(mut books: Vec<(i64, Orderbook)>, trades: Vec<Trade>) -> Vec<ReplayEvent> {
+    books.sort_by_key(|(ts, _)| *ts);
+
+    let mut previous = Orderbook {
+        yes: Vec::new(),
+        no: Vec::new(),
+    };
+    let mut timeline = Vec::with_capacity(books.len() + trades.len());
+
+    for (ts, book) in books {
+        let changes = previous.diff(&book);
+        if !changes.is_empty() {
+            timeline.push(ReplayEvent::Book { ts, changes });
+        }
+        previous = book;
+    }
+
+    timeline.extend(trades.into_iter().map(|trade| ReplayEvent::Trade {
+        ts: trade.created_time.timestamp(),
+        trade,
+    }));
+
+    // The sort is stable, so book changes pushed first stay ahead of trades at the same timestamp.
+    timeline.sort_by_key(ReplayEvent::ts);
+    timeline
+}
+
+#[cfg(test)]
+mod test {
+    use super::{replay_timeline, ReplayEvent};
+    use crate::market::{Orderbook, Trade};
+
+    #[test]
+    fn test_replay_timeline_orders_book_changes_and_trades() {
+        let book = |json: &str| serde_json::from_str::<Orderbook>(json).unwrap();
+        let trade: Trade = serde_json::from_str(
+            r#"{"trade_id":"t1","taker_side":"yes","ticker":"EV-A","count":5,"yes_price":42,"no_price":58,
+                "created_time":"2023-11-14T22:13:30Z"}"#,
+        )
+        .unwrap();
+
+        let timeline = replay_timeline(
+            vec![
+                (1_700_000_010, book(r#"{"yes":[[42,5]],"no":null}"#)),
+                (1_700_000_000, book(r#"{"yes":[[42,5]],"no":null}"#)),
+                (1_700_000_020, book(r#"{"yes":null,"no":null}"#)),
+            ],
+            vec![trade],
+        );
+
+        let kinds: Vec<(i64, &str)> = timeline
+            .iter()
+            .map(|event| match event {
+                ReplayEvent::Book { ts, .. } => (*ts, "book"),
+                ReplayEvent::Trade { ts, .. } => (*ts, "trade"),
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (1_700_000_000, "book"),
+                (1_700_000_010, "trade"),
+                (1_700_000_020, "book"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use super::{Authenticated, Demo, Kalshi};
+use crate::kalshi_error::*;
+use crate::market::{Market, MarketStatusFilter};
+use crate::portfolio::{Action, Order, OrderType, Side};
+
+/// Configuration for [`Kalshi::seed_demo_orders`].
+///
+/// Orders are placed as 'Yes' limit buys far below the market so they rest on the book
+/// without filling.
+#[derive(Debug, Clone)]
+pub struct SeedConfig {
+    /// Number of markets to seed, picked from the most liquid open demo markets.
+    pub markets: usize,
+    /// Number of orders to place in each market.
+    pub orders_per_market: usize,
+    /// Number of contracts in each order.
+    pub count: i32,
+    /// Limit price of each order, in cents.
+    pub yes_price: i64,
+    /// Number of orders in each market to cancel after placing them, so the seeded
+    /// account has both resting and canceled order history.
+    pub cancel_per_market: usize,
+}
+
+impl Default for SeedConfig {
+    fn default() -> Self {
+        SeedConfig {
+            markets: 5,
+            orders_per_market: 2,
+            count: 1,
+            yes_price: 1,
+            cancel_per_market: 1,
+        }
+    }
+}
+
+/// The orders placed and canceled by [`Kalshi::seed_demo_orders`].
+#[derive(Debug, Default)]
+pub struct SeedReport {
+    /// Tickers of the markets that were seeded.
+    pub tickers: Vec<String>,
+    /// Orders that were placed and left resting.
+    pub resting: Vec<Order>,
+    /// Orders that were placed and then canceled.
+    pub canceled: Vec<Order>,
+}
+
+impl Kalshi<Authenticated, Demo> {
+    /// Seeds a demo account with resting and canceled test orders across liquid demo markets.
+    ///
+    /// Useful for integration testing downstream code against a realistic portfolio state.
+    /// Picks the open markets with the highest volume, places `orders_per_market` limit orders in each
+    /// and cancels `cancel_per_market` of them. Only available on instances restricted to the demo
+    /// environment, obtained from [`Kalshi::demo`] or [`into_environment`](Kalshi::into_environment).
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Which markets to seed and what orders to place.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(SeedReport)`: The orders that were placed and canceled.
+    /// - `Err(KalshiError)`: An error if there is an issue with a request. Orders placed before the error are left as they are.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let kalshi_instance = Kalshi::demo()
+    ///     .login("johndoe@example.com", "example_password")
+    ///     .await?;
+    /// let report = kalshi_instance.seed_demo_orders(&SeedConfig::default()).await.unwrap();
+    /// ```
+    ///
+    pub async fn seed_demo_orders(&self, config: &SeedConfig) -> Result<SeedReport, KalshiError> {
+        let (_, mut markets) = self
+            .get_multiple_markets(
+                Some(200),
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(MarketStatusFilter::Open),
+                None,
+            )
+            .await?;
+
+        markets.sort_by_key(|market: &Market| std::cmp::Reverse(market.volume));
+        markets.truncate(config.markets);
+
+        let mut report = SeedReport::default();
+
+        for market in markets {
+            for i in 0..config.orders_per_market {
+                let order = self
+                    .create_order(
+                        Action::Buy,
+                        None,
+                        config.count,
+                        Side::Yes,
+                        market.ticker.clone(),
+                        OrderType::Limit,
+                        None,
+                        None,
+                        None,
+                        None,
+                        Some(config.yes_price),
+                    )
+                    .await?;
+
+                if i < config.cancel_per_market {
+                    let (canceled, _) = self.cancel_order(&order.order_id).await?;
+                    report.canceled.push(canceled);
+                } else {
+                    report.resting.push(order);
+                }
+            }
+            report.tickers.push(market.ticker);
+        }
+
+        Ok(report)
+    }
+}
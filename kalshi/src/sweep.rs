@@ -0,0 +1,183 @@
+use super::{Authenticated, Environment, Kalshi};
+use crate::kalshi_error::*;
+use crate::portfolio::Order;
+use std::time::Duration;
+
+/// Number of orders requested per page while listing resting orders.
+const SWEEP_PAGE_SIZE: i32 = 200;
+
+/// Configuration for [`Kalshi::sweep_orphaned_orders`].
+///
+/// A resting order is orphaned if its `client_order_id` starts with none of `owned_prefixes`,
+/// or if it has rested for longer than `max_age`.
+/// Each rule only applies when configured, so the default configuration finds no orphans.
+#[derive(Debug, Clone, Default)]
+pub struct SweepConfig {
+    /// `client_order_id` prefixes of the strategies currently running. Empty disables the ownership check.
+    pub owned_prefixes: Vec<String>,
+    /// Only sweep orders in this market, if set.
+    pub ticker: Option<String>,
+    /// Orders resting for longer than this are orphaned whoever owns them.
+    ///
+    /// Only checked with the `chrono` feature enabled, which is needed to read order creation times.
+    /// Without it the field is ignored.
+    pub max_age: Option<Duration>,
+    /// Whether to cancel orphaned orders. When `false` they are only reported.
+    pub cancel: bool,
+}
+
+impl SweepConfig {
+    fn is_orphaned(&self, order: &Order) -> bool {
+        let unowned = !self.owned_prefixes.is_empty()
+            && !self
+                .owned_prefixes
+                .iter()
+                .any(|prefix| order.client_order_id.starts_with(prefix.as_str()));
+
+        unowned || self.is_stale(order)
+    }
+
+    #[cfg(feature = "chrono")]
+    fn is_stale(&self, order: &Order) -> bool {
+        match (self.max_age, &order.created_time) {
+            (Some(max_age), Some(created_time)) => {
+                let now = chrono::DateTime::<chrono::Utc>::from(std::time::SystemTime::now());
+                (now - *created_time)
+                    .to_std()
+                    .is_ok_and(|age| age > max_age)
+            }
+            _ => false,
+        }
+    }
+
+    #[cfg(not(feature = "chrono"))]
+    fn is_stale(&self, _order: &Order) -> bool {
+        false
+    }
+}
+
+/// The orphaned orders found by [`Kalshi::sweep_orphaned_orders`].
+#[derive(Debug, Default)]
+pub struct SweepReport {
+    /// Every resting order found to be orphaned.
+    pub orphaned: Vec<Order>,
+    /// Orphaned orders that were canceled, as returned by the exchange.
+    pub canceled: Vec<Order>,
+    /// Orphaned orders that could not be canceled, with the reason.
+    pub failed: Vec<(String, KalshiError)>,
+}
+
+impl<E: Environment> Kalshi<Authenticated, E> {
+    /// Finds resting orders not owned by any running strategy and cancels or reports them.
+    ///
+    /// Useful after a deploy or crash, when orders placed by a previous process would otherwise be
+    /// left resting with nothing managing them. Run it with `cancel: false` first to review what would be canceled.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Which orders count as orphaned and whether to cancel them.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(SweepReport)`: The orphaned orders, and which of them were canceled.
+    /// - `Err(KalshiError)`: An error if the resting orders could not be listed, or if cancellation
+    ///   was requested on a read-only instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let config = SweepConfig {
+    ///     owned_prefixes: vec!["mm-v2-".to_string()],
+    ///     cancel: true,
+    ///     ..SweepConfig::default()
+    /// };
+    /// let report = kalshi_instance.sweep_orphaned_orders(&config).await?;
+    /// println!("canceled {} orphaned orders", report.canceled.len());
+    /// ```
+    ///
+    pub async fn sweep_orphaned_orders(
+        &self,
+        config: &SweepConfig,
+    ) -> Result<SweepReport, KalshiError> {
+        let mut report = SweepReport::default();
+        let mut cursor = None;
+
+        loop {
+            let (next, orders) = self
+                .get_multiple_orders(
+                    config.ticker.clone(),
+                    None,
+                    None,
+                    None,
+                    Some("resting".to_string()),
+                    Some(SWEEP_PAGE_SIZE),
+                    cursor,
+                )
+                .await?;
+
+            report
+                .orphaned
+                .extend(orders.into_iter().filter(|order| config.is_orphaned(order)));
+
+            match next.into_next() {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        if !config.cancel || report.orphaned.is_empty() {
+            return Ok(report);
+        }
+
+        let order_ids: Vec<String> = report
+            .orphaned
+            .iter()
+            .map(|order| order.order_id.clone())
+            .collect();
+        let outcome = self.batch_cancel_order(order_ids.clone()).await?;
+
+        for (order_id, result) in order_ids.into_iter().zip(outcome.into_results()) {
+            match result {
+                Ok(canceled) => report.canceled.push(canceled.order),
+                Err(e) => report.failed.push((order_id, e)),
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SweepConfig;
+    use crate::portfolio::Order;
+
+    fn order(client_order_id: &str, created_time: &str) -> Order {
+        serde_json::from_str(&format!(
+            r#"{{"order_id":"o","ticker":"EV-A","status":"resting","yes_price":45,"no_price":55,
+                "created_time":"{}","action":"buy","side":"yes","type":"limit",
+                "client_order_id":"{}","order_group_id":""}}"#,
+            created_time, client_order_id
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_sweep_config_finds_orphaned_orders() {
+        let recent = order("mm-v2-1", "2999-01-01T00:00:00Z");
+        let foreign = order("arb-1", "2999-01-01T00:00:00Z");
+        let old = order("mm-v2-2", "2023-11-14T22:13:25Z");
+
+        assert!(!SweepConfig::default().is_orphaned(&foreign));
+
+        let config = SweepConfig {
+            owned_prefixes: vec!["mm-v2-".to_string(), "mm-v3-".to_string()],
+            max_age: Some(std::time::Duration::from_secs(3600)),
+            ..SweepConfig::default()
+        };
+        assert!(!config.is_orphaned(&recent));
+        assert!(config.is_orphaned(&foreign));
+        assert_eq!(config.is_orphaned(&old), cfg!(feature = "chrono"));
+    }
+}
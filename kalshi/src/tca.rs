@@ -0,0 +1,384 @@
+use crate::market::Snapshot;
+use crate::portfolio::{Fill, Order};
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+/// Execution quality of one strategy's fills, as reported by [`execution_quality`].
+///
+/// Prices are compared in 'Yes' terms: buying 'No' is treated as selling 'Yes' at 100 minus the price.
+/// Both measures are averages in cents per contract, weighted by the size of each fill.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutionQuality {
+    /// The strategy the fills belong to.
+    pub strategy: String,
+    /// Number of fills measured.
+    pub fills: usize,
+    /// Number of contracts measured.
+    pub contracts: i64,
+    /// Twice the distance between the fill price and the mid at submission, positive when paying
+    /// away from the mid. A fill at the touch of a one-cent market has an effective spread of one cent.
+    pub effective_spread: f64,
+    /// How much better than the touch at submission the fill was, i.e. below the ask when buying
+    /// and above the bid when selling. Negative when the order walked the book.
+    pub price_improvement: f64,
+}
+
+/// The result of [`execution_quality`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TcaReport {
+    /// Execution quality per strategy, sorted by strategy name.
+    pub strategies: Vec<ExecutionQuality>,
+    /// Fills that couldn't be measured: their order is unknown or has no creation time, or no quote
+    /// was recorded for their market at or before the order's submission.
+    pub unmatched: usize,
+}
+
+/// Measures the execution quality of fills against the quotes prevailing when their orders were submitted.
+///
+/// Each fill is matched to its order, and the order's creation time to the last recorded quote of its market
+/// at or before it. Fills are grouped into strategies by `strategy_of`, e.g. from a `client_order_id` prefix.
+/// Only available with the `chrono` feature enabled, which parses the orders' creation times.
+///
+/// # Arguments
+///
+/// * `orders` - The orders the fills belong to, e.g. from [`Kalshi::get_multiple_orders`](crate::Kalshi::get_multiple_orders).
+/// * `fills` - The fills to measure, e.g. from [`Kalshi::get_multiple_fills`](crate::Kalshi::get_multiple_fills).
+/// * `quotes` - Recorded quotes per market ticker, e.g. from [`Kalshi::download_market_history`](crate::Kalshi::download_market_history).
+///   They don't have to be sorted.
+/// * `strategy_of` - Returns the name of the strategy an order belongs to.
+///
+/// # Example
+///
+/// ```
+/// let report = execution_quality(&orders, &fills, &quotes, |order| {
+///     order.client_order_id.split('-').next().unwrap_or_default().to_string()
+/// });
+/// for strategy in &report.strategies {
+///     println!(
+///         "{}: spread {:.2}c, improvement {:.2}c over {} contracts",
+///         strategy.strategy, strategy.effective_spread, strategy.price_improvement, strategy.contracts
+///     );
+/// }
+/// ```
+///
+pub fn execution_quality<F>(
+    orders: &[Order],
+    fills: &[Fill],
+    quotes: &HashMap<String, Vec<Snapshot>>,
+    strategy_of: F,
+) -> TcaReport
+where
+    F: Fn(&Order) -> String,
+{
+    let orders: HashMap<&str, &Order> = orders
+        .iter()
+        .map(|order| (order.order_id.as_str(), order))
+        .collect();
+    let quotes: HashMap<&str, Vec<&Snapshot>> = quotes
+        .iter()
+        .map(|(ticker, snapshots)| {
+            let mut sorted: Vec<&Snapshot> = snapshots.iter().collect();
+            sorted.sort_by_key(|snapshot| snapshot.ts);
+            (ticker.as_str(), sorted)
+        })
+        .collect();
+
+    let mut totals: BTreeMap<String, (usize, i64, f64, f64)> = BTreeMap::new();
+    let mut report = TcaReport::default();
+
+    for fill in fills {
+        let measured = orders.get(fill.order_id.as_str()).and_then(|order| {
+            let submitted = order.created_time?.timestamp();
+            let snapshots = quotes.get(fill.ticker.as_str())?;
+            let prevailing = snapshots.partition_point(|snapshot| snapshot.ts <= submitted);
+            let quote = snapshots.get(prevailing.checked_sub(1)?)?;
+            Some((order, quote))
+        });
+
+        let (order, quote) = match measured {
+            Some(measured) => measured,
+            None => {
+                report.unmatched += 1;
+                continue;
+            }
+        };
+
+        let buying_yes = fill.position_change() > 0;
+        let contracts = fill.count as i64;
+        let price = fill.yes_price as f64;
+        let (spread, improvement) = if buying_yes {
+            (
+                2.0 * (price - quote.mid()),
+                quote.yes_ask.value() as f64 - price,
+            )
+        } else {
+            (
+                2.0 * (quote.mid() - price),
+                price - quote.yes_bid.value() as f64,
+            )
+        };
+
+        let total = totals.entry(strategy_of(order)).or_default();
+        total.0 += 1;
+        total.1 += contracts;
+        total.2 += spread * contracts as f64;
+        total.3 += improvement * contracts as f64;
+    }
+
+    report.strategies = totals
+        .into_iter()
+        .map(
+            |(strategy, (fills, contracts, spread, improvement))| ExecutionQuality {
+                strategy,
+                fills,
+                contracts,
+                effective_spread: spread / contracts.max(1) as f64,
+                price_improvement: improvement / contracts.max(1) as f64,
+            },
+        )
+        .collect();
+    report
+}
+
+/// The average markout of one strategy's fills after a given horizon, as reported by [`markouts`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct HorizonMarkout {
+    /// Time elapsed after each fill.
+    pub horizon: Duration,
+    /// Number of contracts whose market was recorded long enough after the fill to be measured.
+    pub contracts: i64,
+    /// How far the mid moved in favor of the fills, in cents per contract weighted by fill size.
+    /// Consistently negative markouts mean the strategy is being picked off by better informed traders.
+    pub markout: f64,
+}
+
+/// The markouts of one strategy's fills over every horizon, as reported by [`markouts`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrategyMarkouts {
+    /// The strategy the fills belong to.
+    pub strategy: String,
+    /// The markout after each horizon, in the order the horizons were given.
+    pub horizons: Vec<HorizonMarkout>,
+}
+
+/// Measures adverse selection: how the market's mid moved after each fill, over several horizons.
+///
+/// For a fill buying 'Yes' at price `p`, the markout after a horizon is the recorded mid at that time minus `p`;
+/// for a fill selling 'Yes', buying 'No' included, it is `p` minus the mid. A fill is only measured at a horizon
+/// if the quotes of its market were recorded at least until then. Fills whose order is unknown are skipped.
+/// Only available with the `chrono` feature enabled, which parses the fills' creation times.
+///
+/// # Arguments
+///
+/// * `orders` - The orders the fills belong to, used to group fills into strategies.
+/// * `fills` - The fills to measure.
+/// * `quotes` - Recorded quotes per market ticker. They don't have to be sorted.
+/// * `horizons` - The times after each fill to measure the mid at, e.g. 10 seconds, 1 minute and 5 minutes.
+/// * `strategy_of` - Returns the name of the strategy an order belongs to.
+///
+/// # Returns
+///
+/// The markouts of every strategy with measured fills, sorted by strategy name.
+///
+/// # Example
+///
+/// ```
+/// let horizons = [Duration::from_secs(10), Duration::from_secs(60), Duration::from_secs(300)];
+/// for strategy in markouts(&orders, &fills, &quotes, &horizons, |order| order.client_order_id.clone()) {
+///     for horizon in &strategy.horizons {
+///         println!("{} {:?}: {:+.2}c", strategy.strategy, horizon.horizon, horizon.markout);
+///     }
+/// }
+/// ```
+///
+pub fn markouts<F>(
+    orders: &[Order],
+    fills: &[Fill],
+    quotes: &HashMap<String, Vec<Snapshot>>,
+    horizons: &[Duration],
+    strategy_of: F,
+) -> Vec<StrategyMarkouts>
+where
+    F: Fn(&Order) -> String,
+{
+    let orders: HashMap<&str, &Order> = orders
+        .iter()
+        .map(|order| (order.order_id.as_str(), order))
+        .collect();
+    let quotes: HashMap<&str, Vec<&Snapshot>> = quotes
+        .iter()
+        .map(|(ticker, snapshots)| {
+            let mut sorted: Vec<&Snapshot> = snapshots.iter().collect();
+            sorted.sort_by_key(|snapshot| snapshot.ts);
+            (ticker.as_str(), sorted)
+        })
+        .collect();
+
+    // Per strategy, the measured contracts and summed markout of each horizon.
+    let mut totals: BTreeMap<String, Vec<(i64, f64)>> = BTreeMap::new();
+
+    for fill in fills {
+        let (order, snapshots) = match (
+            orders.get(fill.order_id.as_str()),
+            quotes.get(fill.ticker.as_str()),
+        ) {
+            (Some(order), Some(snapshots)) => (order, snapshots),
+            _ => continue,
+        };
+        let recorded_until = match snapshots.last() {
+            Some(snapshot) => snapshot.ts,
+            None => continue,
+        };
+
+        let filled_at = fill.created_time.timestamp();
+        let direction = if fill.position_change() > 0 {
+            1.0
+        } else {
+            -1.0
+        };
+        let contracts = fill.count as i64;
+
+        let total = totals
+            .entry(strategy_of(order))
+            .or_insert_with(|| vec![(0, 0.0); horizons.len()]);
+
+        for (horizon, total) in horizons.iter().zip(total.iter_mut()) {
+            let at = filled_at + horizon.as_secs() as i64;
+            if at > recorded_until {
+                continue;
+            }
+            let prevailing = snapshots.partition_point(|snapshot| snapshot.ts <= at);
+            let quote = match prevailing.checked_sub(1) {
+                Some(index) => snapshots[index],
+                None => continue,
+            };
+
+            let markout = direction * (quote.mid() - fill.yes_price as f64);
+            total.0 += contracts;
+            total.1 += markout * contracts as f64;
+        }
+    }
+
+    totals
+        .into_iter()
+        .filter(|(_, total)| total.iter().any(|(contracts, _)| *contracts > 0))
+        .map(|(strategy, total)| StrategyMarkouts {
+            strategy,
+            horizons: horizons
+                .iter()
+                .zip(total)
+                .map(|(horizon, (contracts, markout))| HorizonMarkout {
+                    horizon: *horizon,
+                    contracts,
+                    markout: markout / contracts.max(1) as f64,
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::execution_quality;
+    use crate::fixtures::quote;
+    use crate::portfolio::{Fill, Order};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_execution_quality_against_prevailing_quote() {
+        let order = |id: &str, client_order_id: &str| -> Order {
+            serde_json::from_str(&format!(
+                r#"{{"order_id":"{}","ticker":"EV-A","status":"executed","yes_price":45,"no_price":55,
+                    "created_time":"2023-11-14T22:13:25Z","action":"buy","side":"yes","type":"limit",
+                    "client_order_id":"{}","order_group_id":""}}"#,
+                id, client_order_id
+            ))
+            .unwrap()
+        };
+        let fill = |order_id: &str, action: &str, side: &str, count: i32, yes_price: i64| -> Fill {
+            serde_json::from_str(&format!(
+                r#"{{"action":"{}","count":{},"created_time":"2023-11-14T22:13:30Z","is_taker":true,
+                    "no_price":{},"order_id":"{}","side":"{}","ticker":"EV-A","trade_id":"t","yes_price":{}}}"#,
+                action,
+                count,
+                100 - yes_price,
+                order_id,
+                side,
+                yes_price
+            ))
+            .unwrap()
+        };
+
+        let orders = [
+            order("o1", "mm-1"),
+            order("o2", "mm-2"),
+            order("o3", "momo-1"),
+        ];
+        let fills = [
+            // Bought at the ask of a 40/42 market, then sold 'Yes' by buying 'No' at 59.
+            fill("o1", "buy", "yes", 10, 42),
+            fill("o2", "buy", "no", 10, 41),
+            fill("o3", "buy", "yes", 5, 43),
+            fill("missing", "buy", "yes", 5, 43),
+        ];
+        let quotes = HashMap::from([(
+            "EV-A".to_string(),
+            vec![quote(1_700_000_100, 10, 90), quote(1_700_000_000, 40, 42)],
+        )]);
+
+        let report = execution_quality(&orders, &fills, &quotes, |order| {
+            order.client_order_id.split('-').next().unwrap().to_string()
+        });
+
+        assert_eq!(report.unmatched, 1);
+        assert_eq!(report.strategies.len(), 2);
+        let mm = &report.strategies[0];
+        assert_eq!(
+            (mm.strategy.as_str(), mm.fills, mm.contracts),
+            ("mm", 2, 20)
+        );
+        assert_eq!(mm.effective_spread, 1.0);
+        assert_eq!(mm.price_improvement, 0.5);
+        let momo = &report.strategies[1];
+        assert_eq!(momo.effective_spread, 4.0);
+        assert_eq!(momo.price_improvement, -1.0);
+    }
+
+    #[test]
+    fn test_markouts_follow_mid_after_fill() {
+        use super::markouts;
+        use std::time::Duration;
+
+        let order: Order = serde_json::from_str(
+            r#"{"order_id":"o1","ticker":"EV-A","status":"executed","yes_price":42,"no_price":58,
+                "action":"buy","side":"yes","type":"limit","client_order_id":"mm-1","order_group_id":""}"#,
+        )
+        .unwrap();
+        // Filled at 1_700_000_010, buying 10 'Yes' contracts at 42.
+        let fill: Fill = serde_json::from_str(
+            r#"{"action":"buy","count":10,"created_time":"2023-11-14T22:13:30Z","is_taker":false,"no_price":58,
+                "order_id":"o1","side":"yes","ticker":"EV-A","trade_id":"t","yes_price":42}"#,
+        )
+        .unwrap();
+        let quotes = HashMap::from([(
+            "EV-A".to_string(),
+            vec![quote(1_700_000_070, 36, 38), quote(1_700_000_015, 40, 42)],
+        )]);
+
+        let horizons = [
+            Duration::from_secs(10),
+            Duration::from_secs(60),
+            Duration::from_secs(600),
+        ];
+        let result = markouts(&[order], &[fill], &quotes, &horizons, |_| "mm".to_string());
+
+        assert_eq!(result.len(), 1);
+        let markouts: Vec<(i64, f64)> = result[0]
+            .horizons
+            .iter()
+            .map(|horizon| (horizon.contracts, horizon.markout))
+            .collect();
+        assert_eq!(markouts, vec![(10, -1.0), (10, -5.0), (0, 0.0)]);
+    }
+}
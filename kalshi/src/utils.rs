@@ -1,16 +1,15 @@
+use crate::kalshi_error::KalshiError;
 use crate::TradingEnvironment;
-// MACROS
-
-#[macro_export]
-#[doc(hidden)]
-macro_rules! add_param {
-    ($params:ident, $param_name:expr, $param_value:expr) => {
-        if let Some(param) = $param_value {
-            $params.push(($param_name, param.to_string()));
-        }
-    };
-}
-
+use futures::future::FutureExt;
+use futures::stream::{FuturesUnordered, StreamExt};
+use serde::Serialize;
+use std::any::Any;
+use std::fmt::{self, Write};
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
 // Helper to build the base url
 
 pub fn build_base_url(trading_env: TradingEnvironment) -> &'static str {
@@ -19,3 +18,303 @@ pub fn build_base_url(trading_env: TradingEnvironment) -> &'static str {
         TradingEnvironment::DemoMode => "https://demo-api.kalshi.co/trade-api/v2",
     }
 }
+
+// Helper to build the client used for order placement
+
+/// How often TCP keepalive probes are sent on the order client's idle connections.
+const ORDER_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Builds the HTTP client reserved for order placement.
+///
+/// Its connections never time out in the pool and are kept alive at the TCP level, so the
+/// connection is still warm when the next burst of orders arrives.
+pub(crate) fn build_order_client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .pool_idle_timeout(None)
+        .tcp_keepalive(ORDER_KEEPALIVE_INTERVAL)
+        .tcp_nodelay(true)
+        .build()
+        .expect("failed to build the order client")
+}
+
+// Helper to build request urls
+
+/// Builds a request URL with query parameters in a buffer that is reused across requests.
+///
+/// The buffer is taken from the client when the builder is created and handed back when it is
+/// dropped, so polling loops don't reallocate it on every call. Parameters are percent-encoded
+/// straight into the buffer and the URL is only parsed once, in [`finish`](QueryBuilder::finish).
+pub(crate) struct QueryBuilder<'a> {
+    pool: &'a Mutex<String>,
+    buf: String,
+    has_params: bool,
+}
+
+impl<'a> QueryBuilder<'a> {
+    pub(crate) fn new(
+        pool: &'a Mutex<String>,
+        base_url: &str,
+        path: fmt::Arguments,
+    ) -> QueryBuilder<'a> {
+        let mut buf = std::mem::take(&mut *pool.lock().unwrap());
+        buf.clear();
+        buf.push_str(base_url);
+        // Writing to a String cannot fail.
+        let _ = buf.write_fmt(path);
+
+        QueryBuilder {
+            pool,
+            buf,
+            has_params: false,
+        }
+    }
+
+    /// Appends the parameter to the query if a value is given.
+    pub(crate) fn param<T: fmt::Display>(&mut self, name: &str, value: Option<T>) -> &mut Self {
+        if let Some(value) = value {
+            self.buf.push(if self.has_params { '&' } else { '?' });
+            self.has_params = true;
+            self.buf
+                .extend(form_urlencoded::byte_serialize(name.as_bytes()));
+            self.buf.push('=');
+            let _ = write!(QueryEncoder(&mut self.buf), "{}", value);
+        }
+        self
+    }
+
+    /// Parses the built URL and returns the buffer to the client.
+    pub(crate) fn finish(self) -> Result<reqwest::Url, KalshiError> {
+        reqwest::Url::parse(&self.buf).map_err(|e| {
+            KalshiError::InternalError(format!("Invalid request URL {}: {}", self.buf, e))
+        })
+    }
+}
+
+impl Drop for QueryBuilder<'_> {
+    fn drop(&mut self) {
+        // Keep whichever buffer has grown larger when requests are built concurrently.
+        let mut pooled = self.pool.lock().unwrap();
+        if pooled.capacity() < self.buf.capacity() {
+            std::mem::swap(&mut *pooled, &mut self.buf);
+        }
+    }
+}
+
+/// Percent-encodes everything written through it into the wrapped buffer.
+struct QueryEncoder<'a>(&'a mut String);
+
+impl fmt::Write for QueryEncoder<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.extend(form_urlencoded::byte_serialize(s.as_bytes()));
+        Ok(())
+    }
+}
+
+// Helper to serialize request bodies
+
+/// Serializes `value` as JSON into a buffer sized after the previous body and returns the buffer.
+///
+/// Returning the buffer itself hands it to the request without a copy, so the size of the last body is
+/// all that's kept for the next one.
+pub(crate) fn json_body<T: Serialize>(
+    last_size: &AtomicUsize,
+    value: &T,
+) -> Result<Vec<u8>, KalshiError> {
+    let mut buf = Vec::with_capacity(last_size.load(Ordering::Relaxed));
+    serde_json::to_writer(&mut buf, value).map_err(|e| {
+        KalshiError::InternalError(format!("Failed to serialize request body: {}", e))
+    })?;
+    last_size.store(buf.len(), Ordering::Relaxed);
+    Ok(buf)
+}
+
+// Helpers for running request tasks concurrently
+
+/// The maximum number of requests a batch operation keeps in flight at once.
+pub(crate) const DEFAULT_MAX_CONCURRENCY: usize = 10;
+
+/// Runs the given request futures concurrently, with at most `max_concurrency` in flight,
+/// and returns their results in the order they were given.
+///
+/// The futures run on the caller's task, so they may borrow from it. Each future is labelled with
+/// an identifier (e.g. an order ID). A future that panics only fails its own entry, as a
+/// `KalshiError::TaskJoinError` carrying that identifier. If the returned future is dropped, every
+/// future that has not finished yet is dropped with it.
+pub(crate) async fn join_bounded<T, F>(
+    tasks: Vec<(String, F)>,
+    max_concurrency: usize,
+) -> Vec<Result<T, KalshiError>>
+where
+    F: Future<Output = Result<T, KalshiError>>,
+{
+    let mut outputs: Vec<Option<Result<T, KalshiError>>> = (0..tasks.len()).map(|_| None).collect();
+
+    let label = |(index, (id, task)): (usize, (String, F))| {
+        AssertUnwindSafe(task)
+            .catch_unwind()
+            .map(move |result| (index, id, result))
+    };
+
+    let mut pending = tasks.into_iter().enumerate();
+    let mut in_flight: FuturesUnordered<_> = pending
+        .by_ref()
+        .take(max_concurrency.max(1))
+        .map(label)
+        .collect();
+
+    while let Some((index, id, result)) = in_flight.next().await {
+        outputs[index] = Some(match result {
+            Ok(result) => result,
+            Err(panic) => Err(KalshiError::TaskJoinError(
+                id,
+                panic_message(panic.as_ref()),
+            )),
+        });
+
+        if let Some(task) = pending.next() {
+            in_flight.push(label(task));
+        }
+    }
+
+    outputs
+        .into_iter()
+        .map(|output| output.expect("every future runs to completion"))
+        .collect()
+}
+
+/// Extracts the message a panic was raised with, if it was a string.
+fn panic_message(panic: &(dyn Any + Send)) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "task panicked".to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{join_bounded, QueryBuilder};
+    use crate::kalshi_error::KalshiError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    #[test]
+    fn test_query_builder_matches_parse_with_params() {
+        let pool = Mutex::new(String::new());
+
+        let mut url = QueryBuilder::new(&pool, "https://example.com/v2", format_args!("/markets"));
+        url.param("limit", Some(100));
+        url.param("cursor", None::<String>);
+        url.param("tickers", Some("A-1,B 2&c=3"));
+        let url = url.finish().unwrap();
+
+        let expected = reqwest::Url::parse_with_params(
+            "https://example.com/v2/markets",
+            &[("limit", "100"), ("tickers", "A-1,B 2&c=3")],
+        )
+        .unwrap();
+
+        assert_eq!(url, expected);
+        assert!(pool.lock().unwrap().capacity() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_join_bounded_keeps_input_order() {
+        let in_flight = AtomicUsize::new(0);
+        let max_in_flight = AtomicUsize::new(0);
+
+        let tasks = (0..6u64)
+            .map(|i| {
+                let in_flight = &in_flight;
+                let max_in_flight = &max_in_flight;
+                (i.to_string(), async move {
+                    let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_in_flight.fetch_max(now, Ordering::SeqCst);
+                    // Later items finish first.
+                    tokio::time::sleep(Duration::from_millis(30 - 5 * i)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    Ok::<_, KalshiError>(i)
+                })
+            })
+            .collect();
+
+        let results: Vec<u64> = join_bounded(tasks, 2)
+            .await
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+
+        assert_eq!(results, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_join_bounded_isolates_panics() {
+        let tasks = ["ok-1", "boom", "ok-2"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, id)| {
+                (id.to_string(), async move {
+                    if id == "boom" {
+                        panic!("order rejected");
+                    }
+                    Ok::<_, KalshiError>(i)
+                })
+            })
+            .collect();
+
+        let results = join_bounded(tasks, 3).await;
+
+        assert_eq!(results[0].as_ref().unwrap(), &0);
+        assert_eq!(results[2].as_ref().unwrap(), &2);
+        match &results[1] {
+            Err(KalshiError::TaskJoinError(id, message)) => {
+                assert_eq!(id, "boom");
+                assert_eq!(message, "order rejected");
+            }
+            other => panic!("expected a task join error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_join_bounded_cancels_on_drop() {
+        struct Finished<'a>(&'a AtomicUsize);
+
+        impl Drop for Finished<'_> {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let started = AtomicUsize::new(0);
+        let dropped = AtomicUsize::new(0);
+        let completed = AtomicUsize::new(0);
+
+        let tasks: Vec<_> = (0..4)
+            .map(|i| {
+                let started = &started;
+                let completed = &completed;
+                let guard = Finished(&dropped);
+                (i.to_string(), async move {
+                    let _guard = guard;
+                    started.fetch_add(1, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_secs(60)).await;
+                    completed.fetch_add(1, Ordering::SeqCst);
+                    Ok::<_, KalshiError>(())
+                })
+            })
+            .collect();
+
+        let timed_out = tokio::time::timeout(Duration::from_millis(20), join_bounded(tasks, 2))
+            .await
+            .is_err();
+
+        assert!(timed_out);
+        assert_eq!(started.load(Ordering::SeqCst), 2);
+        assert_eq!(dropped.load(Ordering::SeqCst), 4);
+        assert_eq!(completed.load(Ordering::SeqCst), 0);
+    }
+}
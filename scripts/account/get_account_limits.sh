@@ -0,0 +1,4 @@
+curl --request GET \
+     --url https://demo-api.kalshi.co/trade-api/v2/account/limits \
+     --header 'Authorization: {token}' \
+     --header 'accept: application/json'
\ No newline at end of file
@@ -0,0 +1,3 @@
+curl --request GET \
+     --url 'https://demo-api.kalshi.co/trade-api/v2/series?category=Economics' \
+     --header 'accept: application/json'
\ No newline at end of file